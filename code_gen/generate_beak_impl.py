@@ -16,12 +16,13 @@ def main():
             [
                 "use super::BirdCard;\n",
                 "use pyo3::prelude::*;\n",
+                "use serde::{Deserialize, Serialize};\n",
                 "\n",
             ]
         )
         f.writelines(
             [
-                "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]\n",
+                "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]\n",
                 "#[pyclass(eq, eq_int)]\n",
                 "pub enum BeakDirection {\n",
                 "  Left,\n",
@@ -15,6 +15,7 @@ def main():
         f.writelines(
             [
                 "// This code is generated automatically via a script in code_gen/ folder\n",
+                "use serde::{Deserialize, Serialize};\n",
                 "use strum_macros::EnumIter;\n\n",
                 "use super::ScoringRule;\n",
                 "use crate::expansion::Expansion;\n",
@@ -25,7 +26,7 @@ def main():
         # Start with enum
         f.writelines(
             [
-                "\n#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter)]",
+                "\n#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, Serialize, Deserialize)]",
                 "\npub enum BonusCard {\n",
             ]
         )
@@ -24,6 +24,7 @@ def main():
         f.writelines(
             [
                 "// This code is generated automatically via a script in code_gen/ folder\n",
+                "use serde::{Deserialize, Serialize};\n",
                 "use strum_macros::EnumIter;\n\n",
                 "use super::BirdCardColor;\n",
                 "use crate::{{bonus_card::BonusCard, habitat::Habitat, expansion::Expansion, food::{{BirdCardCost, CostAlternative}}, nest::NestType}};\n",
@@ -33,7 +34,7 @@ def main():
         # Start with enum
         f.writelines(
             [
-                "\n#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter)]",
+                "\n#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, Hash, Serialize, Deserialize)]",
                 "\npub enum BirdCard {\n",
             ]
         )
@@ -6,7 +6,7 @@ import polars as pl
 import unidecode
 
 
-FOOD_TYPES = ["Invertebrate", "Seed", "Fish", "Fruit", "Rodent"]
+FOOD_TYPES = ["Invertebrate", "Seed", "Fish", "Fruit", "Rodent", "Nectar"]
 HABITATS = ["Forest", "Grassland", "Wetland"]
 
 
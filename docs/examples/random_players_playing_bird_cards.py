@@ -1,5 +1,6 @@
 import wingspan_gym
 from wingspan_gym._internal import StepResult
+import numpy as np
 import random
 
 import wingspan_gym.game
@@ -17,12 +18,11 @@ class DummyHeuristic:
     def get_next_action(self):
         action_type = self.env.next_action()
         if not self.played_a_card and str(action_type) == "ChooseAction":
-            if not self.tried_to_play_a_card:
+            if not self.tried_to_play_a_card and self.env.action_mask()[0]:
                 self.tried_to_play_a_card = True
                 return 0
             else:
-                # Tried to play a card and failed
-                # Get food then
+                # Cannot play a card, so get food then
                 return 1
 
         # No longer choose action, so this works I think
@@ -31,14 +31,13 @@ class DummyHeuristic:
 
         self.tried_to_play_a_card = False
 
-        return random.randint(0, self.env.action_space_size())
+        return random.choice(np.flatnonzero(self.env.action_mask()))
 
 
 env = wingspan_gym.game.WingspanEnv()
 
-env.reset()
+observation, info = env.reset()
 player = [DummyHeuristic(env) for _ in range(2)]
-# observation, info = env.reset()
 
 episode_over = False
 max_steps = 10000
@@ -54,8 +53,11 @@ while not episode_over and step_idx < max_steps:
         print(f"Chose action: {action}")
         print(f"Step: {step_idx}/{max_steps}")
 
-    if env.step(action) == StepResult.Terminated:
-        print(f"Score: {env.points()}")
-        break
+    observation, reward, terminated, truncated, info = env.step(action)
+    if info["step_result"] == StepResult.Invalid:
+        print("Invalid action")
+    episode_over = terminated or truncated
+
+print(f"Score: {env.points()}")
 
 env.close()
@@ -2,6 +2,13 @@
 
 from .game import WingspanEnv
 
-from ._internal import StepResult
+from ._internal import EndOfRoundScoring, Expansion, MatLayout, RewardMode, StepResult
 
-__all__ = ["StepResult", "WingspanEnv"]
+__all__ = [
+    "EndOfRoundScoring",
+    "Expansion",
+    "MatLayout",
+    "RewardMode",
+    "StepResult",
+    "WingspanEnv",
+]
@@ -1,30 +1,136 @@
 """Internal package which contents are written in Rust.
 
 Vast majority of this package is meant to provide introspection into under-the-hood state of the environment.
-The main interactivity is provided with `step` and `reset` functions, although returns do not follow `gymnasium` spec.
+The main interactivity is provided with `step` and `reset` functions, which follow `gymnasium` spec.
 """
 
 from enum import Enum
-from typing import Optional, Union
+from typing import Any, Callable, Optional, Union
+
+import numpy as np
+import numpy.typing as npt
 
 class PyWingspanEnv:
     def __init__(
-        self, hand_limit: Optional[int] = None, num_players: Optional[int] = None
+        self,
+        hand_limit: Optional[int] = None,
+        num_players: Optional[int] = None,
+        record_replay: Optional[bool] = None,
+        undo_history_size: Optional[int] = None,
+        num_rounds: Optional[int] = None,
+        expansions: Optional[list["Expansion"]] = None,
+        scoring_style: Optional["EndOfRoundScoring"] = None,
+        seed: Optional[int] = None,
+        reward_mode: Optional["RewardMode"] = None,
+        mat_layout: Optional["MatLayout"] = None,
     ):
         """
         Initializes environment with specified number of players.
         Additionally it enforces hand limit, which in turn can limits size of action space.
 
         Args:
-            hand_limit (Optional[int], optional): Maximum size of hand (bird cards only) allowed for player to have. Defaults to 20.
+            hand_limit (Optional[int], optional): Maximum size of hand (bird cards only) allowed for player to have.
+                Cards drawn past it are discarded. The starting hand of 5 cards is dealt regardless. Defaults to 20.
             num_players (Optional[int], optional): Number of players in a game. Note, that going way above the regular max number of players (5), might cause instability.
                 Defaults to 2.
+            record_replay (Optional[bool], optional): Whether to record every step into a replay (see `replay_json`).
+                It slows down the environment. Defaults to False.
+            undo_history_size (Optional[int], optional): Maximum number of steps that can be undone in a row (see `undo`).
+                Snapshot of the state is stored for each of them. Defaults to 0, which disables `undo`.
+            num_rounds (Optional[int], optional): Number of rounds in a game, between 1 and 4. Defaults to 4.
+            expansions (Optional[list[Expansion]], optional): Expansions used in a game. Defaults to `[Expansion.Core]`.
+            scoring_style (Optional[EndOfRoundScoring], optional): How end of round goals are scored.
+                Defaults to `EndOfRoundScoring.Competitive`.
+            seed (Optional[int], optional): Seed of the first game. Defaults to None (random).
+            reward_mode (Optional[RewardMode], optional): How rewards returned by `step` and `step_agents` are computed.
+                Defaults to `RewardMode.Points`.
+            mat_layout (Optional[MatLayout], optional): Player mat, which defines rewards of the habitat actions.
+                Defaults to `MatLayout.Core`.
+
+        Raises:
+            ValueError: If any of the settings is invalid.
+        """
+        ...
+
+    def __getstate__(self) -> bytes: ...
+    def __setstate__(self, state: bytes) -> None: ...
+    def to_bytes(self) -> bytes:
+        """Full state of the environment (incl. random number generator) in a compact, binary format.
+
+        It is also used for pickling of the environment.
+
+        Returns:
+            bytes: Serialized state. It includes a version of the format, and can be restored with `from_bytes`.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(state: bytes) -> "PyWingspanEnv":
+        """Restores environment from the output of `to_bytes`.
+
+        Raises:
+            ValueError: If state is malformed, or was created with an incompatible version of the package.
+        """
+        ...
+
+    def to_json(self) -> str:
+        """Full state of the environment (incl. random number generator) in a human-readable JSON format.
+
+        Returns:
+            str: JSON with `version` of the format and the `state` itself. It can be restored with `from_json`.
+        """
+        ...
+
+    @staticmethod
+    def from_json(state: str) -> "PyWingspanEnv":
+        """Restores environment from the output of `to_json`.
+
+        Raises:
+            ValueError: If state is malformed, or was created with an incompatible version of the package.
+        """
+        ...
+
+    def replay_json(self) -> Optional[str]:
+        """Replay of the game since the last reset.
+
+        It contains config, seed and every step taken (incl. ones taken automatically, when there was only one option),
+        together with a checksum of the state after each of the steps.
+
+        Returns:
+            Optional[str]: Replay in JSON format. None, if recording of replays is disabled,
+                or environment was restored with `from_bytes`/`from_json`.
+        """
+        ...
+
+    @staticmethod
+    def from_replay_json(replay: str) -> "PyWingspanEnv":
+        """Re-executes the game from the output of `replay_json`.
+
+        Raises:
+            ValueError: If the game diverges from the replay at any step.
+
+        Returns:
+            PyWingspanEnv: Environment in the state after the last step of the replay.
         """
         ...
 
     @property
     def player_idx(self) -> int:
-        """Index of a current player."""
+        """Index of a player that makes the next decision.
+
+        It might be different from `turn_player_idx`, when other players have to make a decision
+        during someone's turn (i.e. due to a bird power).
+        """
+        ...
+
+    @property
+    def turn_player_idx(self) -> int:
+        """Index of a player whose turn it currently is."""
+        ...
+
+    @property
+    def num_players(self) -> int:
+        """Number of players in the game."""
         ...
 
     @property
@@ -35,23 +141,56 @@ class PyWingspanEnv:
         It is 0-indexed, with 3 as a max value. -1 indicates setup phase, when player chooses which resources/cards to discard.
         """
 
-    def reset(self, seed: Optional[int]):
+    def reset(
+        self, seed: Optional[int] = None
+    ) -> tuple[npt.NDArray[np.float32], dict[str, Any]]:
         """Resets the environment bringing it to the beginning of setup phase.
 
         Args:
             seed (Optional[int]): Random seed to use when setting up a game.
+
+        Returns:
+            tuple[npt.NDArray[np.float32], dict[str, Any]]: Observation of the first player, and info dictionary.
+                See `step` for contents of info dictionary.
         """
         ...
 
-    def step(self, action_idx: int) -> StepResult:
+    def step(
+        self, action_idx: int
+    ) -> tuple[npt.NDArray[np.float32], float, bool, bool, dict[str, Any]]:
         """Performs a step for a current player.
 
         Args:
             action_idx (int): Index of action to take.
-                This is highly game state dependent, and implementation/guide of the various possible contexts is in the works.
+                Use `action_mask` to find out which of the actions are valid.
+
+        Returns:
+            tuple[npt.NDArray[np.float32], float, bool, bool, dict[str, Any]]: Gymnasium style tuple of:
+                1. observation of the player that makes the next decision (see `observation`),
+                2. reward - reward of the player that took the action (see `RewardMode`),
+                3. terminated - whether the game has ended,
+                4. truncated - always `False`,
+                5. info dictionary with keys:
+                    - `action_mask` - mask of valid actions for the next decision (see `action_mask`),
+                    - `player_idx` - index of the player that makes the next decision,
+                    - `action_type` - `ActionType` of the next decision. None, if game is in terminated state,
+                    - `step_result` - `StepResult` of this step. Invalid actions do not change state of the game,
+                    - `game_result` - `GameResult` with final standings. None, until the game has ended.
+        """
+        ...
+
+    def step_agents(self, action_idx: int) -> tuple[StepResult, list[float]]:
+        """Performs a step for a current player, and returns rewards of all of the players.
+
+        It is meant for multi-agent setups, where rewards need to be attributed to each of the players separately.
+
+        Args:
+            action_idx (int): Index of action to take.
+                Use `action_mask` to find out which of the actions are valid.
 
         Returns:
-            StepResult: StepResult indicating whether action was successful or not, and basic feedback about game state.
+            tuple[StepResult, list[float]]: Result of the step, and reward of each player (see `RewardMode`).
+                Invalid actions do not change state of the game, and result in rewards of 0.
         """
         ...
 
@@ -65,6 +204,28 @@ class PyWingspanEnv:
         """
         ...
 
+    def action_mask_size(self) -> int:
+        """Fixed size of the action mask.
+
+        It is an upper bound of the action space size across all of the possible actions,
+        and depends on the hand limit, number of players and bonus cards in the environment.
+
+        Returns:
+            int: Size of the action mask.
+        """
+        ...
+
+    def action_mask(self) -> npt.NDArray[np.bool_]:
+        """Mask of the valid actions for the next action.
+
+        Every index marked as `True` is guaranteed to be accepted by `step`.
+
+        Returns:
+            npt.NDArray[np.bool_]: 1-D array of size `action_mask_size()`.
+                All of the entries are `False` if game is in terminated state.
+        """
+        ...
+
     def _debug_get_state(self):
         """Highly variable function used for debugging.
 
@@ -90,6 +251,460 @@ class PyWingspanEnv:
             list[int]: List of current point tally for each player
         """
 
+    def game_result(self) -> Optional["GameResult"]:
+        """Final standings of the players, with ties broken by unused food.
+
+        Returns:
+            Optional[GameResult]: Result of the game. None, until the game has ended.
+        """
+        ...
+
+    def score_breakdown(self) -> list["ScoreBreakdown"]:
+        """Score of each of the players split into the categories of the official score pad.
+
+        Like `points`, it is calculated as if the game ended right now.
+        It also includes unused food, which is the official tiebreaker.
+
+        Returns:
+            list[ScoreBreakdown]: Breakdown for each player.
+        """
+        ...
+
+    def observation(self, player_idx: Optional[int] = None) -> npt.NDArray[np.float32]:
+        """Fixed-size numeric observation of the game.
+
+        Observation includes only information available to the observing player
+        (i.e. only size of hands of the other players).
+        Categorical values (birds, goals, action types) are encoded as `id + 1`, with `0` meaning an empty slot.
+        Layout of the observation is (in order):
+
+        1. `game` (3) - round index, number of seats from observer to the player whose turn it is, number of cards in deck.
+        2. `player` - observer's foods (6, per FoodIndex), turns left, round goal points,
+            hand (count per BirdCard index), bonus cards (count per BonusCard index) and mat.
+        3. `opponent_{i}` (for each other player, in seat order) - foods (6), turns left, round goal points,
+            hand size, number of bonus cards and mat.
+        4. `bird_feeder` (9) - number of dice showing each face (8) and number of dice outside of the feeder.
+        5. `display` (3) - face-up bird cards.
+        6. `round_goals` (num_rounds) - goal for each of the rounds.
+        7. `pending_action` (2) - `ActionType` of next action and its action space size.
+
+        Mat is encoded as 3 habitats (Forest, Grassland, Wetland), each with 5 slots x 10 values:
+        bird, eggs, eggs capacity, cached food (6, per FoodIndex), tucked cards;
+        followed by nectar spent in the habitat.
+
+        Args:
+            player_idx (Optional[int], optional): Index of the observing player. Defaults to current player.
+
+        Returns:
+            npt.NDArray[np.float32]: 1-D array with the observation.
+        """
+        ...
+
+    def observation_layout(self) -> list[tuple[str, int]]:
+        """Names and sizes of consecutive sections of the observation.
+
+        Returns:
+            list[tuple[str, int]]: Section name and its size, in order they appear in the observation.
+        """
+        ...
+
+    def undo(self) -> None:
+        """Restores the exact state from before the last successful `step`.
+
+        It includes all of the steps taken automatically as part of it, rolls of the birdfeeder and draws from the deck.
+
+        Raises:
+            ValueError: If there is no step to undo, i.e. history is disabled, empty or `undo_history_size` steps were already undone.
+        """
+        ...
+
+    def determinize(
+        self, observer_idx: Optional[int] = None, seed: Optional[int] = None
+    ) -> "PyWingspanEnv":
+        """Copy of the environment, in which information hidden from the observer is resampled.
+
+        Hidden cards (other players' hands and bonus cards, order of the decks) are reshuffled among all of the cards
+        the observer has not seen. Cards other players were seen taking (i.e. from the face-up display) stay in their hands.
+        Future rolls of the birdfeeder are resampled as well. Everything else, incl. observation of the observer, is preserved.
+
+        It is meant for search over imperfect information (i.e. ISMCTS). Replay and undo history are not copied.
+
+        Args:
+            observer_idx (Optional[int], optional): Player whose knowledge is preserved. Defaults to `player_idx`.
+            seed (Optional[int], optional): Seed used for resampling. Defaults to None (random).
+
+        Raises:
+            ValueError: If observer index is not a valid player index.
+        """
+        ...
+
+    def round_goal_history(self) -> list["RoundGoalRecord"]:
+        """Goal of each of the rounds scored so far, with goal count, placement and points of every player.
+
+        Returns:
+            list[RoundGoalRecord]: One record per scored round, in order. Empty before the end of the first round.
+        """
+        ...
+
+    def round_goal_placements(self) -> list[list[Optional[int]]]:
+        """Placement of every player on each of the round goals scored so far.
+
+        Placement is a place among players with at least one item matching the goal (0 is first).
+        Tied players share the best of their places, and the following places are skipped.
+        It is computed the same way for both scoring styles.
+
+        Returns:
+            list[list[Optional[int]]]: Placements indexed by round and then by player. None if player had no matching items.
+        """
+        ...
+
+    @property
+    def config(self) -> "WingspanEnvConfig":
+        """Settings the environment was created with."""
+        ...
+
+    def events(self) -> list["GameEvent"]:
+        """Events that happened during the last step, in order in which they happened.
+
+        It includes events of the steps that were taken automatically (when there was only one option).
+        Invalid steps do not produce any events.
+
+        Returns:
+            list[GameEvent]: Events of the last step.
+        """
+        ...
+
+def search(
+    env: PyWingspanEnv,
+    budget: Union[int, float],
+    exploration: Optional[float] = None,
+    determinize: Optional[bool] = None,
+    max_rollout_steps: Optional[int] = None,
+    seed: Optional[int] = None,
+    rollout_policy: Optional[Callable[[PyWingspanEnv, list[int]], int]] = None,
+) -> tuple[int, dict[int, tuple[int, float]]]:
+    """Monte Carlo Tree Search (UCT) for the decision of the current player.
+
+    Search runs natively, without holding the GIL, and does not modify `env`.
+    Leaves are evaluated with rollouts until the end of the game,
+    and rewarded with player's share of the win (see `GameResult.win_shares`).
+    Rollouts choose uniformly random actions, unless `rollout_policy` is given.
+
+    Args:
+        env (PyWingspanEnv): Environment, in which decision is made.
+        budget (Union[int, float]): Number of iterations if integer, number of seconds if float.
+        exploration (Optional[float], optional): UCT exploration constant. Defaults to sqrt(2).
+        determinize (Optional[bool], optional): Whether to resample information hidden from the deciding player
+            before every iteration (see `PyWingspanEnv.determinize`). Defaults to True.
+        max_rollout_steps (Optional[int], optional): Maximum number of steps in a rollout,
+            after which it is scored as is. Defaults to None (play until the end).
+        seed (Optional[int], optional): Seed of the search. Defaults to None (random).
+        rollout_policy (Optional[Callable[[PyWingspanEnv, list[int]], int]], optional): Chooses actions in rollouts.
+            It is called with a copy of the environment and its valid actions, and returns one of them.
+            Each call takes the GIL, so it is much slower than the default. Defaults to None (uniformly random).
+
+    Raises:
+        ValueError: If game has already ended, budget is invalid or rollout policy chose an invalid action.
+        Exception: Any exception raised by the rollout policy.
+
+    Returns:
+        tuple[int, dict[int, tuple[int, float]]]: Chosen (most visited) action,
+            and a mapping from each explored action to its number of visits and mean reward.
+    """
+    ...
+
+class BatchWingspanEnv:
+    """Many environments stepped together with a single call.
+
+    Environments are stepped natively, without holding the GIL, and optionally on a pool of threads.
+    Games that end are reset automatically, so returned observations always describe a pending decision.
+    Final observation and result of such games are returned in `infos` of `step`.
+    Replays and undo history are not recorded.
+    """
+
+    def __init__(
+        self,
+        num_envs: int,
+        hand_limit: Optional[int] = None,
+        num_players: Optional[int] = None,
+        num_threads: Optional[int] = None,
+        num_rounds: Optional[int] = None,
+        expansions: Optional[list["Expansion"]] = None,
+        scoring_style: Optional["EndOfRoundScoring"] = None,
+        reward_mode: Optional["RewardMode"] = None,
+        mat_layout: Optional["MatLayout"] = None,
+    ) -> None:
+        """Creates a batch of environments with the same config.
+
+        Args:
+            num_envs (int): Number of environments.
+            hand_limit (Optional[int], optional): Maximum number of bird cards in hand. Defaults to 20.
+            num_players (Optional[int], optional): Number of players in each game. Defaults to 2.
+            num_threads (Optional[int], optional): Size of the thread pool used for stepping.
+                0 uses one thread per CPU. Defaults to None (environments are stepped on the calling thread).
+            num_rounds (Optional[int], optional): Number of rounds in a game, between 1 and 4. Defaults to 4.
+            expansions (Optional[list[Expansion]], optional): Expansions used in a game. Defaults to `[Expansion.Core]`.
+            scoring_style (Optional[EndOfRoundScoring], optional): How end of round goals are scored.
+                Defaults to `EndOfRoundScoring.Competitive`.
+            reward_mode (Optional[RewardMode], optional): How rewards are computed. Defaults to `RewardMode.Points`.
+            mat_layout (Optional[MatLayout], optional): Player mat used in each game. Defaults to `MatLayout.Core`.
+
+        Raises:
+            ValueError: If config or thread pool cannot be built.
+        """
+        ...
+
+    @property
+    def config(self) -> "WingspanEnvConfig":
+        """Settings shared by all of the environments."""
+        ...
+
+    @property
+    def num_envs(self) -> int: ...
+    def reset(
+        self, seed: Optional[int] = None
+    ) -> tuple[npt.NDArray[np.float32], npt.NDArray[np.bool_]]:
+        """Resets all of the environments.
+
+        Args:
+            seed (Optional[int], optional): If provided, `i`-th environment is seeded with `seed + i`. Defaults to None.
+
+        Returns:
+            tuple[npt.NDArray[np.float32], npt.NDArray[np.bool_]]: Observations `(num_envs, observation_size)`
+                and action masks `(num_envs, action_mask_size)`.
+        """
+        ...
+
+    def step(
+        self, actions: Union[list[int], npt.NDArray[np.integer]]
+    ) -> tuple[
+        npt.NDArray[np.float32],
+        npt.NDArray[np.bool_],
+        npt.NDArray[np.float64],
+        npt.NDArray[np.bool_],
+        dict[str, npt.NDArray[Any]],
+    ]:
+        """Performs `actions[i]` in `i`-th environment.
+
+        Invalid actions leave the environment unchanged, with 0 reward.
+        Environments, in which game ended, are reset and return observation of the new game.
+        Like in gymnasium vector environments, the game that ended is described by `infos`:
+            - `final_observation` - object array with the last observation (of the player to move) for each reset environment, None otherwise.
+            - `final_info` - object array with `{"game_result": GameResult}` for each reset environment, None otherwise.
+            - `_final_observation`, `_final_info` - masks of the reset environments (same as `dones`).
+
+        Args:
+            actions (Union[list[int], npt.NDArray[np.integer]]): One action per environment.
+
+        Raises:
+            ValueError: If number of actions does not match the number of environments.
+
+        Returns:
+            tuple: Stacked (observations, action_masks, rewards, dones), and infos.
+                Reward is the one of the player that performed the action (see `RewardMode`).
+        """
+        ...
+
+    def player_idxs(self) -> npt.NDArray[np.uint64]:
+        """Index of the player making the next decision in each of the environments."""
+        ...
+
+class WingspanEnvConfig:
+    """Settings of the environment. Read-only, see `PyWingspanEnv` for description of each of them."""
+
+    @property
+    def hand_limit(self) -> int: ...
+    @property
+    def num_players(self) -> int: ...
+    @property
+    def num_rounds(self) -> int: ...
+    @property
+    def expansions(self) -> list["Expansion"]: ...
+    @property
+    def scoring_style(self) -> "EndOfRoundScoring": ...
+    @property
+    def mat_layout(self) -> "MatLayout": ...
+    @property
+    def reward_mode(self) -> "RewardMode": ...
+    @property
+    def record_replay(self) -> bool: ...
+    @property
+    def undo_history_size(self) -> int: ...
+
+class GameResult:
+    """Final standings of the players.
+
+    Player with the most points wins. Ties are broken by unused food in player's supply,
+    and players that are still tied share the victory (or the place).
+    """
+
+    @property
+    def scores(self) -> list[int]: ...
+    @property
+    def unused_food(self) -> list[int]:
+        """Tiebreaker for players with the same score."""
+        ...
+
+    @property
+    def ranks(self) -> list[int]:
+        """Place of each player (0 is first). Players tied on both score and unused food share the best of their places."""
+        ...
+
+    @property
+    def winners(self) -> list[int]:
+        """Indexes of the players with rank 0."""
+        ...
+
+    @property
+    def shared_victory(self) -> bool:
+        """Whether more than one player won."""
+        ...
+
+    def win_shares(self) -> list[float]:
+        """1 for a sole winner, split equally in case of a shared victory. 0 for everyone else."""
+        ...
+
+    def zero_sum_rewards(self) -> list[float]:
+        """Win shares shifted, so that they sum up to 0."""
+        ...
+
+    def rank_rewards(self) -> list[float]:
+        """Linear in place, from 1 for the first place to -1 for the last one. Sums up to 0.
+
+        Tied players get the average of the places they occupy.
+        """
+        ...
+
+class BonusCardScore:
+    """Points from a single bonus card."""
+
+    @property
+    def bonus_card(self) -> "BonusCard": ...
+    @property
+    def num_matching(self) -> int:
+        """Number of birds (or other items) matching the card."""
+        ...
+
+    @property
+    def points(self) -> int: ...
+
+class ScoreBreakdown:
+    """Score of a player split into the categories of the official score pad."""
+
+    @property
+    def bird_points(self) -> int: ...
+    @property
+    def bonus_card_points(self) -> int: ...
+    @property
+    def bonus_cards(self) -> list[BonusCardScore]:
+        """Points from each of the bonus cards, in order in which player holds them."""
+        ...
+
+    @property
+    def round_goal_points(self) -> int: ...
+    @property
+    def egg_points(self) -> int: ...
+    @property
+    def cached_food_points(self) -> int: ...
+    @property
+    def tucked_card_points(self) -> int: ...
+    @property
+    def nectar_points(self) -> int:
+        """Majority of spent nectar in each of the habitats (Oceania). 0 until Oceania is supported."""
+        ...
+    @property
+    def total(self) -> int:
+        """Sum of all of the categories above."""
+        ...
+
+    @property
+    def unused_food(self) -> int:
+        """Food left in player's supply. It is not worth any points, but it breaks ties in total score."""
+        ...
+
+class RoundGoalScore:
+    """Result of a single player for the goal of a single round."""
+
+    @property
+    def player_idx(self) -> int: ...
+    @property
+    def num_matching(self) -> int:
+        """Number of items (birds, eggs, etc.) matching the goal at the end of the round."""
+        ...
+
+    @property
+    def placement(self) -> Optional[int]:
+        """Place among players with at least one matching item (0 is first), regardless of the scoring style.
+
+        Tied players share the best of their places, and the following places are skipped.
+        None if player had no matching items.
+        """
+        ...
+
+    @property
+    def num_tied(self) -> int:
+        """Number of players sharing the placement (incl. this one). 0 if there is no placement."""
+        ...
+
+    @property
+    def points(self) -> int:
+        """Points awarded. On the competitive side, it is the player's part of the points split among tied players."""
+        ...
+
+class RoundGoalRecord:
+    """Goal of a single round, together with results of all of the players."""
+
+    @property
+    def round_idx(self) -> int: ...
+    @property
+    def goal(self) -> str:
+        """Description of the goal (i.e. "EggsInHabitat(Forest)")."""
+        ...
+
+    @property
+    def goal_id(self) -> int:
+        """Index of the goal among sides of all the goal tiles (observation stores it shifted by 1)."""
+        ...
+
+    @property
+    def scoring_style(self) -> "EndOfRoundScoring": ...
+    @property
+    def scores(self) -> list[RoundGoalScore]:
+        """Results indexed by player."""
+        ...
+
+class EndOfRoundScoring(Enum):
+    """How end of round goals are scored.
+
+    Competitive uses the green side of the scoring board (points depend on placement among players),
+    while Friendly uses the blue side (1 point per item, up to 5 in every round).
+    """
+
+    Competitive = 0
+    Friendly = 1
+
+class RewardMode(Enum):
+    """How rewards are computed for each of the players after every step.
+
+    Points and ScoreMargin are dense, and sum up to points (or margin) gained by a player over the game.
+    WinLoss and Rank are 0 until the last step of the game.
+    """
+
+    Points = 0
+    """Change in player's points."""
+    ScoreMargin = 1
+    """Change in difference between player's points and points of the best opponent."""
+    WinLoss = 2
+    """Player's share of the win (see `GameResult.win_shares`), given at the end of the game."""
+    Rank = 3
+    """Reward based on player's place (see `GameResult.rank_rewards`), given at the end of the game."""
+
+class MatLayout(Enum):
+    """Player mat, which defines rewards of the habitat actions for each number of birds in the habitat."""
+
+    Core = 0
+    """Player mat of the base game."""
+
 class StepResult(Enum):
     """Internal enum describing result of the action."""
 
@@ -97,6 +712,101 @@ class StepResult(Enum):
     Terminated = 1
     Invalid = 2
 
+class GameEvent:
+    """Something that happened in the game.
+
+    Each of the kinds of events is a subclass (i.e. `GameEvent.EggLaid`), with fields exposed as attributes.
+    Birds are identified by `player_idx` of their owner, `habitat` and `bird_idx` (index in that habitat's row).
+    """
+
+    class BirdPlayed(GameEvent):
+        player_idx: int
+        bird_card: BirdCard
+        habitat: Habitat
+        bird_idx: int
+
+    class BirdPowerActivated(GameEvent):
+        """Brown, white, teal or yellow power of a bird was activated."""
+
+        player_idx: int
+        bird_card: BirdCard
+        habitat: Habitat
+        bird_idx: int
+
+    class PinkPowerTriggered(GameEvent):
+        """Pink power of a bird reacted to an action of another player."""
+
+        player_idx: int
+        bird_card: BirdCard
+        habitat: Habitat
+        bird_idx: int
+
+    class PredatorSucceeded(GameEvent):
+        player_idx: int
+        bird_card: BirdCard
+        habitat: Habitat
+        bird_idx: int
+
+    class FoodGainedFromFeeder(GameEvent):
+        player_idx: int
+        food: FoodIndex
+
+    class FoodGainedFromSupply(GameEvent):
+        player_idx: int
+        food: FoodIndex
+
+    class BirdFeederRerolled(GameEvent):
+        player_idx: int
+
+    class FoodDiscarded(GameEvent):
+        player_idx: int
+        food: FoodIndex
+        num_food: int
+
+    class EggLaid(GameEvent):
+        player_idx: int
+        habitat: Habitat
+        bird_idx: int
+
+    class EggDiscarded(GameEvent):
+        player_idx: int
+        habitat: Habitat
+        bird_idx: int
+
+    class BirdCardDrawn(GameEvent):
+        player_idx: int
+        bird_card: BirdCard
+        from_display: bool
+
+    class BirdCardDiscarded(GameEvent):
+        player_idx: int
+        bird_card: BirdCard
+
+    class BonusCardDiscarded(GameEvent):
+        player_idx: int
+        bonus_card: BonusCard
+
+    class CardTucked(GameEvent):
+        player_idx: int
+        habitat: Habitat
+        bird_idx: int
+
+    class FoodCached(GameEvent):
+        player_idx: int
+        habitat: Habitat
+        bird_idx: int
+        food: FoodIndex
+
+    class BirdMoved(GameEvent):
+        player_idx: int
+        bird_card: BirdCard
+        habitat: Habitat
+
+    class RoundGoalScored(GameEvent):
+        round_idx: int
+        player_idx: int
+        points: int
+
 class PyAction:
     """A type of action that can be performed."""
 
@@ -104,6 +814,56 @@ class PyAction:
         """String representation of this PyAction."""
         ...
 
+    @property
+    def action_type(self) -> ActionType:
+        """Type of this action."""
+        ...
+
+class ActionType(Enum):
+    """Type of action, without any of its parameters.
+
+    Note:
+        Food choices of `DiscardFoodOrBirdCard` and `DiscardFood` cover only the foods in the game.
+        Nectar (index 5) is one of them only when playing with Oceania, so otherwise
+        cards in hand of `DiscardFoodOrBirdCard` start at index 5.
+    """
+
+    ChooseAction = 0
+    BirdActionFromHabitat = 1
+    EndOfRoundPowers = 2
+    EndOfGamePowers = 3
+    PlayBird = 4
+    PlayBirdHabitat = 5
+    GetFood = 6
+    GetFoodFromSupplyChoice = 7
+    GetFoodFromFeederChoice = 8
+    GetEgg = 9
+    GetEggAtLoc = 10
+    GetEggChoice = 11
+    GetBirdCard = 12
+    GetBirdCardFromDeck = 13
+    GetBirdCardFromDisplay = 14
+    DiscardFoodOrBirdCard = 15
+    """Indexes 0-5 discard food by FoodIndex, index `6 + i` discards `i`-th card in hand."""
+    DiscardBirdCard = 16
+    TuckBirdCard = 17
+    TuckBirdCardFromDeck = 18
+    DiscardBonusCard = 19
+    DiscardFood = 20
+    """Index is FoodIndex of the discarded food."""
+    DiscardFoodChoice = 21
+    PayFood = 22
+    DiscardEgg = 23
+    DiscardEggChoice = 24
+    CacheFoodChoice = 25
+    DoThen = 26
+    Option = 27
+    MultipleActions = 28
+    MoveBird = 29
+    ChangePlayer = 30
+    ChooseThenAction = 31
+    GetCardFromSetAndChangePlayer = 32
+
 class Player:
     """
     Represents a player participating in a game of Wingspan.
@@ -118,7 +878,7 @@ class Player:
         Food tokens that the player currently has.
 
         Returns:
-            bytes: Bytes of length 5.
+            bytes: Bytes of length 6.
                 Indexes correspond food types as defined in FoodIndex.
         """
         ...
@@ -216,7 +976,7 @@ class BirdCard:
         Returns:
             tuple[bytes, int, CostAlternative]: Cost to play a card, represented as a tuple.
                 Members of this tuples mean:
-                    1. `bytes` - Bytes of length 5. Each byte represents cost of each FoodIndex to play the card.
+                    1. `bytes` - Bytes of length 6. Each byte represents cost of each FoodIndex to play the card.
                     2. `int` - Total number of food that one need to pay to play this card
                     3. `CostAlternative` - Whether cost is:
                         - Alternative (i.e. yes - For example "Fish/Seed")
@@ -306,11 +1066,23 @@ class CostAlternative(Enum):
     Yes = 0
     No = 1
 
+class FoodIndex(Enum):
+    """Type of food. Values are used as indexes in per-food arrays (i.e. `Player.foods`)."""
+
+    Invertebrate = 0
+    Seed = 1
+    Fish = 2
+    Fruit = 3
+    Rodent = 4
+    Nectar = 5
+    """Oceania. It is wild when paying for a bird. Not in play until Oceania is supported."""
+
 class Expansion(Enum):
     """Enum representing different expansions in Wingspan.
 
     Note:
-        Currently the only supported expansion is "Core".
+        Currently the supported expansions are "Core" and "European".
+        Nectar is in place for Oceania, but its cards, costs and dice are not.
     """
 
     Core = 0
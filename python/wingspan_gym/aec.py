@@ -0,0 +1,175 @@
+"""PettingZoo AEC (Agent Environment Cycle) interface of the Wingspan Environment.
+
+Requires `pettingzoo` to be installed (`pip install wingspan_gym[pettingzoo]`).
+"""
+
+from typing import Any, Optional
+
+import gymnasium as gym
+import numpy as np
+import numpy.typing as npt
+from pettingzoo import AECEnv
+
+from ._internal import (
+    EndOfRoundScoring,
+    Expansion,
+    MatLayout,
+    PyWingspanEnv,
+    RewardMode,
+    StepResult,
+)
+
+
+class WingspanAECEnv(AECEnv):
+    metadata = {"name": "wingspan_v0", "is_parallelizable": False, "render_modes": []}
+
+    def __init__(
+        self,
+        num_players: int = 2,
+        hand_limit: Optional[int] = None,
+        num_rounds: Optional[int] = None,
+        expansions: Optional[list[Expansion]] = None,
+        scoring_style: Optional[EndOfRoundScoring] = None,
+        reward_mode: Optional[RewardMode] = None,
+        mat_layout: Optional[MatLayout] = None,
+    ):
+        """Multi-agent environment representing a game of Wingspan.
+
+        Each player is a separate agent, named `player_{idx}`.
+        `agent_selection` always points to the player that makes the next decision,
+        which is not necessarily the player whose turn it is (i.e. some bird powers let every player gain food).
+
+        Reward of each agent is computed for the last step according to `reward_mode`,
+        regardless of which agent took it.
+
+        Args:
+            num_players (int, optional): Number of players in the game. Defaults to 2.
+            hand_limit (Optional[int], optional): Maximum number of cards in hand. Defaults to 20.
+            num_rounds (Optional[int], optional): Number of rounds in the game. Defaults to 4.
+            expansions (Optional[list[Expansion]], optional): Expansions used in the game. Defaults to Core only.
+            scoring_style (Optional[EndOfRoundScoring], optional): How end of round goals are scored. Defaults to Competitive.
+            reward_mode (Optional[RewardMode], optional): How rewards of the agents are computed. Defaults to Points.
+            mat_layout (Optional[MatLayout], optional): Player mat used in the game. Defaults to Core.
+
+        Raises:
+            ValueError: If any of the settings is invalid.
+        """
+        super().__init__()
+        self._inner = PyWingspanEnv(
+            hand_limit=hand_limit,
+            num_players=num_players,
+            num_rounds=num_rounds,
+            expansions=expansions,
+            scoring_style=scoring_style,
+            reward_mode=reward_mode,
+            mat_layout=mat_layout,
+        )
+        self.render_mode = None
+
+        self.possible_agents = [f"player_{idx}" for idx in range(num_players)]
+        self.agent_name_mapping = {
+            agent: idx for idx, agent in enumerate(self.possible_agents)
+        }
+
+        mask_size = self._inner.action_mask_size()
+        obs_size = sum(size for _, size in self._inner.observation_layout())
+        self.action_spaces = {
+            agent: gym.spaces.Discrete(mask_size) for agent in self.possible_agents
+        }
+        self.observation_spaces = {
+            agent: gym.spaces.Dict(
+                {
+                    "observation": gym.spaces.Box(
+                        low=-1.0, high=np.inf, shape=(obs_size,), dtype=np.float32
+                    ),
+                    "action_mask": gym.spaces.Box(
+                        low=0, high=1, shape=(mask_size,), dtype=np.int8
+                    ),
+                }
+            )
+            for agent in self.possible_agents
+        }
+
+    def observation_space(self, agent: str) -> gym.spaces.Space:
+        return self.observation_spaces[agent]
+
+    def action_space(self, agent: str) -> gym.spaces.Space:
+        return self.action_spaces[agent]
+
+    def reset(
+        self, seed: Optional[int] = None, options: Optional[dict[str, Any]] = None
+    ) -> None:
+        """Resets environment to initial state.
+
+        If specified, seed can be used for reproducibility.
+        """
+        if seed is None:
+            seed = int(np.random.default_rng().integers(2**32))
+        self._inner.reset(seed)
+
+        self.agents = self.possible_agents[:]
+        self.rewards = {agent: 0.0 for agent in self.agents}
+        self._cumulative_rewards = {agent: 0.0 for agent in self.agents}
+        self.terminations = {agent: False for agent in self.agents}
+        self.truncations = {agent: False for agent in self.agents}
+        self.infos = {agent: {} for agent in self.agents}
+        self.agent_selection = self.possible_agents[self._inner.player_idx]
+
+    def step(self, action: Optional[int]) -> None:
+        """Performs an action for `agent_selection`.
+
+        Invalid actions do not change the state of the game. They result in rewards of 0,
+        and `infos[agent]["step_result"]` set to `StepResult.Invalid`.
+        Once the game has ended, all of the agents are terminated and have to step with `None`.
+        """
+        if (
+            self.terminations[self.agent_selection]
+            or self.truncations[self.agent_selection]
+        ):
+            self._was_dead_step(action)
+            return
+
+        agent = self.agent_selection
+        self._cumulative_rewards[agent] = 0.0
+
+        step_result, rewards = self._inner.step_agents(int(action))
+
+        self.rewards = dict(zip(self.possible_agents, rewards))
+        self.infos = {agent: {} for agent in self.agents}
+        self.infos[agent]["step_result"] = step_result
+
+        if step_result == StepResult.Terminated:
+            self.terminations = {agent: True for agent in self.agents}
+        else:
+            self.agent_selection = self.possible_agents[self._inner.player_idx]
+
+        self._accumulate_rewards()
+
+    def observe(self, agent: str) -> dict[str, npt.NDArray]:
+        """Observation of the game from perspective of a given agent.
+
+        Action mask is only non-zero for the agent that makes the next decision.
+
+        Returns:
+            dict[str, npt.NDArray]: Dictionary with `observation` (see `PyWingspanEnv.observation`) and `action_mask`.
+        """
+        player_idx = self.agent_name_mapping[agent]
+        if player_idx == self._inner.player_idx:
+            action_mask = self._inner.action_mask().astype(np.int8)
+        else:
+            action_mask = np.zeros(self._inner.action_mask_size(), dtype=np.int8)
+
+        return {
+            "observation": self._inner.observation(player_idx),
+            "action_mask": action_mask,
+        }
+
+    def points(self) -> list[int]:
+        """Current point tally for each of the players."""
+        return self._inner.points()
+
+    def render(self) -> None:
+        pass
+
+    def close(self) -> None:
+        pass
@@ -1,35 +1,117 @@
 """Main module containing Wingspan Environment."""
 
-from typing import Optional
+from typing import Any, Optional
 
 import gymnasium as gym
-from ._internal import Player, PyAction, PyWingspanEnv
-
-
-class WingspanEnv(gym.Env):
-    def __init__(self):
+import numpy as np
+import numpy.typing as npt
+from ._internal import (
+    EndOfRoundScoring,
+    Expansion,
+    GameEvent,
+    GameResult,
+    MatLayout,
+    Player,
+    PyAction,
+    PyWingspanEnv,
+    RewardMode,
+    RoundGoalRecord,
+    ScoreBreakdown,
+    WingspanEnvConfig,
+)
+
+
+class WingspanEnv(gym.Env[npt.NDArray[np.float32], int]):
+    def __init__(
+        self,
+        hand_limit: Optional[int] = None,
+        num_players: Optional[int] = None,
+        num_rounds: Optional[int] = None,
+        expansions: Optional[list[Expansion]] = None,
+        scoring_style: Optional[EndOfRoundScoring] = None,
+        record_replay: Optional[bool] = None,
+        undo_history_size: Optional[int] = None,
+        seed: Optional[int] = None,
+        reward_mode: Optional[RewardMode] = None,
+        mat_layout: Optional[MatLayout] = None,
+    ):
         """gym Environment representing a game of Wingspan.
 
         It is single-threaded, but efficient offloading vast majority of operations to native implementation.
+
+        All of the players are controlled through the same environment.
+        Observation, action mask (`info["action_mask"]`) and `info["player_idx"]` always refer to the player
+        that makes the next decision, while reward refers to the player that made the last one.
+
+        All of the settings are optional, and described in `PyWingspanEnv`.
+        If seed is specified, it seeds the first game and every game after `reset` without a seed.
+
+        Raises:
+            ValueError: If any of the settings is invalid.
         """
-        self._inner = PyWingspanEnv()
+        self._inner = PyWingspanEnv(
+            hand_limit=hand_limit,
+            num_players=num_players,
+            record_replay=record_replay,
+            undo_history_size=undo_history_size,
+            num_rounds=num_rounds,
+            expansions=expansions,
+            scoring_style=scoring_style,
+            seed=seed,
+            reward_mode=reward_mode,
+            mat_layout=mat_layout,
+        )
+        if seed is not None:
+            self._np_random, self._np_random_seed = gym.utils.seeding.np_random(seed)
 
         # The biggest action space possible occurs when player needs to choose a card from their hand
-        # or place an egg on a mat (15 choices max)
-        # TODO: Make this into a valid check like the one commented below
-        self.action_space = gym.spaces.Discrete(20)
-        # self.action_space = gym.spaces.Discrete(max(15, self.config.hand_limit)))
+        # and a habitat to play it in. Use `action_mask` to see which actions are valid.
+        self.action_space = gym.spaces.Discrete(self._inner.action_mask_size())
 
-    def reset(self, *, seed: Optional[int] = None):  # pyright: ignore[reportIncompatibleMethodOverride]
+        # Round index is -1 during setup. Everything else is non-negative.
+        obs_size = sum(size for _, size in self._inner.observation_layout())
+        self.observation_space = gym.spaces.Box(
+            low=-1.0, high=np.inf, shape=(obs_size,), dtype=np.float32
+        )
+
+    def reset(
+        self, *, seed: Optional[int] = None, options: Optional[dict[str, Any]] = None
+    ) -> tuple[npt.NDArray[np.float32], dict[str, Any]]:
         """Resets environment to initial state.
 
         If specified, seed can be used for reproducibility.
+
+        Returns:
+            tuple[npt.NDArray[np.float32], dict[str, Any]]: Observation of the first player, and info dictionary.
+        """
+        super().reset(seed=seed)
+        if seed is None:
+            seed = int(self.np_random.integers(2**32))
+        return self._inner.reset(seed)
+
+    def step(
+        self, action: int
+    ) -> tuple[npt.NDArray[np.float32], float, bool, bool, dict[str, Any]]:
+        """Performs an action for the current player.
+
+        Invalid actions do not change the state of the game. They result in a reward of 0,
+        and `info["step_result"]` set to `StepResult.Invalid`.
+
+        Returns:
+            tuple[npt.NDArray[np.float32], float, bool, bool, dict[str, Any]]: Tuple of:
+                1. observation of the player that makes the next decision,
+                2. reward - reward of the player that took the action (see `RewardMode`),
+                3. whether the game has ended,
+                4. whether the game was truncated (always `False`),
+                5. info dictionary with `action_mask`, `player_idx`, `action_type` (of the next action), `step_result`
+                    and `game_result` (final standings, once the game has ended).
         """
-        assert seed is None or seed >= 0
-        self._inner.reset(seed)
+        return self._inner.step(int(action))
 
-    def step(self, action: int):  # pyright: ignore[reportIncompatibleMethodOverride]
-        return self._inner.step(action)
+    @property
+    def config(self) -> WingspanEnvConfig:
+        """Settings the environment was created with."""
+        return self._inner.config
 
     def action_space_size(self) -> int:
         inner_result = self._inner.action_space_size()
@@ -37,6 +119,16 @@ class WingspanEnv(gym.Env):
             raise ValueError("Action space is non-existent in terminated state")
         return inner_result
 
+    def action_mask(self) -> npt.NDArray[np.bool_]:
+        """Mask of the valid actions for the next action.
+
+        Every index marked as `True` is guaranteed to be accepted by `step`.
+
+        Returns:
+            npt.NDArray[np.bool_]: 1-D array of size `action_space.n`.
+        """
+        return self._inner.action_mask()
+
     def cur_player_idx(self) -> int:
         """Returns index of current player
 
@@ -67,6 +159,61 @@ class WingspanEnv(gym.Env):
         """
         return self._inner.points()
 
+    def game_result(self) -> Optional[GameResult]:
+        """Final standings of the players, with ties broken by unused food.
+
+        Returns:
+            Optional[GameResult]: Result of the game. None, until the game has ended.
+        """
+        return self._inner.game_result()
+
+    def score_breakdown(self) -> list[ScoreBreakdown]:
+        """Score of each of the players split into categories (birds, bonus cards, eggs etc.).
+
+        It also includes unused food, which is the official tiebreaker.
+
+        Returns:
+            list[ScoreBreakdown]: Breakdown for each player.
+        """
+        return self._inner.score_breakdown()
+
+    def observation(self, player_idx: Optional[int] = None) -> npt.NDArray[np.float32]:
+        """Fixed-size numeric observation of the game.
+
+        See `PyWingspanEnv.observation` for description of the layout.
+
+        Args:
+            player_idx (Optional[int], optional): Index of the observing player. Defaults to current player.
+
+        Returns:
+            npt.NDArray[np.float32]: 1-D array with the observation.
+        """
+        return self._inner.observation(player_idx)
+
+    def round_goal_history(self) -> list[RoundGoalRecord]:
+        """Goal of each of the rounds scored so far, with goal count, placement and points of every player.
+
+        Returns:
+            list[RoundGoalRecord]: One record per scored round, in order.
+        """
+        return self._inner.round_goal_history()
+
+    def round_goal_placements(self) -> list[list[Optional[int]]]:
+        """Placement of every player on each of the round goals scored so far (see `PyWingspanEnv.round_goal_placements`).
+
+        Returns:
+            list[list[Optional[int]]]: Placements indexed by round and then by player (0 is first place).
+        """
+        return self._inner.round_goal_placements()
+
+    def events(self) -> list[GameEvent]:
+        """Events that happened during the last step (see `GameEvent`).
+
+        Returns:
+            list[GameEvent]: Events in order in which they happened.
+        """
+        return self._inner.events()
+
     def _debug_print_state(self):
         round_idx, player_idx, action, players, callbacks = (
             self._inner._debug_get_state()
@@ -1,18 +1,31 @@
 use crate::{
     bird_card::{BirdCard, BirdCardColor},
     error::{WingError, WingResult},
+    event::GameEvent,
     food::FoodIndex,
     habitat::Habitat,
+    trigger::Trigger,
     wingspan_env::WingspanEnv,
 };
 use pyo3::prelude::*;
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+use serde::{Deserialize, Serialize};
+use strum_macros::{EnumCount, EnumDiscriminants};
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, EnumDiscriminants, Serialize, Deserialize,
+)]
+#[strum_discriminants(name(ActionType))]
+#[strum_discriminants(derive(Hash, PartialOrd, Ord, EnumCount))]
+#[strum_discriminants(pyclass(eq, eq_int))]
 pub enum Action {
     // First decision of the turn (i.e. play a bird, forest, grassland, wetland)
     #[allow(clippy::enum_variant_names)]
     ChooseAction,
     BirdActionFromHabitat(Habitat),
+    // Activates end of round (teal) powers of the current player
+    EndOfRoundPowers,
+    // Activates game end (yellow) powers of the current player
+    EndOfGamePowers,
 
     PlayBird,
     PlayBirdHabitat(Habitat),
@@ -20,11 +33,15 @@ pub enum Action {
     // Get resource actions
     GetFood,
     GetFoodFromSupplyChoice(Box<[FoodIndex]>),
+    // Take 1 die showing one of the foods from the birdfeeder (without rerolling it)
+    GetFoodFromFeederChoice(Box<[FoodIndex]>),
     GetEgg,
     GetEggAtLoc(Habitat, usize, usize),
     GetEggChoice(Box<[(Habitat, usize)]>, EggCapacityOverride),
     GetBirdCard,
     GetBirdCardFromDeck,
+    // Take 1 face-up card from the tray, which can live in the habitat (any card if None)
+    GetBirdCardFromDisplay(Option<Habitat>),
 
     // Discard actions,
     DiscardFoodOrBirdCard,
@@ -32,8 +49,11 @@ pub enum Action {
     TuckBirdCard(Habitat, usize),
     TuckBirdCardFromDeck(Habitat, usize),
     DiscardBonusCard,
-    DiscardFood,
-    DiscardFoodChoice(Box<[(FoodIndex, u8)]>), // Discard food of choice N times
+    // Discard 1 food for a power or trade of a bird/action in the habitat. Spent nectar goes to that habitat
+    DiscardFood(Habitat),
+    DiscardFoodChoice(Box<[(FoodIndex, u8)]>, Habitat), // Discard food of choice N times
+    // Pay 1 food of choice for a bird played in the habitat. Spent nectar goes to that habitat
+    PayFood(Box<[FoodIndex]>, Habitat),
     DiscardEgg,
     DiscardEggChoice(Box<[(Habitat, usize)]>),
     // Cache food of choice N times on specific bird.
@@ -64,6 +84,100 @@ pub enum Action {
     GetCardFromSetAndChangePlayer(Vec<BirdCard>),
 }
 
+// Actions targeting a bird can outlive it being at that spot, i.e. when they are delayed until the end of turn
+fn has_bird(env: &WingspanEnv, habitat: &Habitat, bird_idx: usize) -> bool {
+    bird_idx
+        < env
+            .current_player()
+            .get_mat()
+            .get_row(habitat)
+            .get_birds()
+            .len()
+}
+
+fn has_bird_of_color(env: &WingspanEnv, color: BirdCardColor) -> bool {
+    env.current_player().get_mat().rows().iter().any(|mat_row| {
+        mat_row
+            .get_birds()
+            .iter()
+            .any(|bird_card| bird_card.color() == &color)
+    })
+}
+
+// Indexes of the foods the current player has at least one of
+fn foods_in_supply(env: &WingspanEnv) -> Vec<u8> {
+    env.current_player()
+        .get_foods()
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, food)| if *food > 0 { Some(idx as u8) } else { None })
+        .collect()
+}
+
+// Discards food of the current player, which is spent for something in the habitat.
+// Spent nectar is recorded on that habitat (see nectar majority scoring)
+fn spend_food(
+    env: &mut WingspanEnv,
+    food_idx: FoodIndex,
+    num_food: u8,
+    habitat: &Habitat,
+) -> WingResult<()> {
+    env.current_player_mut().discard_food(food_idx, num_food)?;
+    if food_idx == FoodIndex::Nectar {
+        env.current_player_mut()
+            .get_mat_mut()
+            .get_row_mut(habitat)
+            .spend_nectar(num_food);
+    }
+    env.push_event(GameEvent::FoodDiscarded {
+        player_idx: env.current_player_idx(),
+        food: food_idx,
+        num_food,
+    });
+    Ok(())
+}
+
+// Activates all of the current player's birds of the color (i.e. end of round or game end powers)
+fn activate_all_birds_of_color(env: &mut WingspanEnv, color: BirdCardColor) {
+    let mat_rows = env.current_player().get_mat().rows().map(|row| row.clone());
+    let mut actions = vec![];
+    let mut later_actions = vec![];
+    for mat_row in mat_rows {
+        let (row_actions, mut end_of_turn_actions) = mat_row.get_bird_actions(env, color);
+        // Habitats are resolved top to bottom, so actions of later ones go underneath
+        actions.splice(0..0, row_actions);
+        later_actions.append(&mut end_of_turn_actions);
+    }
+    // There is no turn to end, so what would happen at the end of it happens after all of the powers
+    later_actions.append(&mut actions);
+    env.append_actions(&mut later_actions);
+}
+
+/// Finishes playing a bird: announces it, activates its "when played" power and queues the rest of its payment.
+pub(crate) fn resolve_played_bird(
+    env: &mut WingspanEnv,
+    (bird_card, habitat, bird_idx, mut followup_actions): (BirdCard, Habitat, usize, Vec<Action>),
+) {
+    env.push_event(GameEvent::BirdPlayed {
+        player_idx: env.current_player_idx(),
+        bird_card,
+        habitat,
+        bird_idx,
+    });
+    env.raise_trigger(Trigger::BirdPlayed {
+        player_idx: env.current_player_idx(),
+        habitat,
+    });
+
+    if matches!(*bird_card.color(), BirdCardColor::White | BirdCardColor::Pink) {
+        let mut action_result = bird_card.activate(env, &habitat, bird_idx).unwrap();
+        env.prepend_actions(&mut action_result.end_of_turn_actions);
+        env.append_actions(&mut action_result.immediate_actions);
+    }
+
+    env.append_actions(&mut followup_actions);
+}
+
 impl Action {
     pub fn perform_action(&mut self, action_idx: u8, env: &mut WingspanEnv) -> WingResult<()> {
         match self {
@@ -85,29 +199,55 @@ impl Action {
                     _ => return Err(WingError::InvalidAction),
                 };
 
+                env.raise_trigger(Trigger::HabitatActionTaken {
+                    player_idx: env.current_player_idx(),
+                    habitat,
+                });
                 env.populate_action_queue_from_habitat_action(&habitat);
 
                 Ok(())
             }
             Action::BirdActionFromHabitat(habitat) => {
                 let mat_row = env.current_player().get_mat().get_row(habitat).clone();
-                let (mut actions, mut end_of_turn_actions) = mat_row.get_bird_actions(env);
+                let (mut actions, mut end_of_turn_actions) =
+                    mat_row.get_bird_actions(env, BirdCardColor::Brown);
                 env.prepend_actions(&mut end_of_turn_actions);
                 env.append_actions(&mut actions);
 
                 Ok(())
             }
+            Action::EndOfRoundPowers => {
+                activate_all_birds_of_color(env, BirdCardColor::Teal);
+                Ok(())
+            }
+            Action::EndOfGamePowers => {
+                activate_all_birds_of_color(env, BirdCardColor::Yellow);
+                Ok(())
+            }
             Action::PlayBird | Action::PlayBirdHabitat(_) => {
-                let (bird_card, habitat, bird_idx, mut followup_actions) =
-                    env.current_player_mut().play_a_bird_card(action_idx)?;
+                let (bird_card, habitat, _) = *env
+                    .current_player()
+                    .get_playable_card_hab_combos()
+                    .get(action_idx as usize)
+                    .ok_or(WingError::InvalidAction)?;
 
-                if matches!(*bird_card.color(), BirdCardColor::White | BirdCardColor::Pink) {
-                    let mut action_result = bird_card.activate(env, &habitat, bird_idx).unwrap();
-                    env.prepend_actions(&mut action_result.end_of_turn_actions);
-                    env.append_actions(&mut action_result.immediate_actions);
+                // Some European birds can be paid for in multiple ways (see `BirdCard::after_choice_callback`)
+                let num_payment_options = env
+                    .current_player()
+                    .payment_options(&bird_card, &habitat)
+                    .len();
+                if num_payment_options > 1 {
+                    env.push_action(Action::ChooseThenAction(
+                        num_payment_options as u8,
+                        bird_card,
+                        habitat,
+                        0,
+                    ));
+                    return Ok(());
                 }
 
-                env.append_actions(&mut followup_actions);
+                let played_bird = env.current_player_mut().play_a_bird_card(action_idx)?;
+                resolve_played_bird(env, played_bird);
                 Ok(())
             }
             Action::GetFood => {
@@ -117,9 +257,15 @@ impl Action {
                     self.clone(),
                 )? {
                     crate::bird_feeder::BirdFeederActionResult::GainFood(food_idx) => {
-                        env.current_player_mut().add_food(food_idx, 1)
+                        env.gain_food_from_feeder(food_idx, 1);
                     }
                     crate::bird_feeder::BirdFeederActionResult::FollowupAction(action) => {
+                        // Reroll of the feeder is followed by the same action
+                        if matches!(action, Action::GetFood) {
+                            env.push_event(GameEvent::BirdFeederRerolled {
+                                player_idx: env.current_player_idx(),
+                            });
+                        }
                         env.push_action(action)
                     }
                 }
@@ -130,19 +276,37 @@ impl Action {
                 if action_idx >= choices.len() {
                     Err(WingError::InvalidAction)
                 } else {
-                    env.current_player_mut().add_food(choices[action_idx], 1);
+                    env.gain_food_from_supply(choices[action_idx], 1);
                     Ok(())
                 }
             }
-            Action::GetEgg => env.current_player_mut().get_mat_mut().place_egg(action_idx),
+            Action::GetFoodFromFeederChoice(choices) => {
+                let food_idx = *choices
+                    .get(action_idx as usize)
+                    .ok_or(WingError::InvalidAction)?;
+                env._bird_feeder.take_specific_food(food_idx)?;
+                env.gain_food_from_feeder(food_idx, 1);
+                Ok(())
+            }
+            Action::GetEgg => {
+                let (habitat, bird_idx) =
+                    env.current_player_mut().get_mat_mut().place_egg(action_idx)?;
+                env.push_event(GameEvent::EggLaid {
+                    player_idx: env.current_player_idx(),
+                    habitat,
+                    bird_idx,
+                });
+                env.raise_trigger(Trigger::EggLaid {
+                    player_idx: env.current_player_idx(),
+                    habitat,
+                    bird_idx,
+                });
+                Ok(())
+            }
             Action::GetEggAtLoc(habitat, bird_idx, num_eggs) => {
                 for _ in 0..*num_eggs {
                     // Ignore errors, since some eggs might have succeeded. If user makes bad moves let them
-                    let _ = env
-                        .current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .place_egg_at_exact_bird_idx(*bird_idx, 0);
+                    let _ = env.lay_egg(habitat, *bird_idx, 0);
                 }
 
                 Ok(())
@@ -153,67 +317,110 @@ impl Action {
                     Err(WingError::InvalidAction)
                 } else {
                     let (habitat, bird_idx) = choices[action_idx];
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(&habitat)
-                        .place_egg_at_exact_bird_idx(bird_idx, (*egg_cap_override).into())
+                    env.lay_egg(&habitat, bird_idx, (*egg_cap_override).into())
                 }
             }
             Action::GetBirdCard => {
                 let card = env._bird_deck.draw_card(action_idx)?;
-                env.current_player_mut().add_bird_card(card);
+                env.add_bird_card(card, action_idx > 0);
                 Ok(())
             }
             Action::GetBirdCardFromDeck => {
-                let card = env._bird_deck.draw_cards_from_deck(1)[0];
-                env.current_player_mut().add_bird_card(card);
+                env.draw_bird_cards_from_deck(1);
+                Ok(())
+            }
+            Action::GetBirdCardFromDisplay(_) => {
+                if !self.valid_actions(env).contains(&action_idx) {
+                    return Err(WingError::InvalidAction);
+                }
+                let card = env._bird_deck.draw_card(action_idx + 1)?;
+                env.add_bird_card(card, true);
+                Ok(())
+            }
+            Action::DiscardFoodOrBirdCard => {
+                let player_idx = env.current_player_idx();
+                let num_foods = env.num_foods();
+                let event = match env
+                    .current_player_mut()
+                    .discard_food_or_bird_card(action_idx as usize, num_foods)?
+                {
+                    Some(bird_card) => GameEvent::BirdCardDiscarded {
+                        player_idx,
+                        bird_card,
+                    },
+                    None => GameEvent::FoodDiscarded {
+                        player_idx,
+                        food: FoodIndex::from(action_idx),
+                        num_food: 1,
+                    },
+                };
+                env.push_event(event);
+                Ok(())
+            }
+            Action::DiscardBirdCard => {
+                let bird_card = env
+                    .current_player_mut()
+                    .discard_bird_card(action_idx as usize)?;
+                env.push_event(GameEvent::BirdCardDiscarded {
+                    player_idx: env.current_player_idx(),
+                    bird_card,
+                });
                 Ok(())
             }
-            Action::DiscardFoodOrBirdCard => env
-                .current_player_mut()
-                .discard_food_or_bird_card(action_idx as usize),
-            Action::DiscardBirdCard => env
-                .current_player_mut()
-                .discard_bird_card(action_idx as usize),
             Action::TuckBirdCard(habitat, bird_idx) => {
                 env.current_player_mut()
                     .discard_bird_card(action_idx as usize)?;
-                env.current_player_mut()
-                    .get_mat_mut()
-                    .get_row_mut(habitat)
-                    .tuck_card(*bird_idx);
+                env.tuck_card(habitat, *bird_idx);
 
                 Ok(())
             }
             Action::TuckBirdCardFromDeck(habitat, bird_idx) => {
-                env.current_player_mut()
-                    .get_mat_mut()
-                    .get_row_mut(habitat)
-                    .tuck_card(*bird_idx);
+                env.tuck_card(habitat, *bird_idx);
 
                 Ok(())
             }
-            Action::DiscardBonusCard => env
-                .current_player_mut()
-                .discard_bonus_card(action_idx as usize),
-            Action::DiscardFood => {
-                if action_idx >= 5 {
+            Action::DiscardBonusCard => {
+                let bonus_card = env
+                    .current_player_mut()
+                    .discard_bonus_card(action_idx as usize)?;
+                env.push_event(GameEvent::BonusCardDiscarded {
+                    player_idx: env.current_player_idx(),
+                    bonus_card,
+                });
+                Ok(())
+            }
+            Action::DiscardFood(habitat) => {
+                if action_idx as usize >= env.num_foods() {
                     return Err(WingError::InvalidAction);
                 }
-                env.current_player_mut()
-                    .discard_food(FoodIndex::from(action_idx), 1)
+                spend_food(env, FoodIndex::from(action_idx), 1, habitat)
             }
-            Action::DiscardFoodChoice(choices) => {
+            Action::DiscardFoodChoice(choices, habitat) => {
                 let (food_idx, num_food) = choices
                     .get(action_idx as usize)
                     .ok_or(WingError::InvalidAction)?;
 
-                env.current_player_mut().discard_food(*food_idx, *num_food)
+                spend_food(env, *food_idx, *num_food, habitat)
+            }
+            Action::PayFood(choices, habitat) => {
+                let food_idx = *choices
+                    .get(action_idx as usize)
+                    .ok_or(WingError::InvalidAction)?;
+
+                spend_food(env, food_idx, 1, habitat)
+            }
+            Action::DiscardEgg => {
+                let (habitat, bird_idx) = env
+                    .current_player_mut()
+                    .get_mat_mut()
+                    .discard_egg(action_idx)?;
+                env.push_event(GameEvent::EggDiscarded {
+                    player_idx: env.current_player_idx(),
+                    habitat,
+                    bird_idx,
+                });
+                Ok(())
             }
-            Action::DiscardEgg => env
-                .current_player_mut()
-                .get_mat_mut()
-                .discard_egg(action_idx),
             Action::DiscardEggChoice(choices) => {
                 let action_idx = action_idx as usize;
                 if action_idx >= choices.len() {
@@ -223,16 +430,21 @@ impl Action {
                     env.current_player_mut()
                         .get_mat_mut()
                         .get_row_mut(&habitat)
-                        .discard_egg_at_exact_bird_idx(bird_idx)
+                        .discard_egg_at_exact_bird_idx(bird_idx)?;
+                    env.push_event(GameEvent::EggDiscarded {
+                        player_idx: env.current_player_idx(),
+                        habitat,
+                        bird_idx,
+                    });
+                    Ok(())
                 }
             }
             Action::CacheFoodChoice(foods, habitat, bird_idx) => {
                 let (food_index, num_food) = foods
                     .get(action_idx as usize)
                     .ok_or(WingError::InvalidAction)?;
-                let row = env.current_player_mut().get_mat_mut().get_row_mut(habitat);
                 for _ in 0..*num_food {
-                    row.cache_food(*bird_idx, *food_index);
+                    env.cache_food(habitat, *bird_idx, *food_index);
                 }
                 Ok(())
             }
@@ -242,7 +454,13 @@ impl Action {
                     .ok_or(WingError::InvalidAction)?;
                 env.current_player_mut()
                     .get_mat_mut()
-                    .move_bird(*bird_card, *target_habitat)
+                    .move_bird(*bird_card, *target_habitat)?;
+                env.push_event(GameEvent::BirdMoved {
+                    player_idx: env.current_player_idx(),
+                    bird_card: *bird_card,
+                    habitat: *target_habitat,
+                });
+                Ok(())
             }
             Action::DoThen(a, b) => {
                 match action_idx {
@@ -294,9 +512,12 @@ impl Action {
                     return Err(WingError::InvalidAction);
                 }
 
-                env.current_player_mut()
-                    .add_bird_card(cards.remove(action_idx));
-                env.increment_player_idx();
+                // Every player has seen the set, so card is not hidden
+                let bird_card = cards.remove(action_idx);
+                env.add_bird_card(bird_card, false);
+                env.current_player_mut().reveal_bird_card(bird_card);
+                // Only the choosing player changes. It is still the same player's turn.
+                env.set_current_player((env.current_player_idx() + 1) % env.config().num_players);
 
                 if !cards.is_empty() {
                     env.push_action(Action::GetCardFromSetAndChangePlayer(cards.to_owned()));
@@ -314,6 +535,8 @@ impl Action {
         match self {
             Action::ChooseAction => true,
             Action::BirdActionFromHabitat(_) => true,
+            Action::EndOfRoundPowers => has_bird_of_color(env, BirdCardColor::Teal),
+            Action::EndOfGamePowers => has_bird_of_color(env, BirdCardColor::Yellow),
             Action::PlayBird => env.current_player_mut().can_play_a_bird_card(vec![
                 Habitat::Forest,
                 Habitat::Grassland,
@@ -324,22 +547,28 @@ impl Action {
                 .can_play_a_bird_card(vec![*habitat]),
             Action::GetFood => true,
             Action::GetFoodFromSupplyChoice(_) => true,
+            Action::GetFoodFromFeederChoice(_) => !self.valid_actions(env).is_empty(),
             Action::GetEgg => env.current_player().get_mat().can_place_egg(),
             Action::GetEggAtLoc(_, _, _) => self.action_space_size(env) > 0,
             Action::GetEggChoice(_, _) => !self.valid_actions(env).is_empty(),
             Action::GetBirdCard | Action::GetBirdCardFromDeck => {
                 env.current_player().get_bird_cards().len() < env.config().hand_limit.into()
             }
+            Action::GetBirdCardFromDisplay(_) => {
+                env.current_player().get_bird_cards().len() < env.config().hand_limit.into()
+                    && !self.valid_actions(env).is_empty()
+            }
             Action::DiscardFoodOrBirdCard => {
-                Action::DiscardFood.is_performable(env)
+                env.current_player().can_discard_food()
                     || Action::DiscardBirdCard.is_performable(env)
             }
-            Action::DiscardBirdCard | Action::TuckBirdCard(_, _) => {
-                env.current_player().can_discard_bird_card()
+            Action::DiscardBirdCard => env.current_player().can_discard_bird_card(),
+            Action::TuckBirdCard(habitat, bird_idx) => {
+                env.current_player().can_discard_bird_card() && has_bird(env, habitat, *bird_idx)
             }
             Action::DiscardBonusCard => !env.current_player().get_bonus_cards().is_empty(),
-            Action::DiscardFood => env.current_player().can_discard_food(),
-            Action::DiscardFoodChoice(choices) => {
+            Action::DiscardFood(_) => env.current_player().can_discard_food(),
+            Action::DiscardFoodChoice(choices, _) => {
                 let foods = env.current_player().get_foods();
                 choices
                     .iter()
@@ -347,10 +576,11 @@ impl Action {
                     .reduce(|a, b| a || b)
                     .unwrap_or(true)
             }
+            Action::PayFood(_, _) => !self.valid_actions(env).is_empty(),
             Action::DiscardEgg => env.current_player().get_mat().can_discard_egg(),
             Action::DiscardEggChoice(_) => !self.valid_actions(env).is_empty(),
-            Action::TuckBirdCardFromDeck(_, _) => true,
-            Action::CacheFoodChoice(_, _, _) => true,
+            Action::TuckBirdCardFromDeck(habitat, bird_idx)
+            | Action::CacheFoodChoice(_, habitat, bird_idx) => has_bird(env, habitat, *bird_idx),
             Action::MoveBird(_, _) => self.action_space_size(env) > 0,
             Action::DoThen(action_req, action_reward) => {
                 action_req.is_performable(env) && action_reward.is_performable(env)
@@ -366,14 +596,18 @@ impl Action {
     pub fn action_space_size(&self, env: &WingspanEnv) -> usize {
         match self {
             Action::ChooseAction => 4,
-            Action::BirdActionFromHabitat(_) => 1,
+            Action::BirdActionFromHabitat(_)
+            | Action::EndOfRoundPowers
+            | Action::EndOfGamePowers => 1,
             Action::PlayBird => env.current_player().get_playable_card_hab_combos().len(),
             Action::PlayBirdHabitat(_) => {
                 // Note: card habitat combos are populated with only that habitat
                 env.current_player().get_playable_card_hab_combos().len()
             }
             Action::GetFood => env._bird_feeder.num_actions(),
-            Action::GetFoodFromSupplyChoice(choices) => choices.len(),
+            Action::GetFoodFromSupplyChoice(choices) | Action::GetFoodFromFeederChoice(choices) => {
+                choices.len()
+            }
             Action::GetEgg => env.current_player().get_mat().num_spots_to_place_eggs(),
             Action::GetEggAtLoc(habitat, bird_idx, _) => {
                 let mat_row = env.current_player().get_mat().get_row(habitat);
@@ -388,24 +622,33 @@ impl Action {
             Action::GetEggChoice(choices, _) => choices.len(),
             Action::GetBirdCard => env._bird_deck.num_actions(),
             Action::GetBirdCardFromDeck => 1,
-            Action::DiscardFoodOrBirdCard => 5 + env.current_player().get_bird_cards().len(),
+            Action::GetBirdCardFromDisplay(_) => env._bird_deck.get_display_cards().len(),
+            Action::DiscardFoodOrBirdCard => {
+                env.num_foods() + env.current_player().get_bird_cards().len()
+            }
             Action::DiscardBirdCard | Action::TuckBirdCard(_, _) => {
                 env.current_player().get_bird_cards().len()
             }
             Action::DiscardBonusCard => env.current_player().get_bonus_cards().len(),
             Action::TuckBirdCardFromDeck(_, _) => 1,
-            Action::DiscardFood => 5,
-            Action::DiscardFoodChoice(choices) => choices.len(),
+            Action::DiscardFood(_) => env.num_foods(),
+            Action::DiscardFoodChoice(choices, _) => choices.len(),
+            Action::PayFood(choices, _) => choices.len(),
             Action::DiscardEgg => env.current_player().get_mat().num_spots_to_discard_eggs(),
             Action::DiscardEggChoice(choices) => choices.len(),
             Action::CacheFoodChoice(food_choices, _, _) => food_choices.len(),
-            Action::MoveBird(bird_card, habitats) => env
-                .current_player()
-                .get_mat()
-                .playable_habitats(bird_card)
-                .iter()
-                .filter(|hab| habitats.contains(hab))
-                .count(),
+            Action::MoveBird(bird_card, habitats) => {
+                let mat = env.current_player().get_mat();
+                // Bird may have been covered by another one before the move resolves
+                if mat.find_bird(bird_card).is_none() {
+                    return 0;
+                }
+
+                mat.playable_habitats(bird_card)
+                    .iter()
+                    .filter(|hab| habitats.contains(hab))
+                    .count()
+            }
             // Do it or not
             Action::DoThen(_, _) => 2,
             Action::Option(_) => 2,
@@ -425,38 +668,55 @@ impl Action {
                     vec![1, 2, 3]
                 }
             }
-            Action::BirdActionFromHabitat(_) => vec![0],
+            Action::BirdActionFromHabitat(_)
+            | Action::EndOfRoundPowers
+            | Action::EndOfGamePowers => vec![0],
             Action::DiscardFoodOrBirdCard => {
-                let mut result = Action::DiscardFood.valid_actions(env);
+                let mut result = foods_in_supply(env);
                 result.extend(
                     Action::DiscardBirdCard
                         .valid_actions(env)
                         .into_iter()
-                        .map(|idx| 5 + idx),
+                        .map(|idx| env.num_foods() as u8 + idx),
                 );
                 result
             }
-            Action::DiscardFood => env
-                .current_player()
-                .get_foods()
-                .iter()
-                .enumerate()
-                .filter_map(|(idx, food)| if *food > 0 { Some(idx as u8) } else { None })
-                .collect(),
-            Action::DiscardFoodChoice(choices) => {
+            Action::DiscardFood(_) => foods_in_supply(env),
+            Action::DiscardFoodChoice(choices, _) => {
                 let foods = &env.current_player().get_foods();
                 choices
                     .iter()
-                    .filter_map(|(idx, cost)| {
-                        let idx = *idx;
-                        if foods[idx as usize] >= *cost {
-                            Some(idx as u8)
+                    .enumerate()
+                    .filter_map(|(choice_idx, (food_idx, cost))| {
+                        if foods[*food_idx as usize] >= *cost {
+                            Some(choice_idx as u8)
                         } else {
                             None
                         }
                     })
                     .collect()
             }
+            Action::PayFood(choices, _) => {
+                let foods = env.current_player().get_foods();
+                choices
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(choice_idx, food_idx)| {
+                        (foods[*food_idx as usize] > 0).then_some(choice_idx as u8)
+                    })
+                    .collect()
+            }
+            Action::GetFoodFromFeederChoice(choices) => choices
+                .iter()
+                .enumerate()
+                .filter_map(|(choice_idx, food_idx)| {
+                    if env._bird_feeder.contains(*food_idx) > 0 {
+                        Some(choice_idx as u8)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
             Action::GetEggChoice(choices, egg_cap_override) => choices
                 .iter()
                 .enumerate()
@@ -489,7 +749,25 @@ impl Action {
                     }
                 })
                 .collect(),
-            Action::MoveBird(_, habs) => (0..habs.len() as u8).collect(),
+            Action::GetBirdCardFromDisplay(habitat) => env
+                ._bird_deck
+                .get_display_cards()
+                .iter()
+                .enumerate()
+                .filter_map(|(display_idx, bird_card)| {
+                    habitat
+                        .is_none_or(|habitat| bird_card.habitats().contains(&habitat))
+                        .then_some(display_idx as u8)
+                })
+                .collect(),
+            Action::MoveBird(bird_card, habs) => {
+                let playable_habitats = env.current_player().get_mat().playable_habitats(bird_card);
+                habs.iter()
+                    .enumerate()
+                    .filter(|(_, hab)| playable_habitats.contains(hab))
+                    .map(|(idx, _)| idx as u8)
+                    .collect()
+            }
             Action::PlayBird
             | Action::PlayBirdHabitat(_)
             | Action::GetFood
@@ -546,15 +824,21 @@ impl PyAction {
     pub fn __str__(&self) -> String {
         format!("{:?}", self.inner)
     }
+
+    #[getter]
+    pub fn action_type(&self) -> ActionType {
+        ActionType::from(&self.inner)
+    }
 }
 
 // Helper things for Specific actions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum EggCapacityOverride {
     None,
     Over(u8),
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for EggCapacityOverride {
     fn default() -> Self {
         Self::None
@@ -0,0 +1,470 @@
+//! Many environments stepped together, meant for training with lots of parallel games.
+//!
+//! Finished games are reset automatically, so every environment always has a pending decision.
+//! Final observation and result of such games are returned alongside the observation of the new game.
+//! Results are stacked into flat, row-major buffers (one row per environment),
+//! which are exposed to python as 2D numpy arrays without any per-environment python objects.
+
+use numpy::{PyArray1, PyArray2, PyArrayMethods};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+
+use crate::{
+    end_of_round_goal::EndOfRoundScoring,
+    error::{WingError, WingResult},
+    expansion::Expansion,
+    game_result::GameResult,
+    mat_layout::MatLayout,
+    observation::observation_size,
+    reward::RewardMode,
+    step_result::StepResult,
+    wingspan_env::{WingspanEnv, WingspanEnvConfig},
+};
+
+/// Stacked results of stepping (or resetting) all of the environments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchStep {
+    /// `num_envs x observation_size` observations of the players making the next decision.
+    pub observations: Vec<f32>,
+    /// `num_envs x action_mask_size` masks of the next decision.
+    pub action_masks: Vec<bool>,
+    /// Reward of the player that performed the action, as configured by `reward_mode`. 0 for invalid actions.
+    pub rewards: Vec<f64>,
+    /// Whether the game ended with this step. Such environments are already reset.
+    pub dones: Vec<bool>,
+    /// For environments that were reset, observation of the player making the next decision
+    /// in the game that just ended (same as `WingspanEnv::observation` before the reset).
+    pub final_observations: Vec<Option<Vec<f32>>>,
+    /// For environments that were reset, result of the game that just ended.
+    pub final_results: Vec<Option<GameResult>>,
+}
+
+#[pyclass]
+pub struct BatchWingspanEnv {
+    config: WingspanEnvConfig,
+    envs: Vec<WingspanEnv>,
+    // Without a pool, environments are stepped one after another on the calling thread
+    thread_pool: Option<ThreadPool>,
+}
+
+// Result of stepping (or resetting) a single environment
+struct EnvStep {
+    // Observation and mask of the next decision
+    observation: Vec<f32>,
+    action_mask: Vec<bool>,
+    reward: f64,
+    done: bool,
+    // Final observation and result of the game, if it ended
+    final_observation: Option<Vec<f32>>,
+    final_result: Option<GameResult>,
+}
+
+fn step_env(env: &mut WingspanEnv, action_idx: u8) -> WingResult<EnvStep> {
+    let acting_player_idx = env.current_player_idx();
+    let (reward, done) = match env.step_with_rewards(action_idx) {
+        Ok((step_result, rewards)) => (
+            rewards[acting_player_idx],
+            step_result == StepResult::Terminated,
+        ),
+        Err(WingError::InvalidAction) => (0.0, false),
+        Err(err) => return Err(err),
+    };
+    let (final_observation, final_result) = if done {
+        let final_observation = env.observation();
+        let final_result = env.game_result();
+        // Seed is drawn from the environment's rng, so the sequence of games is reproducible
+        env.reset(None);
+        (Some(final_observation), final_result)
+    } else {
+        (None, None)
+    };
+
+    Ok(EnvStep {
+        observation: env.observation(),
+        action_mask: env.action_mask(),
+        reward,
+        done,
+        final_observation,
+        final_result,
+    })
+}
+
+fn reset_env(env: &mut WingspanEnv, seed: Option<u64>) -> EnvStep {
+    env.reset(seed);
+    EnvStep {
+        observation: env.observation(),
+        action_mask: env.action_mask(),
+        reward: 0.0,
+        done: false,
+        final_observation: None,
+        final_result: None,
+    }
+}
+
+impl BatchWingspanEnv {
+    pub fn new(config: WingspanEnvConfig, num_envs: usize) -> Self {
+        // Replays and undo history are per game, and there is no way to access them from the batch
+        let mut config = config;
+        config.record_replay = false;
+        config.undo_history_size = 0;
+
+        Self {
+            envs: (0..num_envs)
+                .map(|_| WingspanEnv::try_new(config.clone()))
+                .collect(),
+            config,
+            thread_pool: None,
+        }
+    }
+
+    /// Steps environments on the given pool instead of the calling thread.
+    pub fn with_thread_pool(mut self, thread_pool: ThreadPool) -> Self {
+        self.thread_pool = Some(thread_pool);
+        self
+    }
+
+    pub fn num_envs(&self) -> usize {
+        self.envs.len()
+    }
+
+    pub fn envs(&self) -> &[WingspanEnv] {
+        &self.envs
+    }
+
+    /// Resets all of the environments. If seed is provided, `i`-th environment is seeded with `seed + i`.
+    pub fn reset(&mut self, seed: Option<u64>) -> BatchStep {
+        let env_steps = self.map_envs(|env_idx, env| {
+            Ok(reset_env(
+                env,
+                seed.map(|seed| seed.wrapping_add(env_idx as u64)),
+            ))
+        });
+        // Resetting cannot fail
+        self.stack(env_steps.unwrap())
+    }
+
+    /// Performs `actions[i]` in `i`-th environment.
+    ///
+    /// Invalid actions leave the environment as is (with 0 reward).
+    /// Errors other than invalid action are returned for the first environment that failed.
+    /// It is an error if number of actions does not match the number of environments.
+    pub fn step(&mut self, actions: &[u8]) -> WingResult<BatchStep> {
+        if actions.len() != self.envs.len() {
+            return Err(WingError::ActionCountMismatch(format!(
+                "Expected {} actions, got {}",
+                self.envs.len(),
+                actions.len()
+            )));
+        }
+
+        let env_steps = self.map_envs(|env_idx, env| step_env(env, actions[env_idx]))?;
+        Ok(self.stack(env_steps))
+    }
+
+    fn map_envs<F>(&mut self, f: F) -> WingResult<Vec<EnvStep>>
+    where
+        F: Fn(usize, &mut WingspanEnv) -> WingResult<EnvStep> + Sync,
+    {
+        match &self.thread_pool {
+            Some(thread_pool) => thread_pool.install(|| {
+                self.envs
+                    .par_iter_mut()
+                    .enumerate()
+                    .map(|(env_idx, env)| f(env_idx, env))
+                    .collect()
+            }),
+            None => self
+                .envs
+                .iter_mut()
+                .enumerate()
+                .map(|(env_idx, env)| f(env_idx, env))
+                .collect(),
+        }
+    }
+
+    fn stack(&self, env_steps: Vec<EnvStep>) -> BatchStep {
+        let num_envs = env_steps.len();
+        let mut batch_step = BatchStep {
+            observations: Vec::with_capacity(num_envs * observation_size(&self.config)),
+            action_masks: Vec::with_capacity(num_envs * self.action_mask_size()),
+            rewards: Vec::with_capacity(num_envs),
+            dones: Vec::with_capacity(num_envs),
+            final_observations: Vec::with_capacity(num_envs),
+            final_results: Vec::with_capacity(num_envs),
+        };
+        for env_step in env_steps {
+            batch_step.observations.extend(env_step.observation);
+            batch_step.action_masks.extend(env_step.action_mask);
+            batch_step.rewards.push(env_step.reward);
+            batch_step.dones.push(env_step.done);
+            batch_step
+                .final_observations
+                .push(env_step.final_observation);
+            batch_step.final_results.push(env_step.final_result);
+        }
+
+        batch_step
+    }
+
+    fn action_mask_size(&self) -> usize {
+        self.envs
+            .first()
+            .map(|env| env.action_mask_size())
+            .unwrap_or_default()
+    }
+}
+
+type PyBatchStep<'py> = (
+    Bound<'py, PyArray2<f32>>,
+    Bound<'py, PyArray2<bool>>,
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<bool>>,
+    Bound<'py, PyDict>,
+);
+
+#[pymethods]
+impl BatchWingspanEnv {
+    #[new]
+    #[pyo3(signature = (num_envs, hand_limit=None, num_players=None, num_threads=None, num_rounds=None, expansions=None, scoring_style=None, reward_mode=None, mat_layout=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_new(
+        num_envs: usize,
+        hand_limit: Option<u8>,
+        num_players: Option<usize>,
+        num_threads: Option<usize>,
+        num_rounds: Option<usize>,
+        expansions: Option<Vec<Expansion>>,
+        scoring_style: Option<EndOfRoundScoring>,
+        reward_mode: Option<RewardMode>,
+        mat_layout: Option<MatLayout>,
+    ) -> PyResult<Self> {
+        let config = WingspanEnvConfig::from_py_args(
+            hand_limit,
+            num_players,
+            num_rounds,
+            expansions,
+            scoring_style,
+            reward_mode,
+            None,
+            None,
+            mat_layout,
+        )?;
+
+        let batch_env = Self::new(config, num_envs);
+        match num_threads {
+            Some(num_threads) => {
+                let thread_pool = ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()
+                    .map_err(|err| {
+                        PyValueError::new_err(format!("Error building thread pool: {err}"))
+                    })?;
+                Ok(batch_env.with_thread_pool(thread_pool))
+            }
+            None => Ok(batch_env),
+        }
+    }
+
+    /// Settings shared by all of the environments.
+    #[getter]
+    pub fn config(slf: &Bound<'_, Self>) -> WingspanEnvConfig {
+        slf.borrow().config.clone()
+    }
+
+    #[getter(num_envs)]
+    pub fn py_num_envs(slf: &Bound<'_, Self>) -> usize {
+        slf.borrow().num_envs()
+    }
+
+    /// Resets all of the environments, and returns their observations and action masks.
+    #[allow(clippy::type_complexity)]
+    #[pyo3(name = "reset", signature = (seed=None))]
+    pub fn py_reset<'py>(
+        slf: &Bound<'py, Self>,
+        seed: Option<u64>,
+    ) -> PyResult<(Bound<'py, PyArray2<f32>>, Bound<'py, PyArray2<bool>>)> {
+        let py = slf.py();
+        let batch_step = {
+            let batch_env: &mut Self = &mut slf.borrow_mut();
+            py.allow_threads(|| batch_env.reset(seed))
+        };
+        let (observations, action_masks, _, _, _) = Self::to_numpy(slf, batch_step)?;
+
+        Ok((observations, action_masks))
+    }
+
+    /// Performs one action in each of the environments.
+    ///
+    /// Returns stacked (observations, action_masks, rewards, dones, infos).
+    /// Like in gymnasium vector environments, `infos["final_observation"]` and `infos["final_info"]`
+    /// hold the last observation and `{"game_result": ...}` of environments that were reset (None for others),
+    /// and `infos["_final_observation"]`, `infos["_final_info"]` mark which environments these are.
+    #[pyo3(name = "step")]
+    pub fn py_step<'py>(slf: &Bound<'py, Self>, actions: Vec<u8>) -> PyResult<PyBatchStep<'py>> {
+        let py = slf.py();
+        let batch_step = {
+            let batch_env: &mut Self = &mut slf.borrow_mut();
+            py.allow_threads(|| batch_env.step(&actions))?
+        };
+        Self::to_numpy(slf, batch_step)
+    }
+
+    /// Index of the player making the next decision in each of the environments.
+    pub fn player_idxs<'py>(slf: &Bound<'py, Self>) -> Bound<'py, PyArray1<usize>> {
+        let player_idxs = slf
+            .borrow()
+            .envs
+            .iter()
+            .map(|env| env.current_player_idx())
+            .collect();
+        PyArray1::from_vec(slf.py(), player_idxs)
+    }
+}
+
+impl BatchWingspanEnv {
+    fn to_numpy<'py>(slf: &Bound<'py, Self>, batch_step: BatchStep) -> PyResult<PyBatchStep<'py>> {
+        let py = slf.py();
+        let (num_envs, observation_size, action_mask_size) = {
+            let batch_env = slf.borrow();
+            (
+                batch_env.num_envs(),
+                observation_size(&batch_env.config),
+                batch_env.action_mask_size(),
+            )
+        };
+
+        let final_observations = batch_step
+            .final_observations
+            .into_iter()
+            .map(|obs| match obs {
+                Some(obs) => PyArray1::from_vec(py, obs).into_any().unbind(),
+                None => py.None(),
+            })
+            .collect();
+        let final_infos = batch_step
+            .final_results
+            .into_iter()
+            .zip(batch_step.dones.iter())
+            .map(|(game_result, &done)| {
+                if !done {
+                    return Ok(py.None());
+                }
+                let final_info = PyDict::new(py);
+                final_info.set_item("game_result", game_result)?;
+                Ok(final_info.into_any().unbind())
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let infos = PyDict::new(py);
+        infos.set_item(
+            "final_observation",
+            PyArray1::from_vec(py, final_observations),
+        )?;
+        infos.set_item(
+            "_final_observation",
+            PyArray1::from_slice(py, &batch_step.dones),
+        )?;
+        infos.set_item("final_info", PyArray1::from_vec(py, final_infos))?;
+        infos.set_item("_final_info", PyArray1::from_slice(py, &batch_step.dones))?;
+
+        Ok((
+            PyArray1::from_vec(py, batch_step.observations)
+                .reshape([num_envs, observation_size])?,
+            PyArray1::from_vec(py, batch_step.action_masks)
+                .reshape([num_envs, action_mask_size])?,
+            PyArray1::from_vec(py, batch_step.rewards),
+            PyArray1::from_vec(py, batch_step.dones),
+            infos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    use super::*;
+    use crate::wingspan_env::{test_utils::legal_actions_in_mask, WingspanEnvConfigBuilder};
+
+    fn random_legal_actions(batch_step: &BatchStep, num_envs: usize, rng: &mut StdRng) -> Vec<u8> {
+        batch_step
+            .action_masks
+            .chunks(batch_step.action_masks.len() / num_envs)
+            .map(|mask| *legal_actions_in_mask(mask).choose(rng).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn batch_matches_individual_environments() {
+        let config = WingspanEnvConfigBuilder::default().build().unwrap();
+        let num_envs = 4;
+        let mut batch_env = BatchWingspanEnv::new(config.clone(), num_envs)
+            .with_thread_pool(ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+        let mut envs: Vec<_> = (0..num_envs)
+            .map(|_| WingspanEnv::try_new(config.clone()))
+            .collect();
+
+        let mut batch_step = batch_env.reset(Some(10));
+        for (env_idx, env) in envs.iter_mut().enumerate() {
+            env.reset(Some(10 + env_idx as u64));
+        }
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut num_dones = 0;
+        while num_dones < num_envs {
+            let actions = random_legal_actions(&batch_step, num_envs, &mut rng);
+            batch_step = batch_env.step(&actions).unwrap();
+
+            let obs_size = batch_step.observations.len() / num_envs;
+            for (env_idx, env) in envs.iter_mut().enumerate() {
+                let acting_player_idx = env.current_player_idx();
+                let (step_result, rewards) = env.step_with_rewards(actions[env_idx]).unwrap();
+                let done = step_result == StepResult::Terminated;
+                if done {
+                    assert_eq!(
+                        batch_step.final_observations[env_idx],
+                        Some(env.observation())
+                    );
+                    assert_eq!(batch_step.final_results[env_idx], env.game_result());
+                    assert!(batch_step.final_results[env_idx].is_some());
+                    env.reset(None);
+                    num_dones += 1;
+                } else {
+                    assert!(batch_step.final_observations[env_idx].is_none());
+                    assert!(batch_step.final_results[env_idx].is_none());
+                }
+
+                assert_eq!(batch_step.dones[env_idx], done);
+                assert_eq!(batch_step.rewards[env_idx], rewards[acting_player_idx]);
+                assert_eq!(
+                    batch_step.observations[env_idx * obs_size..(env_idx + 1) * obs_size],
+                    env.observation()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_actions_do_not_change_state() {
+        let config = WingspanEnvConfigBuilder::default().build().unwrap();
+        let mut batch_env = BatchWingspanEnv::new(config, 3);
+        let before = batch_env.reset(Some(0));
+
+        let batch_step = batch_env.step(&[u8::MAX; 3]).unwrap();
+        assert_eq!(batch_step, before);
+    }
+
+    #[test]
+    fn wrong_number_of_actions_is_an_error() {
+        let config = WingspanEnvConfigBuilder::default().build().unwrap();
+        let mut batch_env = BatchWingspanEnv::new(config, 3);
+        let before = batch_env.reset(Some(0));
+
+        assert!(matches!(
+            batch_env.step(&[0; 2]),
+            Err(WingError::ActionCountMismatch(_))
+        ));
+        // Environments are left as they were
+        assert_eq!(batch_env.step(&[u8::MAX; 3]).unwrap(), before);
+    }
+}
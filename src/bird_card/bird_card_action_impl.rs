@@ -1,35 +1,25 @@
-use strum::IntoEnumIterator;
-
 use super::BirdCard;
 use crate::{
-    action::{Action, EggCapacityOverride},
+    action::{resolve_played_bird, Action, EggCapacityOverride},
     bird_card::BirdCardColor,
     bird_card_callback::BirdCardCallback,
     error::{WingError, WingResult},
+    event::GameEvent,
     food::FoodIndex,
     habitat::{Habitat, HABITATS},
     nest::NestType,
+    player::BirdPayment,
+    predator,
+    trigger::Trigger,
     wingspan_env::WingspanEnv,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ActivateResult {
     pub immediate_actions: Vec<Action>,
     pub end_of_turn_actions: Vec<Action>,
-    pub was_successful: bool,
-}
-
-impl Default for ActivateResult {
-    fn default() -> Self {
-        Self {
-            immediate_actions: Default::default(),
-            end_of_turn_actions: Default::default(),
-            was_successful: true,
-        }
-    }
 }
 
-
 impl BirdCard {
     pub fn activate(
         &self,
@@ -37,18 +27,34 @@ impl BirdCard {
         habitat: &Habitat,
         bird_idx: usize,
     ) -> WingResult<ActivateResult> {
+        env.push_event(GameEvent::BirdPowerActivated {
+            player_idx: env.current_player_idx(),
+            bird_card: *self,
+            habitat: *habitat,
+            bird_idx,
+        });
+
         match self {
             Self::BlackTern | Self::ClarksGrebe | Self::ForstersTern => {
                 // draw 1 [card]. if you do, discard 1 [card] from your hand at the end of your turn.
                 Ok(ActivateResult {
                     immediate_actions: vec![Action::GetBirdCard],
                     end_of_turn_actions: vec![Action::DiscardBirdCard],
-                    ..Default::default()
                 })
             }
             Self::RedWattlebird => {
                 // gain 1 [nectar] from the supply for each bird with a wingspan less than 49cm in your [forest].
-                todo!()
+                let num_birds = env
+                    .current_player()
+                    .get_mat()
+                    .get_row(&Habitat::Forest)
+                    .get_birds()
+                    .iter()
+                    .filter(|bird_card| predator::wingspan_less_than(49)(bird_card))
+                    .count();
+
+                env.gain_food_from_supply(FoodIndex::Nectar, num_birds as u8);
+                Ok(Default::default())
             }
             Self::Pukeko => {
                 // lay 1 [egg] on an adjacent bird.
@@ -56,7 +62,21 @@ impl BirdCard {
             }
             Self::ParrotCrossbill => {
                 // remove any 1 [die] from the birdfeeder, then gain 1 [seed] from the supply.
-                todo!()
+                let num_dice = env._bird_feeder.num_dice_in();
+                if num_dice == 0 {
+                    env.gain_food_from_supply(FoodIndex::Seed, 1);
+                    return Ok(Default::default());
+                }
+
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        num_dice as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::VerditerFlycatcher => {
                 // if you have gained a [invertebrate] from the birdfeeder on this turn, gain 1 [fruit] from the supply.
@@ -68,16 +88,35 @@ impl BirdCard {
             }
             Self::CommonGoldeneye => {
                 // lay 1 [egg] on this bird for each other bird with a [cavity] nest that you have.
-                todo!()
+                let num_birds = env
+                    .current_player()
+                    .get_mat()
+                    .get_birds_with_nest_type(&NestType::Cavity)
+                    .iter()
+                    .filter(|(iter_habitat, iter_bird_idx)| {
+                        iter_habitat != habitat || *iter_bird_idx != bird_idx
+                    })
+                    .count();
+
+                for _ in 0..num_birds {
+                    let _ = env.lay_egg(habitat, bird_idx, 0);
+                }
+                Ok(Default::default())
             }
             Self::PhilippineEagle => {
                 // roll all 5 [die]. you may reroll any number of [die], up to 2 times. if at least 3 [rodent] are showing when you stop, draw 2 bonus cards and keep 1. reset the birdfeeder.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::ShortToedTreecreeper => {
                 // discard 1 [egg] from any bird. if you do, play another bird in your [forest]. pay its normal food and egg cost.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DoThen(
+                        Box::new(Action::DiscardEgg),
+                        Box::new(Action::PlayBirdHabitat(Habitat::Forest)),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::AzureTit => {
                 // gain 1 [invertebrate], [seed], or [fruit] from the supply.
@@ -111,16 +150,40 @@ impl BirdCard {
             }
             Self::CommonChaffinch | Self::CommonChiffchaff => {
                 // choose 1-5 birds in this habitat. tuck 1 [card] from your hand behind each.
-                todo!()
+                let num_birds = env
+                    .current_player()
+                    .get_mat()
+                    .get_row(habitat)
+                    .get_birds()
+                    .len();
+
+                // Actions are a LIFO queue, so birds are offered left to right
+                Ok(ActivateResult {
+                    immediate_actions: (0..num_birds)
+                        .rev()
+                        .map(|iter_bird_idx| {
+                            Action::Option(Box::new(Action::TuckBirdCard(*habitat, iter_bird_idx)))
+                        })
+                        .collect(),
+                    ..Default::default()
+                })
             }
             Self::LaughingKookaburra => {
                 // reset the birdfeeder. if you do, gain 1 [invertebrate], [fish], or [rodent], if there is one.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::Dunnock => {
                 // choose 1 other player. for each action cube on their [grassland], lay 1 [egg] on this bird.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        (env.config().num_players - 1) as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::FireFrontedSerin => {
                 // lay 1 [egg] on this bird for each bird to its left in this row.
@@ -135,10 +198,7 @@ impl BirdCard {
             | Self::NorthernBobwhite
             | Self::ScaledQuail => {
                 // lay 1 [egg] on this bird.
-                env.current_player_mut()
-                    .get_mat_mut()
-                    .get_row_mut(habitat)
-                    .place_egg_at_exact_bird_idx(bird_idx, 0)?;
+                env.lay_egg(habitat, bird_idx, 0)?;
                 Ok(Default::default())
             }
             Self::CommonIora => {
@@ -147,11 +207,20 @@ impl BirdCard {
             }
             Self::BlackHeadedGull => {
                 // steal 1 [wild] from another player's supply and add it to your own supply. they gain 1 [die] from the birdfeeder.
-                todo!()
+                let num_choices = foods_to_steal(env, &env.wild_foods()).len();
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        num_choices as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::WhiteThroatedKingfisher => {
                 // choose any 1 [die]. roll it up to 3 times. each time, if you roll a [invertebrate], [fish], or [rodent], cache 1 here. if not, stop and return all food cached here this turn.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::BlueRockThrush => {
@@ -185,12 +254,21 @@ impl BirdCard {
             }
             Self::ForestOwlet => {
                 // choose any 2 [die]. roll them up to 3 times. each time, if you roll at least 1 [invertebrate] or [rodent], cache 1 here. if not, stop and return all food cached here this turn.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::BlackRedstart | Self::LesserWhitethroat => {
                 // choose a habitat with no [egg]. lay 1 [egg] on each bird in that habitat.
-                todo!()
+                let num_choices = habitats_without_eggs(env).len();
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        num_choices as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::CommonTeal => {
                 // for every 3 [egg] in your [wetland], draw 1 [card] from the deck. you may tuck up to 2 [card] from your hand behind this bird.
@@ -202,11 +280,18 @@ impl BirdCard {
             }
             Self::MoltonisWarbler | Self::WhiteWagtail | Self::Yellowhammer => {
                 // if you used all 4 types of actions this round, play another bird. pay its normal food and egg cost.
-                todo!()
+                if env.current_player().used_all_action_types() {
+                    Ok(ActivateResult {
+                        immediate_actions: vec![Action::PlayBird],
+                        ..Default::default()
+                    })
+                } else {
+                    Ok(Default::default())
+                }
             }
             Self::BrahminyKite => {
                 // choose any 3 [die]. roll them up to 3 times. each time, if you roll at least 1 [fish] or [rodent], cache 1 here. if not, stop and return all food cached here this turn.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::CommonGreenMagpie => {
@@ -219,11 +304,19 @@ impl BirdCard {
             }
             Self::LittleBustard | Self::SnowyOwl => {
                 // draw 1 new bonus card. then gain 1 [card] or lay 1 [egg] on any bird.
-                todo!()
+                env.draw_bonus_cards(1);
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(2, *self, *habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::EuropeanTurtleDove => {
                 // draw 1 new bonus card. then gain 1 [die] from the birdfeeder, lay 1 [egg] on any bird, or draw 1 [card].
-                todo!()
+                env.draw_bonus_cards(1);
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(3, *self, *habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::GreenPheasant => {
                 // all players lay 1 [egg].
@@ -235,7 +328,12 @@ impl BirdCard {
             }
             Self::Kakapo => {
                 // draw 4 bonus cards, keep 1, and discard the other 3.
-                todo!()
+                // TODO: Discard should be only of these four cards
+                env.draw_bonus_cards(4);
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DiscardBonusCard; 3],
+                    ..Default::default()
+                })
             }
             Self::Twite => {
                 // draw 2 [card] from the deck and add them to your hand. then, tuck any 2 [card] from your hand behind this bird.
@@ -251,7 +349,16 @@ impl BirdCard {
             }
             Self::RedBackedShrike => {
                 // steal 1 [invertebrate] from another player's supply and cache it on this bird. they gain 1 [die] from the birdfeeder.
-                todo!()
+                let num_choices = foods_to_steal(env, &[FoodIndex::Invertebrate]).len();
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        num_choices as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::WelcomeSwallow => {
                 // tuck 1 [card] from the deck behind each bird in this habitat, including this bird.
@@ -259,7 +366,15 @@ impl BirdCard {
             }
             Self::Korimako => {
                 // discard any number of [rodent] to gain that many [nectar] from the supply.
-                todo!()
+                let num_rodents = env.current_player().get_foods()[FoodIndex::Rodent as usize];
+                Ok(ActivateResult {
+                    immediate_actions: vec![do_then_up_to(
+                        num_rodents as usize,
+                        Action::DiscardFoodChoice(Box::new([(FoodIndex::Rodent, 1)]), *habitat),
+                        Action::GetFoodFromSupplyChoice(Box::new([FoodIndex::Nectar])),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::MuskDuck => {
                 // draw 1 face-up [card] from the tray with a [ground] or [star] nest. you may reset or refill the tray before doing so.
@@ -267,7 +382,20 @@ impl BirdCard {
             }
             Self::RedWingedParrot => {
                 // give 1 [nectar] from your supply to another player. if you do, lay 2 [egg] on this bird or gain 2 [die] from the birdfeeder.
-                todo!()
+                if env.current_player().get_foods()[FoodIndex::Nectar as usize] == 0 {
+                    return Ok(Default::default());
+                }
+
+                // Each choice is a pair of the other player and the reward (see `after_choice_callback`)
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::Option(Box::new(Action::ChooseThenAction(
+                        2 * (env.config().num_players - 1) as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )))],
+                    ..Default::default()
+                })
             }
             Self::CrestedIbis | Self::SpoonBilledSandpiper => {
                 // draw 2 new bonus cards and keep 1. other players may discard any 2 resources ([wild], [egg], or [card]) to do the same.
@@ -276,7 +404,7 @@ impl BirdCard {
             Self::Brant => {
                 // draw the 3 face-up [card] in the bird tray.
                 for bird_card in env._bird_deck.get_display_cards().clone() {
-                    env.current_player_mut().add_bird_card(bird_card);
+                    env.add_bird_card(bird_card, true);
                 }
                 env._bird_deck.reset_display();
                 Ok(Default::default())
@@ -287,11 +415,17 @@ impl BirdCard {
             }
             Self::EasternRosella => {
                 // all players gain 1 [nectar] from the supply. you also gain 1 [seed] from the supply.
-                todo!()
+                all_players_gain_food_from_supply(env, FoodIndex::Nectar);
+                env.gain_food_from_supply(FoodIndex::Seed, 1);
+                Ok(Default::default())
             }
             Self::Hawfinch => {
                 // reset the birdfeeder. if you do, gain 1 [seed] from the birdfeeder after resetting.
-                todo!()
+                // Reset is optional, and the food is gained only if it happens
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(2, *self, *habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::BlackNeckedStilt | Self::CarolinaWren => {
                 // draw 2 [card].
@@ -306,26 +440,38 @@ impl BirdCard {
             }
             Self::Mallard => {
                 // draw 1 [card].
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-                env.current_player_mut().add_bird_card(bird_card);
+                env.draw_bird_cards_from_deck(1);
                 Ok(Default::default())
             }
             Self::Silvereye => {
                 // all players gain 1 [nectar] from the supply.
-                todo!()
+                all_players_gain_food_from_supply(env, FoodIndex::Nectar);
+                Ok(Default::default())
             }
             Self::EleonorasFalcon => {
                 // roll all dice not in the birdfeeder. if any are [rodent], place 1 [egg] on this card.
-                todo!()
+                if predator::hunt_with_dice(env, habitat, bird_idx, FoodIndex::Rodent) > 0 {
+                    let _ = env.lay_egg(habitat, bird_idx, 0);
+                }
+                Ok(Default::default())
             }
             Self::SriLankaFrogmouth => {
                 // roll any 1 [die]. if you roll a [invertebrate], cache 1 [invertebrate] from the supply on this bird. all players may discard 1 [card] from their hand to gain 1 [invertebrate] from the supply.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::LittleOwl => {
                 // steal 1 [rodent] from another player's supply and cache it on this bird. they gain 1 [die] from the birdfeeder.
-                todo!()
+                let num_choices = foods_to_steal(env, &[FoodIndex::Rodent]).len();
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        num_choices as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::NorthIslandBrownKiwi => {
                 // discard a bonus card. if you do, draw 4 bonus cards, keep 2, and discard the other 2.
@@ -333,12 +479,12 @@ impl BirdCard {
             }
             Self::TawnyFrogmouth => {
                 // reset the birdfeeder. cache 1 [invertebrate] or [rodent] from the birdfeeder (if available) on this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::BlackShoulderedKite => {
                 // reset the birdfeeder and gain 1 [rodent], if there is one. you may give it to another player; if you do, lay up to 3 [egg] on this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::AustralianMagpie => {
@@ -347,7 +493,16 @@ impl BirdCard {
             }
             Self::TheklasLark => {
                 // discard 1 [seed] from your supply. if you do, lay 2 [egg] on this bird.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DoThen(
+                        Box::new(Action::DiscardFoodChoice(
+                            Box::new([(FoodIndex::Seed, 1)]),
+                            *habitat,
+                        )),
+                        Box::new(Action::GetEggAtLoc(*habitat, bird_idx, 2)),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::CommonYellowthroat
             | Self::PiedBilledGrebe
@@ -358,7 +513,6 @@ impl BirdCard {
                 Ok(ActivateResult {
                     immediate_actions: vec![Action::GetBirdCard, Action::GetBirdCard],
                     end_of_turn_actions: vec![Action::DiscardBirdCard],
-                    ..Default::default()
                 })
             }
             Self::DarkEyedJunco
@@ -392,20 +546,10 @@ impl BirdCard {
             }
             Self::RufousNightHeron => {
                 // look at a [card] from the deck. if it can live in [wetland], tuck it behind this bird. if not, discard it.
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-
-                let was_successful = bird_card.habitats().contains(&Habitat::Wetland);
-
-                if was_successful {
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .tuck_card(bird_idx);
-                }
-                Ok(ActivateResult {
-                    was_successful,
-                    ..Default::default()
-                })
+                predator::hunt_with_card(env, habitat, bird_idx, |bird_card| {
+                    bird_card.habitats().contains(&Habitat::Wetland)
+                });
+                Ok(Default::default())
             }
             Self::RedVentedBulbul => {
                 // if you have at least 1 [fruit] in your supply, lay 1 [egg] on this bird.
@@ -437,15 +581,17 @@ impl BirdCard {
                     .unwrap();
 
                 let cur_player_idx = env.current_player_idx();
+                let num_players = env.config().num_players;
 
                 let mut actions = Vec::new();
-
-                for player_idx in 0..min_birds_num {
+                // Players take dice in turn order, starting with the current one.
+                // Actions are a LIFO queue, so push them in reverse order.
+                for player_idx in (cur_player_idx..num_players).chain(0..cur_player_idx).rev() {
                     if min_birds_num
                         < env
                             .get_player(player_idx)
                             .get_mat()
-                            .get_row(&Habitat::Wetland)
+                            .get_row(&Habitat::Forest)
                             .get_birds()
                             .len()
                     {
@@ -468,7 +614,14 @@ impl BirdCard {
             }
             Self::RedLeggedPartridge => {
                 // lay 1 [egg] on each bird in this column, including this one.
-                todo!()
+                let birds_in_column = env
+                    .current_player()
+                    .get_mat()
+                    .birds_in_columns_of(habitat, bird_idx);
+                for (iter_habitat, iter_bird_idx) in birds_in_column {
+                    let _ = env.lay_egg(&iter_habitat, iter_bird_idx, 0);
+                }
+                Ok(Default::default())
             }
             Self::WhiteBreastedWoodswallow => {
                 // lay 1 [egg] on each bird in your [grassland], including this one.
@@ -476,22 +629,17 @@ impl BirdCard {
             }
             Self::WedgeTailedEagle => {
                 // look at a [card] from the deck. if its wingspan is over 65cm, tuck it behind this bird and cache 1 [rodent] from the supply on this bird. if not, discard it.
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-
-                let was_successful = match bird_card.wingspan() {
-                    Some(x) => x > 65,
-                    None => true,
-                };
+                let succeeded = predator::hunt_with_card(env, habitat, bird_idx, |bird_card| {
+                    bird_card
+                        .wingspan()
+                        .map(|wingspan| wingspan > 65)
+                        .unwrap_or(true)
+                });
 
-                if was_successful {
-                    let row = env.current_player_mut().get_mat_mut().get_row_mut(habitat);
-                    row.tuck_card(bird_idx);
-                    row.cache_food(bird_idx, FoodIndex::Rodent);
+                if succeeded {
+                    env.cache_food(habitat, bird_idx, FoodIndex::Rodent);
                 }
-                Ok(ActivateResult {
-                    was_successful,
-                    ..Default::default()
-                })
+                Ok(Default::default())
             }
             Self::GreatSpottedWoodpecker => {
                 // gain 1 [invertebrate] or [seed] from the birdfeeder, if there is one.
@@ -499,11 +647,27 @@ impl BirdCard {
             }
             Self::RufousBandedHoneyeater => {
                 // discard 1 [invertebrate]. if you do, gain 1 [nectar] from the supply.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DoThen(
+                        Box::new(Action::DiscardFoodChoice(
+                            Box::new([(FoodIndex::Invertebrate, 1)]),
+                            *habitat,
+                        )),
+                        Box::new(Action::GetFoodFromSupplyChoice(Box::new([
+                            FoodIndex::Nectar,
+                        ]))),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::CommonLittleBittern => {
                 // gain 1 face-up [card] that can live in [grassland].
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::GetBirdCardFromDisplay(Some(
+                        Habitat::Grassland,
+                    ))],
+                    ..Default::default()
+                })
             }
             Self::DesertWheatear => {
                 // for each bird in your [grassland] with an [egg] on it, roll any 1 [die]. choose 1 type of food you rolled, and gain 1 of that food from the supply.
@@ -515,7 +679,41 @@ impl BirdCard {
             }
             Self::MuteSwan => {
                 // choose 1-3 birds in your [wetland]. tuck 1 [card] from your hand behind each. if you tuck at least 1 card, draw 1 [card].
-                todo!()
+                let num_birds = env
+                    .current_player()
+                    .get_mat()
+                    .get_row(&Habitat::Wetland)
+                    .get_birds()
+                    .len();
+                let num_tucks = 3
+                    .min(num_birds)
+                    .min(env.current_player().get_bird_cards().len());
+                if num_tucks == 0 {
+                    return Ok(Default::default());
+                }
+
+                // Birds are chosen one at a time (see `after_choice_callback`).
+                // TODO: The same bird should not be chosen twice
+                let choose_bird =
+                    Action::ChooseThenAction(num_birds as u8, *self, Habitat::Wetland, bird_idx);
+                let mut action = Action::GetBirdCard;
+                if num_tucks > 1 {
+                    let mut optional_tucks = Action::Option(Box::new(choose_bird.clone()));
+                    for _ in 2..num_tucks {
+                        optional_tucks =
+                            Action::DoThen(Box::new(choose_bird.clone()), Box::new(optional_tucks));
+                    }
+                    // Actions are a LIFO queue, so the card is drawn after all of the tucks
+                    action = Action::MultipleActions(vec![action, optional_tucks]);
+                }
+
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DoThen(
+                        Box::new(choose_bird),
+                        Box::new(action),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::AustralasianShoveler => {
                 // choose 1 other player. you both draw 1 [card] from the deck.
@@ -535,7 +733,7 @@ impl BirdCard {
             }
             Self::PurpleHeron => {
                 // choose any 2 [die]. roll them up to 3 times. each time, if you roll at least 1 [invertebrate] or [fish], cache 1 here. if not, stop and return all food cached here this turn.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::BlythsHornbill => {
@@ -555,12 +753,20 @@ impl BirdCard {
             | Self::EurasianSparrowhawk
             | Self::NorthernGoshawk => {
                 // for each [rodent] in this bird's cost, you may pay 1 [card] from your hand instead. if you do, tuck the paid [card] behind this card.
-                // TODO: Mark was_successful here
-                todo!()
+                // Cost is paid when the bird is played (see `Player::payment_options`)
+                Ok(Default::default())
             }
             Self::Bluethroat | Self::CommonNightingale => {
                 // choose a food type. all players gain 1 of that food from the supply.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        env.wild_foods().len() as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::BlackNapedOriole => {
                 // if you used all 4 actions this round, gain 1 [wild] from the supply, lay 1 [egg] on any bird, and draw 1 [card] from the deck.
@@ -572,7 +778,11 @@ impl BirdCard {
             }
             Self::Bullfinch => {
                 // reset the birdfeeder. if you do, gain 1 [seed] or 1 [fruit] from the birdfeeder after resetting.
-                todo!()
+                // Reset is optional, and the food is gained only if it happens
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(2, *self, *habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::RegentBowerbird => {
                 // choose 1 other player. you both gain 1 [invertebrate] from the supply.
@@ -586,7 +796,7 @@ impl BirdCard {
                     .get_row(habitat)
                     .get_birds()
                     .iter()
-                    .filter(|bc| bc.is_predator())
+                    .filter(|bc| bc.is_predator() && *bc.color() == BirdCardColor::Brown)
                     .count();
 
                 if num_choices == 0 {
@@ -605,8 +815,8 @@ impl BirdCard {
             }
             Self::CommonBuzzard | Self::EurasianHobby | Self::MontagusHarrier | Self::RedKite => {
                 // instead of paying any costs, you may play this bird on top of another bird on your player mat. discard any eggs and food from that bird. it becomes a tucked card.
-                // TODO: Mark was_successful here
-                todo!()
+                // Bird is placed when it is played (see `Player::payment_options`)
+                Ok(Default::default())
             }
             Self::BrownShrike => {
                 // all players may cache 1 [invertebrate] from their personal supply on a bird in their [grassland].
@@ -656,11 +866,19 @@ impl BirdCard {
             }
             Self::EuropeanHoneyBuzzard => {
                 // reset the birdfeeder. if you do, gain all [invertebrate] in the birdfeeder after resetting.
-                todo!()
+                // Reset is optional, and the food is gained only if it happens
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(2, *self, *habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::Kereru => {
                 // if the player to your left has a [nectar] in their personal supply, gain 1 [nectar] from the general supply.
-                todo!()
+                let left_player_idx = env.current_player_idx() + 1;
+                if env.get_player(left_player_idx).get_foods()[FoodIndex::Nectar as usize] > 0 {
+                    env.gain_food_from_supply(FoodIndex::Nectar, 1);
+                }
+                Ok(Default::default())
             }
             Self::PlumbeousRedstart => {
                 // draw 1 [card] from the deck and add it to your hand. all other players draw 1 [card] from the deck and add it to their hand if the bird has a [invertebrate] or [seed] in its food cost.
@@ -702,11 +920,7 @@ impl BirdCard {
                     .get_mat()
                     .get_birds_with_nest_type(&goal_nest_type);
                 for (row_idx, bird_idx) in idxs {
-                    let _ = env
-                        .current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(&row_idx)
-                        .place_egg_at_exact_bird_idx(bird_idx, 0);
+                    let _ = env.lay_egg(&row_idx, bird_idx, 0);
                 }
 
                 Ok(Default::default())
@@ -727,15 +941,27 @@ impl BirdCard {
             | Self::WhiteBreastedNuthatch => {
                 // cache 1 [seed] from the supply on this bird.
 
-                env.current_player_mut()
-                    .get_mat_mut()
-                    .get_row_mut(habitat)
-                    .cache_food(bird_idx, FoodIndex::Seed);
+                env.cache_food(habitat, bird_idx, FoodIndex::Seed);
                 Ok(Default::default())
             }
             Self::SulphurCrestedCockatoo => {
                 // tuck 1 [card] from your hand behind this bird. if you do, all players gain 1 [nectar] from the supply.
-                todo!()
+                // Actions are a LIFO queue, so push them in reverse order
+                let mut all_players_gain = vec![Action::ChangePlayer(env.current_player_idx())];
+                for player_idx in (0..env.config().num_players).rev() {
+                    all_players_gain.push(Action::GetFoodFromSupplyChoice(Box::new([
+                        FoodIndex::Nectar,
+                    ])));
+                    all_players_gain.push(Action::ChangePlayer(player_idx));
+                }
+
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DoThen(
+                        Box::new(Action::TuckBirdCard(*habitat, bird_idx)),
+                        Box::new(Action::MultipleActions(all_players_gain)),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::AmericanAvocet
             | Self::BeltedKingfisher
@@ -808,10 +1034,7 @@ impl BirdCard {
                 // if the player to your right has a [seed] in their personal supply, tuck a [card] from the deck behind this bird.
                 let player_to_right = env.get_player(env.current_player_idx() - 1);
                 if player_to_right.get_foods()[FoodIndex::Seed as usize] > 0 {
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .tuck_card(bird_idx);
+                    env.tuck_card(habitat, bird_idx);
                 }
 
                 Ok(Default::default())
@@ -821,13 +1044,11 @@ impl BirdCard {
                 let cur_player_idx = env.current_player_idx();
                 for player_idx in 0..env.config().num_players {
                     env.set_current_player(player_idx);
-                    let mut cards = env._bird_deck.draw_cards_from_deck(2);
-                    env.current_player_mut().append_bird_cards(&mut cards);
+                    env.draw_bird_cards_from_deck(2);
                 }
 
                 env.set_current_player(cur_player_idx);
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-                env.current_player_mut().add_bird_card(bird_card);
+                env.draw_bird_cards_from_deck(1);
 
                 Ok(Default::default())
             }
@@ -839,7 +1060,10 @@ impl BirdCard {
                 // discard 1 [seed]. if you do, lay 1 [egg] on this bird.
                 Ok(ActivateResult {
                     immediate_actions: vec![Action::DoThen(
-                        Box::new(Action::DiscardFoodChoice(Box::new([(FoodIndex::Seed, 1)]))),
+                        Box::new(Action::DiscardFoodChoice(
+                            Box::new([(FoodIndex::Seed, 1)]),
+                            *habitat,
+                        )),
                         Box::new(Action::GetEggAtLoc(*habitat, bird_idx, 1)),
                     )],
                     ..Default::default()
@@ -847,22 +1071,12 @@ impl BirdCard {
             }
             Self::BrownFalcon => {
                 // look at a [card] from the deck. if its food cost includes an [invertebrate] or a [rodent], tuck it behind this bird. if not, discard it.
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-
-                let food_cost = bird_card.cost().0;
-                let was_successful = food_cost[FoodIndex::Rodent as usize].is_some()
-                    || food_cost[FoodIndex::Invertebrate as usize].is_some();
-
-                if was_successful {
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .tuck_card(bird_idx);
-                }
-                Ok(ActivateResult {
-                    was_successful,
-                    ..Default::default()
-                })
+                predator::hunt_with_card(env, habitat, bird_idx, |bird_card| {
+                    let food_cost = bird_card.cost().0;
+                    food_cost[FoodIndex::Rodent as usize].is_some()
+                        || food_cost[FoodIndex::Invertebrate as usize].is_some()
+                });
+                Ok(Default::default())
             }
             Self::WhiteCrestedLaughingthrush => {
                 // tuck 1 [card] from your hand behind this bird. if you do, gain 1 [invertebrate], [seed], or [fruit] from the birdfeeder.
@@ -878,12 +1092,18 @@ impl BirdCard {
             }
             Self::GreatCormorant => {
                 // you may move 1 [fish] from this bird to your supply. then, roll any 2 [die]. if any are [fish], cache 1 [fish] on this bird from the supply.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::NewHollandHoneyeater => {
                 // gain 1 [nectar] from the birdfeeder, if there is one.
-                todo!()
+                // Action is skipped if there is no [nectar] in the birdfeeder
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::GetFoodFromFeederChoice(Box::new([
+                        FoodIndex::Nectar,
+                    ]))],
+                    ..Default::default()
+                })
             }
             Self::AustralianShelduck => {
                 // draw 1 face-up [card] from the tray with a [cavity] or [star] nest. you may reset or refill the tray before doing so.
@@ -922,7 +1142,7 @@ impl BirdCard {
             }
             Self::StorkBilledKingfisher => {
                 // choose any 1 [die]. roll it once for each of your [wetland] birds. if you roll at least 1 [fish], gain 1 from the supply. you may cache it on this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::BlackBelliedWhistlingDuck
@@ -948,7 +1168,7 @@ impl BirdCard {
 
                 Ok(ActivateResult {
                     immediate_actions: vec![Action::DoThen(
-                        Box::new(Action::DiscardFoodChoice(choices)),
+                        Box::new(Action::DiscardFoodChoice(choices, *habitat)),
                         Box::new(Action::MultipleActions(vec![
                             Action::TuckBirdCardFromDeck(*habitat, bird_idx),
                             Action::TuckBirdCardFromDeck(*habitat, bird_idx),
@@ -959,7 +1179,7 @@ impl BirdCard {
             }
             Self::BrownPelican => {
                 // gain 3 [fish] from the supply.
-                env.current_player_mut().add_food(FoodIndex::Fish, 3);
+                env.gain_food_from_supply(FoodIndex::Fish, 3);
                 Ok(Default::default())
             }
             Self::GreenBeeEater => {
@@ -968,11 +1188,35 @@ impl BirdCard {
             }
             Self::EuropeanRobin => {
                 // from the supply, gain 1 food of a type you already gained this turn.
-                todo!()
+                let foods: Box<[FoodIndex]> = env
+                    .foods_gained_this_turn()
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(food_idx, num_food)| {
+                        (*num_food > 0).then_some(FoodIndex::from(food_idx))
+                    })
+                    .collect();
+                if foods.is_empty() {
+                    return Ok(Default::default());
+                }
+
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::GetFoodFromSupplyChoice(foods)],
+                    ..Default::default()
+                })
             }
             Self::CommonKingfisher => {
                 // steal 1 [fish] from another player's supply and cache it on this bird. they gain 1 [die] from the birdfeeder.
-                todo!()
+                let num_choices = foods_to_steal(env, &[FoodIndex::Fish]).len();
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        num_choices as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::MandarinDuck => {
                 // draw 5 [card] from the deck. add 1 to your hand, tuck 1 behind this bird, give 1 to another player, and discard the rest.
@@ -992,7 +1236,7 @@ impl BirdCard {
             }
             Self::WhiteBelliedSeaEagle => {
                 // reset the birdfeeder. gain 1 [fish] or 1 [rodent] from the birdfeeder, if there is one, and cache it on this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::PeacefulDove => {
@@ -1001,11 +1245,23 @@ impl BirdCard {
             }
             Self::GreatTit => {
                 // reset the birdfeeder. if you do, gain 1 [die] from the birdfeeder after resetting.
-                todo!()
+                // Reset is optional, and the food is gained only if it happens
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(2, *self, *habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::EurasianMagpie => {
                 // choose 1 other player. for each action cube on their [grassland], cache 1 [wild] from the supply on any of your birds.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        (env.config().num_players - 1) as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::MajorMitchellsCockatoo => {
                 // tuck 1 [card] from your hand behind this bird. if you do, all players gain 1 [seed] from the supply.
@@ -1037,16 +1293,14 @@ impl BirdCard {
                 let mut actions = Vec::new();
                 for player_idx in 0..env.config().num_players {
                     let mat = env.get_player(player_idx).get_mat();
-                    let mut playable_birds = vec![];
-                    for habitat in HABITATS {
-                        let row = mat.get_row(&habitat);
-                        for (bird_idx, bird_card) in row.get_birds().iter().enumerate() {
-                            if bird_card.nest_type() == &nest_type && row.can_place_egg(bird_idx, 0)
-                            {
-                                playable_birds.push((habitat, bird_idx));
-                            }
-                        }
-                    }
+                    // Wild nests match any nest type
+                    let playable_birds: Vec<_> = mat
+                        .get_birds_with_nest_type(&nest_type)
+                        .into_iter()
+                        .filter(|(habitat, bird_idx)| {
+                            mat.get_row(habitat).can_place_egg(*bird_idx, 0)
+                        })
+                        .collect();
 
                     // If there are birds that satisfy condition, add actions for that
                     if !playable_birds.is_empty() {
@@ -1078,20 +1332,39 @@ impl BirdCard {
             }
             Self::CommonStarling | Self::EurasianCollaredDove => {
                 // discard up to 5 [wild] from your supply. for each, tuck 1 [card] from the deck behind this bird.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![do_then_up_to(
+                        5,
+                        Action::DiscardFood(*habitat),
+                        Action::TuckBirdCardFromDeck(*habitat, bird_idx),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::LittlePenguin => {
                 // draw and discard 5 [card] from the deck. for each [fish] in their food costs, cache 1 [fish] from the supply on this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::PesquetsParrot => {
                 // if the player to your right has a [nectar] in their personal supply, gain 1 [nectar] from the general supply.
-                todo!()
+                let right_player_idx = env.current_player_idx() + env.config().num_players - 1;
+                if env.get_player(right_player_idx).get_foods()[FoodIndex::Nectar as usize] > 0 {
+                    env.gain_food_from_supply(FoodIndex::Nectar, 1);
+                }
+                Ok(Default::default())
             }
             Self::HoodedCrow => {
                 // choose 1 other player. for each action cube on their [grassland], tuck 1 [card] from your hand behind this bird, then draw an equal number of [card].
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        (env.config().num_players - 1) as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::AmericanBittern | Self::CommonLoon => {
                 // player(s) with the fewest birds in their [wetland] draw 1 [card].
@@ -1108,7 +1381,7 @@ impl BirdCard {
 
                 let cur_player_idx = env.current_player_idx();
 
-                for player_idx in 0..min_birds_num {
+                for player_idx in 0..env.config().num_players {
                     if min_birds_num
                         < env
                             .get_player(player_idx)
@@ -1121,8 +1394,7 @@ impl BirdCard {
                     }
 
                     env.set_current_player(player_idx);
-                    let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-                    env.current_player_mut().add_bird_card(bird_card);
+                    env.draw_bird_cards_from_deck(1);
                 }
 
                 env.set_current_player(cur_player_idx);
@@ -1130,45 +1402,46 @@ impl BirdCard {
             }
             Self::WhiteFacedHeron => {
                 // reset the birdfeeder and gain all [fish], if there are any. you may cache any or all of them on this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::GreyShrikethrush => {
                 // reset the birdfeeder and gain all [rodent], if there are any. you may cache any or all of them on this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::GreatCrestedGrebe | Self::WilsonsStormPetrel => {
                 // draw 1 [card] for each empty card slot in this row. at the end of your turn, keep 1 and discard the rest.
-                todo!()
-            }
-            Self::GreaterRoadrunner => {
-                // look at a [card] from the deck. if less than 50cm, tuck it behind this bird. if not, discard it.
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-
-                let was_successful = match bird_card.wingspan() {
-                    Some(x) => x < 50,
-                    None => true,
-                };
-
-                if was_successful {
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .tuck_card(bird_idx);
+                let num_cards = env
+                    .current_player()
+                    .get_mat()
+                    .get_row(habitat)
+                    .num_empty_columns();
+                if num_cards == 0 {
+                    return Ok(Default::default());
                 }
+
+                // TODO: Discard should be only of these cards
+                env.draw_bird_cards_from_deck(num_cards);
                 Ok(ActivateResult {
-                    was_successful,
+                    end_of_turn_actions: vec![Action::DiscardBirdCard; num_cards - 1],
                     ..Default::default()
                 })
             }
+            Self::GreaterRoadrunner => {
+                // look at a [card] from the deck. if less than 50cm, tuck it behind this bird. if not, discard it.
+                predator::hunt_with_card(env, habitat, bird_idx, predator::wingspan_less_than(50));
+                Ok(Default::default())
+            }
             Self::Kea => {
                 // draw 1 bonus card. you may discard any number of [wild] to draw that many additional bonus cards. keep 1 of the cards you drew and discard the rest.
                 todo!()
             }
             Self::CoalTit | Self::EurasianNuthatch => {
                 // gain 1 [seed] from the supply and cache it on this card. at any time, you may spend [seed] cached on this card.
-                todo!()
+                // Cached [seed] can be spent when paying for a bird (see `Player::payment_options`)
+                env.cache_food(habitat, bird_idx, FoodIndex::Seed);
+                Ok(Default::default())
             }
             Self::BlackStork => {
                 // lay 1 [egg] on each of the birds immediately to the left and right of this bird.
@@ -1195,7 +1468,6 @@ impl BirdCard {
             | Self::RedHeadedWoodpecker
             | Self::StellersJay => {
                 // gain 1 [seed] from the birdfeeder, if available. you may cache it on this bird.
-                if env._bird_feeder.count(FoodIndex::Seed) > 0 {};
                 if env
                     ._bird_feeder
                     .take_specific_food(FoodIndex::Seed)
@@ -1205,11 +1477,14 @@ impl BirdCard {
                     return Ok(Default::default());
                 }
                 // Add it to player stash
-                env.current_player_mut().add_food(FoodIndex::Seed, 1);
+                env.gain_food_from_feeder(FoodIndex::Seed, 1);
 
                 Ok(ActivateResult {
                     immediate_actions: vec![Action::DoThen(
-                        Box::new(Action::DiscardFoodChoice(Box::new([(FoodIndex::Seed, 1)]))),
+                        Box::new(Action::DiscardFoodChoice(
+                            Box::new([(FoodIndex::Seed, 1)]),
+                            *habitat,
+                        )),
                         Box::new(Action::CacheFoodChoice(
                             Box::new([(FoodIndex::Seed, 1)]),
                             *habitat,
@@ -1221,7 +1496,17 @@ impl BirdCard {
             }
             Self::CommonSwift => {
                 // discard up to 5 [invertebrate] from your supply. for each, tuck 1 [card] from the deck behind this bird.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![do_then_up_to(
+                        5,
+                        Action::DiscardFoodChoice(
+                            Box::new([(FoodIndex::Invertebrate, 1)]),
+                            *habitat,
+                        ),
+                        Action::TuckBirdCardFromDeck(*habitat, bird_idx),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::BayaWeaver => {
                 // tuck up to 3 [card] from your hand behind this bird. if you tuck at least 1 [card], lay 1 [egg] on this bird.
@@ -1233,7 +1518,14 @@ impl BirdCard {
             }
             Self::Ruff => {
                 // tuck up to 3 [card] from your hand behind this bird. draw 1 [card] for each card you tucked.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![do_then_up_to(
+                        3,
+                        Action::TuckBirdCard(*habitat, bird_idx),
+                        Action::GetBirdCard,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::ManyColoredFruitDove => {
                 // all players gain 1 [fruit] from the supply. you gain 1 additional [fruit] from the supply.
@@ -1245,63 +1537,46 @@ impl BirdCard {
             }
             Self::EuropeanRoller => {
                 // place this bird sideways, so that it covers 2 [grassland] spaces. pay the lower egg cost.
-                todo!()
+                // Bird covers 2 columns when it is put on the mat (see `MatRow::play_a_bird`)
+                Ok(Default::default())
             }
             Self::CrestedPigeon => {
                 // cache up to 8 [seed] from your supply on this bird.
-                todo!()
-            }
-            Self::GoldenEagle | Self::GreatHornedOwl | Self::PeregrineFalcon => {
-                // look at a [card] from the deck. if less than 100cm, tuck it behind this bird. if not, discard it.
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-
-                let was_successful = match bird_card.wingspan() {
-                    Some(x) => x < 100,
-                    None => true,
-                };
-
-                if was_successful {
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .tuck_card(bird_idx);
-                }
                 Ok(ActivateResult {
-                    was_successful,
+                    immediate_actions: vec![do_then_up_to(
+                        8,
+                        Action::DiscardFoodChoice(Box::new([(FoodIndex::Seed, 1)]), *habitat),
+                        Action::CacheFoodChoice(
+                            Box::new([(FoodIndex::Seed, 1)]),
+                            *habitat,
+                            bird_idx,
+                        ),
+                    )],
                     ..Default::default()
                 })
             }
+            Self::GoldenEagle | Self::GreatHornedOwl | Self::PeregrineFalcon => {
+                // look at a [card] from the deck. if less than 100cm, tuck it behind this bird. if not, discard it.
+                predator::hunt_with_card(env, habitat, bird_idx, predator::wingspan_less_than(100));
+                Ok(Default::default())
+            }
             Self::SquaccoHeron => {
                 // gain 1 face-up [card] that can live in [wetland].
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::GetBirdCardFromDisplay(Some(Habitat::Wetland))],
+                    ..Default::default()
+                })
             }
             Self::RoseBreastedGrosbeak => {
                 // gain 1 [seed] or [fruit] from the birdfeeder, if available.
-                let has_seed = env._bird_feeder.contains(FoodIndex::Seed) > 0;
-                let has_fruit = env._bird_feeder.contains(FoodIndex::Fruit) > 0;
-
-                if has_seed && has_fruit {
-                    return Ok(ActivateResult {
-                        immediate_actions: vec![Action::GetFoodFromSupplyChoice(Box::new([
-                            FoodIndex::Seed,
-                            FoodIndex::Fruit,
-                        ]))],
-                        ..Default::default()
-                    });
-                }
-                if has_seed {
-                    env.current_player_mut().add_food(FoodIndex::Seed, 1);
-                    env._bird_feeder
-                        .take_specific_food(FoodIndex::Seed)
-                        .unwrap();
-                } else if has_fruit {
-                    env.current_player_mut().add_food(FoodIndex::Fruit, 1);
-                    env._bird_feeder
-                        .take_specific_food(FoodIndex::Fruit)
-                        .unwrap();
-                }
-
-                Ok(ActivateResult::default())
+                // Action is skipped if neither of them is in the birdfeeder
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::GetFoodFromFeederChoice(Box::new([
+                        FoodIndex::Seed,
+                        FoodIndex::Fruit,
+                    ]))],
+                    ..Default::default()
+                })
             }
             Self::RoyalSpoonbill => {
                 // draw 1 face-up [card] from the tray with a [platform] or [star] nest. you may reset or refill the tray before doing so.
@@ -1341,7 +1616,7 @@ impl BirdCard {
             }
             Self::AmericanGoldfinch => {
                 // gain 3 [seed] from the supply.
-                env.current_player_mut().add_food(FoodIndex::Seed, 3);
+                env.gain_food_from_supply(FoodIndex::Seed, 3);
                 Ok(Default::default())
             }
             Self::AsianEmeraldDove => {
@@ -1364,11 +1639,15 @@ impl BirdCard {
                                 continue;
                             }
 
-                            let _ = env
-                                .current_player_mut()
-                                .get_mat_mut()
-                                .get_row_mut(&cur_row_idx.into())
-                                .place_egg_at_exact_column(col_idx);
+                            let habitat: Habitat = cur_row_idx.into();
+                            let bird_idx = env
+                                .current_player()
+                                .get_mat()
+                                .get_row(&habitat)
+                                .bird_idx_at_column(col_idx);
+                            if let Some(bird_idx) = bird_idx {
+                                let _ = env.lay_egg(&habitat, bird_idx, 0);
+                            }
                         }
                         break;
                     }
@@ -1402,11 +1681,28 @@ impl BirdCard {
             }
             Self::EurasianJay => {
                 // steal 1 [seed] from another player's supply and cache it on this bird. they gain 1 [die] from the birdfeeder.
-                todo!()
+                let num_choices = foods_to_steal(env, &[FoodIndex::Seed]).len();
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        num_choices as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::CarrionCrow | Self::GriffonVulture => {
                 // choose any 1 player (including yourself). cache 1 [rodent] from the supply on this bird for each [predator] that player has.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        env.config().num_players as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::Budgerigar => {
                 // tuck the smallest bird in the tray behind this bird.
@@ -1426,7 +1722,13 @@ impl BirdCard {
             }
             Self::Goldcrest => {
                 // discard 1 [card] from your hand. if you do, play another bird in your [forest]. pay its normal food and egg cost.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DoThen(
+                        Box::new(Action::DiscardBirdCard),
+                        Box::new(Action::PlayBirdHabitat(Habitat::Forest)),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::Anhinga
             | Self::BlackSkimmer
@@ -1460,33 +1762,14 @@ impl BirdCard {
                     _ => panic!("Got bird {self:?} in activation case which it not belongs to"),
                 };
 
-                let dice_idxs = food_idx.dice_sides();
-
-                let dice_out_of_birdfeeder = env
-                    ._bird_feeder
-                    .roll_all_dice_not_in_birdfeeder(&mut env.rng);
-                let num_dice_matching = dice_out_of_birdfeeder
-                    .iter()
-                    .filter(|dice_idx| dice_idxs.contains(dice_idx))
-                    .count();
-
-                let was_successful = if num_dice_matching > 0 {
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .cache_food(bird_idx, food_idx);
-                    true
-                } else {
-                    false
-                };
-                Ok(ActivateResult {
-                    was_successful,
-                    ..Default::default()
-                })
+                if predator::hunt_with_dice(env, habitat, bird_idx, food_idx) > 0 {
+                    env.cache_food(habitat, bird_idx, food_idx);
+                }
+                Ok(Default::default())
             }
             Self::OrientalBayOwl => {
                 // activate the "when activated" (brown) powers of all of your other [predator].
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::Rook => {
@@ -1524,7 +1807,7 @@ impl BirdCard {
                 let cur_player_idx = env.current_player_idx();
                 for player_idx in 0..env.config().num_players {
                     env.set_current_player(player_idx);
-                    env.current_player_mut().add_food(food_type, 1);
+                    env.gain_food_from_supply(food_type, 1);
                 }
 
                 env.set_current_player(cur_player_idx);
@@ -1532,7 +1815,15 @@ impl BirdCard {
             }
             Self::LewinsHoneyeater => {
                 // choose 1 other player. you both gain 1 [nectar] from the supply.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        (env.config().num_players - 1) as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::HimalayanMonal => {
                 // all players gain 1 [seed] from the supply. you also lay 1 [egg].
@@ -1546,7 +1837,11 @@ impl BirdCard {
                     .get_row(habitat)
                     .get_birds()
                     .iter()
-                    .filter(|bc| bc.color() == &BirdCardColor::Brown && *bc != self)
+                    // Repeating another repeat power could loop forever, so those are not eligible
+                    .filter(|bc| {
+                        bc.color() == &BirdCardColor::Brown
+                            && !matches!(bc, Self::GrayCatbird | Self::NorthernMockingbird)
+                    })
                     .count();
 
                 if num_choices == 0 {
@@ -1565,7 +1860,23 @@ impl BirdCard {
             }
             Self::Mistletoebird => {
                 // gain 1 [fruit] from the supply, or discard 1 [fruit] to gain 1 [nectar] from the supply.
-                todo!()
+                // Trade is only a choice if player has a [fruit] to discard
+                let num_choices = if env.current_player().get_foods()[FoodIndex::Fruit as usize] > 0
+                {
+                    2
+                } else {
+                    1
+                };
+
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        num_choices,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::LargeBilledCrow => {
                 // cache 1 [wild] from your supply on any bird. if you do, you may tuck 1 [card] from your hand behind this bird.
@@ -1577,26 +1888,22 @@ impl BirdCard {
             }
             Self::GreyButcherbird => {
                 // look at a [card] from the deck. if its wingspan is less than 40cm, tuck it behind this bird and cache 1 [rodent] from the supply on this bird. if not, discard it.
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-
-                let was_successful = match bird_card.wingspan() {
-                    Some(x) => x < 40,
-                    None => true,
-                };
+                let succeeded = predator::hunt_with_card(
+                    env,
+                    habitat,
+                    bird_idx,
+                    predator::wingspan_less_than(40),
+                );
 
-                if was_successful {
-                    let row = env.current_player_mut().get_mat_mut().get_row_mut(habitat);
-                    row.tuck_card(bird_idx);
-                    row.cache_food(bird_idx, FoodIndex::Rodent);
+                if succeeded {
+                    env.cache_food(habitat, bird_idx, FoodIndex::Rodent);
                 }
-                Ok(ActivateResult {
-                    was_successful,
-                    ..Default::default()
-                })
+                Ok(Default::default())
             }
             Self::CommonBlackbird | Self::LongTailedTit => {
                 // place this bird sideways, so that it covers 2 [forest] spaces. pay the lower egg cost.
-                todo!()
+                // Bird covers 2 columns when it is put on the mat (see `MatRow::play_a_bird`)
+                Ok(Default::default())
             }
             Self::SplendidFairywren => {
                 // lay 1 [egg] on each of your birds with a wingspan less than 30cm, including this one.
@@ -1610,8 +1917,10 @@ impl BirdCard {
                 // discard 1 [egg] from any of your other birds to gain 1 [wild] from the supply.
                 Ok(ActivateResult {
                     immediate_actions: vec![Action::DoThen(
-                        Box::new(Action::DiscardEgg),
-                        Box::new(Action::GetFood),
+                        Box::new(Action::DiscardEggChoice(other_birds_with_eggs(
+                            env, habitat, bird_idx,
+                        ))),
+                        Box::new(Action::GetFoodFromSupplyChoice(env.wild_foods())),
                     )],
                     ..Default::default()
                 })
@@ -1626,7 +1935,33 @@ impl BirdCard {
             }
             Self::EurasianNutcracker => {
                 // choose 1-5 birds in your [forest]. cache 1 [seed] from your supply on each.
-                todo!()
+                let num_birds = env
+                    .current_player()
+                    .get_mat()
+                    .get_row(&Habitat::Forest)
+                    .get_birds()
+                    .len();
+
+                // Actions are a LIFO queue, so birds are offered left to right
+                Ok(ActivateResult {
+                    immediate_actions: (0..num_birds)
+                        .rev()
+                        .map(|iter_bird_idx| {
+                            Action::DoThen(
+                                Box::new(Action::DiscardFoodChoice(
+                                    Box::new([(FoodIndex::Seed, 1)]),
+                                    *habitat,
+                                )),
+                                Box::new(Action::CacheFoodChoice(
+                                    Box::new([(FoodIndex::Seed, 1)]),
+                                    Habitat::Forest,
+                                    iter_bird_idx,
+                                )),
+                            )
+                        })
+                        .collect(),
+                    ..Default::default()
+                })
             }
             Self::RedCrownedCrane => {
                 // score 1 of your bonus cards now by caching 1 [wild] from the supply on this bird for each point. discard that bonus card and draw 1 new one.
@@ -1634,7 +1969,8 @@ impl BirdCard {
             }
             Self::CettisWarbler | Self::EurasianGreenWoodpecker | Self::GreylagGoose => {
                 // this bird counts double toward the end-of-round goal, if it qualifies for the goal.
-                todo!()
+                // Bird is counted when the goal is scored (see `EndOfRoundGoal::get_num_matching`)
+                Ok(Default::default())
             }
             Self::SpottedDove => {
                 // if this bird has no birds to the right of it, you may move it (only the card) to the player mat of the player on your right (you choose its habitat). if you do, draw 3 [card].
@@ -1646,7 +1982,10 @@ impl BirdCard {
             }
             Self::BlackThroatedDiver | Self::WhiteStork | Self::WhiteThroatedDipper => {
                 // discard all remaining face-up [card] and refill the tray. if you do, draw 1 of the new face-up [card].
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(2, *self, *habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::RedWattledLapwing => {
                 // discard any number of [card] from the tray, then refill it. if at least 1 of the discarded birds is a [predator], lay 1 [egg] on this bird.
@@ -1663,36 +2002,25 @@ impl BirdCard {
             }
             Self::NorthernGannet => {
                 // roll all dice not in birdfeeder. if any are a [fish], gain that many [fish] from the supply and cache them on this bird.
-                let food_idx = FoodIndex::Fish;
-                let dice_idxs = food_idx.dice_sides();
-
-                let dice_out_of_birdfeeder = env
-                    ._bird_feeder
-                    .roll_all_dice_not_in_birdfeeder(&mut env.rng);
-                let num_dice_matching = dice_out_of_birdfeeder
-                    .iter()
-                    .filter(|dice_idx| dice_idxs.contains(dice_idx))
-                    .count();
-
-                for _ in 0..num_dice_matching {
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .cache_food(bird_idx, food_idx);
+                let num_fish = predator::hunt_with_dice(env, habitat, bird_idx, FoodIndex::Fish);
+                for _ in 0..num_fish {
+                    env.cache_food(habitat, bird_idx, FoodIndex::Fish);
                 }
 
-                Ok(ActivateResult {
-                    was_successful: num_dice_matching > 0,
-                    ..Default::default()
-                })
+                Ok(Default::default())
             }
             Self::AudouinsGull => {
                 // draw 2 [card] from the deck. tuck 1 behind this bird and keep the other.
-                todo!()
+                // TODO: Tuck should be only of these two cards
+                env.draw_bird_cards_from_deck(2);
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::TuckBirdCard(*habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::RufousOwl => {
                 // draw 1 face-up [card] from the tray with a wingspan less than 75cm and tuck it behind this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::SatyrTragopan => {
@@ -1712,8 +2040,7 @@ impl BirdCard {
                 let cur_player_idx = env.current_player_idx();
                 for player_idx in 0..env.config().num_players {
                     env.set_current_player(player_idx);
-                    let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-                    env.current_player_mut().add_bird_card(bird_card);
+                    env.draw_bird_cards_from_deck(1);
                 }
 
                 env.set_current_player(cur_player_idx);
@@ -1723,17 +2050,15 @@ impl BirdCard {
                 // trade 1 [wild] for any other type from the supply.
                 Ok(ActivateResult {
                     immediate_actions: vec![Action::DoThen(
-                        Box::new(Action::DiscardFood),
-                        Box::new(Action::GetFoodFromSupplyChoice(
-                            FoodIndex::iter().collect::<Box<[FoodIndex]>>(),
-                        )),
+                        Box::new(Action::DiscardFood(*habitat)),
+                        Box::new(Action::GetFoodFromSupplyChoice(env.wild_foods())),
                     )],
                     ..Default::default()
                 })
             }
             Self::RhinocerosAuklet => {
                 // roll any 2 [die]. if you roll at least 1 [fish], cache 1 [fish] from the supply on this bird. all players may discard 1 [card] from their hand to gain 1 [fish] from the supply.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::BewicksWren
@@ -1773,23 +2098,11 @@ impl BirdCard {
             }
             Self::IndigoBunting | Self::WesternTanager => {
                 // gain 1 [invertebrate] or [fruit] from the birdfeeder, if available.
-                let num_inverterbrate = env._bird_feeder.contains(FoodIndex::Invertebrate);
-                let num_fruit = env._bird_feeder.contains(FoodIndex::Fruit);
-
-                if num_fruit == 0 || num_inverterbrate == 0 {
-                    return Ok(Default::default());
-                }
-
-                let mut v = vec![];
-                if num_fruit > 0 {
-                    v.push(FoodIndex::Fruit);
-                }
-                if num_inverterbrate > 0 {
-                    v.push(FoodIndex::Invertebrate);
-                }
-
                 Ok(ActivateResult {
-                    immediate_actions: vec![Action::GetFoodFromSupplyChoice(v.into_boxed_slice())],
+                    immediate_actions: vec![Action::GetFoodFromFeederChoice(Box::new([
+                        FoodIndex::Invertebrate,
+                        FoodIndex::Fruit,
+                    ]))],
                     ..Default::default()
                 })
             }
@@ -1812,8 +2125,7 @@ impl BirdCard {
             Self::GreatCrestedFlycatcher => {
                 // gain 1 [invertebrate] from the birdfeeder, if available.
                 if let Ok(()) = env._bird_feeder.take_specific_food(FoodIndex::Invertebrate) {
-                    env.current_player_mut()
-                        .add_food(FoodIndex::Invertebrate, 1);
+                    env.gain_food_from_feeder(FoodIndex::Invertebrate, 1);
                 }
                 Ok(Default::default())
             }
@@ -1823,28 +2135,25 @@ impl BirdCard {
             }
             Self::BlackTailedGodwit | Self::RedKnot => {
                 // draw 1 new bonus card. then draw 3 [card] and keep 1 of them.
-                todo!()
+                // TODO: Discard should be only of these three cards
+                env.draw_bonus_cards(1);
+                env.draw_bird_cards_from_deck(3);
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DiscardBirdCard, Action::DiscardBirdCard],
+                    ..Default::default()
+                })
             }
             Self::ChihuahuanRaven | Self::CommonRaven => {
                 // discard 1 [egg] from any of your other birds to gain 2 [wild] from the supply.
-                let mut choices = vec![];
-                let mat = env.current_player().get_mat();
-                for iter_habitat in HABITATS {
-                    let row = mat.get_row(&iter_habitat);
-                    for (iter_bird_idx, _bird_card) in row.get_birds().iter().enumerate() {
-                        if (iter_habitat != *habitat || iter_bird_idx != bird_idx)
-                            && row.can_discard_egg(bird_idx)
-                        {
-                            choices.push((iter_habitat, iter_bird_idx));
-                        }
-                    }
-                }
+                let wild = env.wild_foods();
                 Ok(ActivateResult {
                     immediate_actions: vec![Action::DoThen(
-                        Box::new(Action::DiscardEggChoice(choices.into_boxed_slice())),
+                        Box::new(Action::DiscardEggChoice(other_birds_with_eggs(
+                            env, habitat, bird_idx,
+                        ))),
                         Box::new(Action::MultipleActions(vec![
-                            Action::GetFood,
-                            Action::GetFood,
+                            Action::GetFoodFromSupplyChoice(wild.clone()),
+                            Action::GetFoodFromSupplyChoice(wild),
                         ])),
                     )],
                     ..Default::default()
@@ -1852,7 +2161,7 @@ impl BirdCard {
             }
             Self::EurasianEagleOwl | Self::EurasianMarshHarrier => {
                 // up to 3 times, draw 1 [card] from the deck. when you stop, if the birds' total wingspan is less than 110 cm, tuck them behind this bird. if not, discard them.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::IndianVulture => {
@@ -1873,7 +2182,7 @@ impl BirdCard {
                     Self::SpottedTowhee => FoodIndex::Seed,
                     _ => panic!("Got bird {self:?} in action that is not related."),
                 };
-                env.current_player_mut().add_food(food_type, 1);
+                env.gain_food_from_supply(food_type, 1);
                 Ok(Default::default())
             }
             Self::SuperbLyrebird => {
@@ -1887,23 +2196,8 @@ impl BirdCard {
             | Self::RedTailedHawk
             | Self::SwainsonsHawk => {
                 // look at a [card] from the deck. if less than 75cm, tuck it behind this bird. if not, discard it.
-                let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
-
-                let was_successful = match bird_card.wingspan() {
-                    Some(x) => x < 75,
-                    None => true,
-                };
-
-                if was_successful {
-                    env.current_player_mut()
-                        .get_mat_mut()
-                        .get_row_mut(habitat)
-                        .tuck_card(bird_idx);
-                }
-                Ok(ActivateResult {
-                  was_successful,
-                  ..Default::default()
-                })
+                predator::hunt_with_card(env, habitat, bird_idx, predator::wingspan_less_than(75));
+                Ok(Default::default())
             }
             Self::RedJunglefowl => {
                 // count the [egg] on all of your birds. if the total is fewer than 6 [egg], lay 1 [egg] on this bird.
@@ -1919,7 +2213,7 @@ impl BirdCard {
             }
             Self::EurasianKestrel => {
                 // roll any 3 [die]. if you roll at least 1 [rodent], cache 1 [rodent] on this bird.
-                // TODO: Mark was_successful here
+                // TODO: Resolve the hunt via `predator` module
                 todo!()
             }
             Self::HorsfieldsBushlark => {
@@ -1956,11 +2250,19 @@ impl BirdCard {
             }
             Self::HouseSparrow => {
                 // discard up to 5 [seed] from your supply. for each, tuck 1 [card] from the deck behind this bird.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![do_then_up_to(
+                        5,
+                        Action::DiscardFoodChoice(Box::new([(FoodIndex::Seed, 1)]), *habitat),
+                        Action::TuckBirdCardFromDeck(*habitat, bird_idx),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::GreyHeron => {
                 // place this bird sideways, so that it covers 2 [wetland] spaces. pay the lower egg cost.
-                todo!()
+                // Bird covers 2 columns when it is put on the mat (see `MatRow::play_a_bird`)
+                Ok(Default::default())
             }
             Self::BlackSwan => {
                 // lay 1 [egg] on each of your birds with a wingspan over 100cm, including this one.
@@ -1968,7 +2270,19 @@ impl BirdCard {
             }
             Self::RainbowLorikeet => {
                 // discard 1 [nectar] to the "spent nectar" space for your [forest]. if you do, gain 2 [die] from the birdfeeder.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DoThen(
+                        Box::new(Action::PayFood(
+                            Box::new([FoodIndex::Nectar]),
+                            Habitat::Forest,
+                        )),
+                        Box::new(Action::MultipleActions(vec![
+                            Action::GetFood,
+                            Action::GetFood,
+                        ])),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::SouthIslandRobin => {
                 // if the player to your right has an [invertebrate] in their supply, cache 1 [invertebrate] from the general supply on this bird.
@@ -1976,7 +2290,18 @@ impl BirdCard {
             }
             Self::SavisWarbler => {
                 // draw 2 [card]. all other players draw 1 [card] from the deck.
-                todo!()
+                let cur_player_idx = env.current_player_idx();
+                let num_players = env.config().num_players;
+                for player_offset in 1..num_players {
+                    env.set_current_player((cur_player_idx + player_offset) % num_players);
+                    env.draw_bird_cards_from_deck(1);
+                }
+                env.set_current_player(cur_player_idx);
+
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::GetBirdCard, Action::GetBirdCard],
+                    ..Default::default()
+                })
             }
 
             Self::BlackWoodpecker | Self::NorthernFlicker | Self::BaldEagle => {
@@ -1992,7 +2317,7 @@ impl BirdCard {
                 };
 
                 let num_food = env._bird_feeder.contains(food_type);
-                env.current_player_mut().add_food(food_type, num_food as u8);
+                env.gain_food_from_feeder(food_type, num_food as u8);
 
                 for _ in 0..num_food {
                     env._bird_feeder.take_specific_food(food_type).unwrap();
@@ -2002,7 +2327,15 @@ impl BirdCard {
             }
             Self::GreaterFlamingo => {
                 // choose 1 other player. for each action cube on their [wetland], tuck 1 [card] from your hand behind this bird, then draw an equal number of [card].
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(
+                        (env.config().num_players - 1) as u8,
+                        *self,
+                        *habitat,
+                        bird_idx,
+                    )],
+                    ..Default::default()
+                })
             }
             Self::Smew => {
                 // draw 4 [card]. tuck 2 [card] behind this bird and add the other 2 [card] to your hand.
@@ -2010,11 +2343,21 @@ impl BirdCard {
             }
             Self::EuropeanBeeEater => {
                 // reset the birdfeeder. if you do, gain 1 [invertebrate] from the birdfeeder after resetting.
-                todo!()
+                // Reset is optional, and the food is gained only if it happens
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::ChooseThenAction(2, *self, *habitat, bird_idx)],
+                    ..Default::default()
+                })
             }
             Self::CommonMoorhen => {
                 // discard 1 [wild] from your supply. if you do, play another bird in your [wetland]. pay its normal food and egg cost.
-                todo!()
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::DoThen(
+                        Box::new(Action::DiscardFood(*habitat)),
+                        Box::new(Action::PlayBirdHabitat(Habitat::Wetland)),
+                    )],
+                    ..Default::default()
+                })
             }
             Self::GoldenPheasant => {
                 // all players lay 2 [egg]. you lay 2 additional [egg].
@@ -2025,21 +2368,21 @@ impl BirdCard {
 
     /// Performs a condition check for "Once between turns, when X happens do Y" (aka Pink birds).
     ///
-    /// If bird_player_idx is current players index it always returns Ok(false).
+    /// X is checked against a trigger raised by a change of the game state (see `Trigger`).
+    /// If trigger was raised by the player owning the bird it always returns Ok(false).
     /// Does both the check (X) and performs action if (Y) happens.
     /// If bird is has such a condition (it is a pink bird) it will return Ok(true), if X succeeds and Ok(false) if it fails.
     /// If bird is not a pink bird, returns a Err(InvalidBird)
     pub fn conditional_callback(
         &self,
         env: &mut WingspanEnv,
-        action_type_taken: &Action,
-        action_taken: u8,
+        trigger: &Trigger,
         bird_habitat: &Habitat,
         bird_idx: usize,
         bird_player_idx: usize,
     ) -> WingResult<bool> {
-        // Pink b
-        if bird_player_idx == env.current_player_idx() {
+        // Pink birds only react to other players
+        if trigger.player_idx() == bird_player_idx {
             return Ok(false);
         }
 
@@ -2049,7 +2392,13 @@ impl BirdCard {
             | Self::EurasianTreeSparrow
             | Self::EurasianGoldenOriole => {
                 // when another player takes the "gain food" action, gain 1 [FOOD types] from the birdfeeder, if there is one, at the end of their turn.
-                if *action_type_taken == Action::ChooseAction && action_taken == 1 {
+                if matches!(
+                    trigger,
+                    Trigger::HabitatActionTaken {
+                        habitat: Habitat::Forest,
+                        ..
+                    }
+                ) {
                     let food_choice: Box<[FoodIndex]> = match self {
             Self::AustralianOwletNightjar => Box::new([FoodIndex::Invertebrate]),
             Self::SacredKingfisher => Box::new([FoodIndex::Invertebrate, FoodIndex::Fish, FoodIndex::Rodent]),
@@ -2059,8 +2408,8 @@ impl BirdCard {
           };
 
                     env.prepend_actions(&mut [
-                        Action::ChangePlayer(env.current_player_idx()),
-                        Action::GetFoodFromSupplyChoice(food_choice),
+                        Action::ChangePlayer(env.current_turn_player_idx()),
+                        Action::GetFoodFromFeederChoice(food_choice),
                         Action::ChangePlayer(bird_player_idx),
                     ]);
 
@@ -2077,7 +2426,13 @@ impl BirdCard {
             | Self::AmericanAvocet
             | Self::CommonCuckoo => {
                 // when another player takes the "lay eggs" action, lay 1 [egg] on a bird with a [NEST TYPE] nest.
-                if *action_type_taken == Action::ChooseAction && action_taken == 1 {
+                if matches!(
+                    trigger,
+                    Trigger::HabitatActionTaken {
+                        habitat: Habitat::Grassland,
+                        ..
+                    }
+                ) {
                     // Text includes "on another bird"
                     let remove_self = matches!(self, Self::BarrowsGoldeneye | Self::CommonCuckoo);
                     let egg_cap_override = match self {
@@ -2128,11 +2483,12 @@ impl BirdCard {
             }
             Self::HornedLark => {
                 // when another player plays a bird in their [grassland], tuck 1 [card] from your hand behind this bird.
-                if is_last_bird_played_in_habitat(
-                    env,
-                    action_type_taken,
-                    action_taken,
-                    &Habitat::Grassland,
+                if matches!(
+                    trigger,
+                    Trigger::BirdPlayed {
+                        habitat: Habitat::Grassland,
+                        ..
+                    }
                 ) {
                     env.append_actions(&mut vec![
                         Action::ChangePlayer(env.current_player_idx()),
@@ -2147,11 +2503,12 @@ impl BirdCard {
             }
             Self::EasternKingbird => {
                 // when another player plays a bird in their [forest], gain 1 [invertebrate] from the supply.
-                if is_last_bird_played_in_habitat(
-                    env,
-                    action_type_taken,
-                    action_taken,
-                    &Habitat::Forest,
+                if matches!(
+                    trigger,
+                    Trigger::BirdPlayed {
+                        habitat: Habitat::Forest,
+                        ..
+                    }
                 ) {
                     env.append_actions(&mut vec![
                         Action::ChangePlayer(env.current_player_idx()),
@@ -2166,14 +2523,11 @@ impl BirdCard {
             }
             Self::SnowBunting => {
                 // when another player tucks a [card] for any reason, tuck 1 [card] from your hand behind this bird, then draw 1 [card] at the end of their turn.
-                let satisfies_condition = matches!(
-                    action_type_taken,
-                    Action::TuckBirdCard(_, _) | Action::TuckBirdCardFromDeck(_, _)
-                );
+                let satisfies_condition = matches!(trigger, Trigger::CardTucked { .. });
 
                 if satisfies_condition {
                     env.prepend_actions(&mut [
-                        Action::ChangePlayer(env.current_player_idx()),
+                        Action::ChangePlayer(env.current_turn_player_idx()),
                         Action::DoThen(
                             Box::new(Action::TuckBirdCard(*bird_habitat, bird_idx)),
                             Box::new(Action::GetBirdCard),
@@ -2186,11 +2540,12 @@ impl BirdCard {
             }
             Self::BeltedKingfisher => {
                 // when another player plays a bird in their [wetland], gain 1 [fish] from the supply.
-                if is_last_bird_played_in_habitat(
-                    env,
-                    action_type_taken,
-                    action_taken,
-                    &Habitat::Wetland,
+                if matches!(
+                    trigger,
+                    Trigger::BirdPlayed {
+                        habitat: Habitat::Wetland,
+                        ..
+                    }
                 ) {
                     env.append_actions(&mut vec![
                         Action::ChangePlayer(env.current_player_idx()),
@@ -2205,16 +2560,43 @@ impl BirdCard {
             }
             Self::SpangledDrongo => {
                 // when another player gains [nectar], gain 1 [nectar] from the supply.
-                todo!()
+                if matches!(
+                    trigger,
+                    Trigger::FoodGained {
+                        food: FoodIndex::Nectar,
+                        ..
+                    }
+                ) {
+                    env.append_actions(&mut vec![
+                        Action::ChangePlayer(env.current_player_idx()),
+                        Action::GetFoodFromSupplyChoice(Box::new([FoodIndex::Nectar])),
+                        Action::ChangePlayer(bird_player_idx),
+                    ]);
+
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
             }
             Self::LoggerheadShrike => {
                 // when another player takes the "gain food" action, if they gain any number of [rodent], cache 1 [rodent] from the supply on this bird.
-                if
-                    env._turn_action_taken == 1
-                    && env.current_turn_player().get_foods()[FoodIndex::Rodent as usize] > env._food_at_start_of_turn[FoodIndex::Rodent as usize]
-                {
+                let gained_rodent = matches!(
+                    trigger,
+                    Trigger::FoodGained {
+                        player_idx,
+                        food: FoodIndex::Rodent,
+                        ..
+                    } if *player_idx == env.current_turn_player_idx()
+                );
+                if gained_rodent && env._turn_action_taken == 1 {
                     let row = env.get_player_mut(bird_player_idx).get_mat_mut().get_row_mut(bird_habitat);
                     row.cache_food(bird_idx, FoodIndex::Rodent);
+                    env.push_event(GameEvent::FoodCached {
+                        player_idx: bird_player_idx,
+                        habitat: *bird_habitat,
+                        bird_idx,
+                        food: FoodIndex::Rodent,
+                    });
 
                     return Ok(true);
                 }
@@ -2222,7 +2604,7 @@ impl BirdCard {
             }
             Self::BlackVulture | Self::BlackBilledMagpie | Self::TurkeyVulture => {
                 // when another player's [predator] succeeds, gain 1 [die] from the birdfeeder.
-                if env._predator_succeeded {
+                if matches!(trigger, Trigger::PredatorSucceeded { .. }) {
                     env.append_actions(&mut vec![
                         Action::ChangePlayer(env.current_player_idx()),
                         Action::GetFood,
@@ -2236,10 +2618,7 @@ impl BirdCard {
             }
             Self::EuropeanGoldfinch => {
                 // when another player tucks a [card] for any reason, tuck 1 [card] from the deck behind this bird.
-                let satisfies_condition = matches!(
-                    action_type_taken,
-                    Action::TuckBirdCard(_, _) | Action::TuckBirdCardFromDeck(_, _)
-                );
+                let satisfies_condition = matches!(trigger, Trigger::CardTucked { .. });
 
                 if satisfies_condition {
                     env.append_actions(&mut vec![
@@ -2253,7 +2632,13 @@ impl BirdCard {
             }
             Self::PheasantCoucal => {
                 // when another player takes the "lay eggs" action, lay 1 [egg] on this bird.
-                if *action_type_taken == Action::ChooseAction && action_taken == 1 {
+                if matches!(
+                    trigger,
+                    Trigger::HabitatActionTaken {
+                        habitat: Habitat::Grassland,
+                        ..
+                    }
+                ) {
                     env.append_actions(&mut vec![
                         Action::ChangePlayer(env.current_player_idx()),
                         Action::GetEggAtLoc(*bird_habitat, bird_idx, 1),
@@ -2267,7 +2652,13 @@ impl BirdCard {
             Self::HorsfieldsBronzeCuckoo | Self::VioletCuckoo => {
                 // when another player takes the "lay eggs" action, lay 1 [egg] on another bird with wingspan less than 30 cm.
                 // [Violet only] you may go 2 over its egg limit while using this power.
-                let satisfies_condition = *action_type_taken == Action::ChooseAction && action_taken == 1;
+                let satisfies_condition = matches!(
+                    trigger,
+                    Trigger::HabitatActionTaken {
+                        habitat: Habitat::Grassland,
+                        ..
+                    }
+                );
 
                 if satisfies_condition {
                     let egg_cap_override = match self {
@@ -2326,7 +2717,7 @@ impl BirdCard {
         choice_idx: u8,
         env: &mut WingspanEnv,
         habitat: &Habitat,
-        _bird_idx: usize,
+        bird_idx: usize,
     ) -> WingResult<ActivateResult> {
         match self {
             Self::AnnasHummingbird | Self::RubyThroatedHummingbird => {
@@ -2337,12 +2728,12 @@ impl BirdCard {
                 }
 
                 let mut actions = Vec::new();
-                // Loop from choice idx to end and then from start to choice idx
-                for player_idx in choice_idx..env.config().num_players {
-                    actions.push(Action::GetFood);
-                    actions.push(Action::ChangePlayer(player_idx));
-                }
-                for player_idx in 0..choice_idx {
+                // Loop from choice idx to end and then from start to choice idx.
+                // Actions are a LIFO queue, so push them in reverse order.
+                for player_idx in (choice_idx..env.config().num_players)
+                    .chain(0..choice_idx)
+                    .rev()
+                {
                     actions.push(Action::GetFood);
                     actions.push(Action::ChangePlayer(player_idx));
                 }
@@ -2365,17 +2756,23 @@ impl BirdCard {
                     .get_birds()
                     .iter()
                     .enumerate()
-                    .filter(|(_bird_idx, bc)| bc.color() == &BirdCardColor::Brown && *bc != self)
+                    .filter(|(_bird_idx, bc)| {
+                        bc.color() == &BirdCardColor::Brown
+                            && !matches!(bc, Self::GrayCatbird | Self::NorthernMockingbird)
+                    })
                     .collect();
 
+                // Birds may have left the habitat since the choice was made, then nothing is repeated
                 if choice_idx >= bird_choices.len() {
-                    return Err(WingError::InvalidAction);
+                    return Ok(Default::default());
                 }
 
+                // Like when activated from the habitat, a power that cannot be used does nothing
                 let (choice_bird_idx, choice_bird_card) = bird_choices[choice_idx];
-                choice_bird_card
+                Ok(choice_bird_card
                     .clone()
                     .activate(env, habitat, choice_bird_idx)
+                    .unwrap_or_default())
             }
             Self::HoodedMerganser => {
                 // repeat 1 [predator] power in this habitat.
@@ -2388,17 +2785,360 @@ impl BirdCard {
                     .get_birds()
                     .iter()
                     .enumerate()
-                    .filter(|(_bird_idx, bc)| bc.is_predator())
+                    .filter(|(_bird_idx, bc)| {
+                        bc.is_predator() && *bc.color() == BirdCardColor::Brown
+                    })
                     .collect();
 
+                // Birds may have left the habitat since the choice was made, then nothing is repeated
                 if choice_idx >= bird_choices.len() {
-                    return Err(WingError::InvalidAction);
+                    return Ok(Default::default());
                 }
 
+                // Like when activated from the habitat, a power that cannot be used does nothing
                 let (choice_bird_idx, choice_bird_card) = bird_choices[choice_idx];
-                choice_bird_card
+                Ok(choice_bird_card
                     .clone()
                     .activate(env, habitat, choice_bird_idx)
+                    .unwrap_or_default())
+            }
+            Self::Mistletoebird => {
+                // gain 1 [fruit] from the supply, or discard 1 [fruit] to gain 1 [nectar] from the supply.
+                match choice_idx {
+                    0 => {
+                        env.gain_food_from_supply(FoodIndex::Fruit, 1);
+                    }
+                    1 => {
+                        env.current_player_mut().discard_food(FoodIndex::Fruit, 1)?;
+                        env.push_event(GameEvent::FoodDiscarded {
+                            player_idx: env.current_player_idx(),
+                            food: FoodIndex::Fruit,
+                            num_food: 1,
+                        });
+                        env.gain_food_from_supply(FoodIndex::Nectar, 1);
+                    }
+                    _ => return Err(WingError::InvalidAction),
+                }
+                Ok(Default::default())
+            }
+            Self::ParrotCrossbill => {
+                // remove any 1 [die] from the birdfeeder, then gain 1 [seed] from the supply.
+                // Other actions may have taken the die since the choice was made, then none is removed.
+                let _ = env._bird_feeder.remove_die(choice_idx as usize);
+                env.gain_food_from_supply(FoodIndex::Seed, 1);
+                Ok(Default::default())
+            }
+            Self::Dunnock => {
+                // choose 1 other player. for each action cube on their [grassland], lay 1 [egg] on this bird.
+                let other_player_idx = chosen_other_player_idx(env, choice_idx)?;
+                let num_cubes = env
+                    .get_player(other_player_idx)
+                    .num_action_cubes_on(&Habitat::Grassland);
+
+                for _ in 0..num_cubes {
+                    let _ = env.lay_egg(habitat, bird_idx, 0);
+                }
+                Ok(Default::default())
+            }
+            Self::LewinsHoneyeater => {
+                // choose 1 other player. you both gain 1 [nectar] from the supply.
+                let other_player_idx = chosen_other_player_idx(env, choice_idx)?;
+                let cur_player_idx = env.current_player_idx();
+
+                env.gain_food_from_supply(FoodIndex::Nectar, 1);
+                env.set_current_player(other_player_idx);
+                env.gain_food_from_supply(FoodIndex::Nectar, 1);
+                env.set_current_player(cur_player_idx);
+                Ok(Default::default())
+            }
+            Self::RedWingedParrot => {
+                // give 1 [nectar] from your supply to another player. if you do, lay 2 [egg] on this bird or gain 2 [die] from the birdfeeder.
+                // Even choices lay eggs, odd ones gain dice
+                let other_player_idx = chosen_other_player_idx(env, choice_idx / 2)?;
+                let cur_player_idx = env.current_player_idx();
+
+                env.current_player_mut()
+                    .discard_food(FoodIndex::Nectar, 1)?;
+                env.push_event(GameEvent::FoodDiscarded {
+                    player_idx: cur_player_idx,
+                    food: FoodIndex::Nectar,
+                    num_food: 1,
+                });
+                env.set_current_player(other_player_idx);
+                env.gain_food_from_supply(FoodIndex::Nectar, 1);
+                env.set_current_player(cur_player_idx);
+
+                match choice_idx % 2 {
+                    0 => {
+                        for _ in 0..2 {
+                            let _ = env.lay_egg(habitat, bird_idx, 0);
+                        }
+                        Ok(Default::default())
+                    }
+                    _ => Ok(ActivateResult {
+                        immediate_actions: vec![Action::GetFood, Action::GetFood],
+                        ..Default::default()
+                    }),
+                }
+            }
+            Self::EurasianMagpie => {
+                // choose 1 other player. for each action cube on their [grassland], cache 1 [wild] from the supply on any of your birds.
+                let other_player_idx = chosen_other_player_idx(env, choice_idx)?;
+                let num_cubes = env
+                    .get_player(other_player_idx)
+                    .num_action_cubes_on(&Habitat::Grassland);
+
+                // Cached food is worth the same on any bird, so it is all cached on this one
+                let foods: Box<[_]> = env.wild_foods().iter().map(|food| (*food, 1)).collect();
+                Ok(ActivateResult {
+                    immediate_actions: (0..num_cubes)
+                        .map(|_| Action::CacheFoodChoice(foods.clone(), *habitat, bird_idx))
+                        .collect(),
+                    ..Default::default()
+                })
+            }
+            Self::HoodedCrow | Self::GreaterFlamingo => {
+                // choose 1 other player. for each action cube on their [grassland] ([wetland] for Greater Flamingo),
+                // tuck 1 [card] from your hand behind this bird, then draw an equal number of [card].
+                let other_player_idx = chosen_other_player_idx(env, choice_idx)?;
+                let cube_habitat = match self {
+                    Self::HoodedCrow => Habitat::Grassland,
+                    _ => Habitat::Wetland,
+                };
+                let num_cubes = env
+                    .get_player(other_player_idx)
+                    .num_action_cubes_on(&cube_habitat);
+                if num_cubes == 0 {
+                    return Ok(Default::default());
+                }
+
+                Ok(ActivateResult {
+                    immediate_actions: vec![do_then_up_to(
+                        num_cubes as usize,
+                        Action::TuckBirdCard(*habitat, bird_idx),
+                        Action::GetBirdCard,
+                    )],
+                    ..Default::default()
+                })
+            }
+            Self::BlackHeadedGull => {
+                // steal 1 [wild] from another player's supply and add it to your own supply. they gain 1 [die] from the birdfeeder.
+                let wild_foods = env.wild_foods();
+                let Some((food, immediate_actions)) = steal_food(env, choice_idx, &wild_foods)?
+                else {
+                    return Ok(Default::default());
+                };
+                env.gain_food_from_supply(food, 1);
+
+                Ok(ActivateResult {
+                    immediate_actions,
+                    ..Default::default()
+                })
+            }
+            Self::RedBackedShrike
+            | Self::LittleOwl
+            | Self::CommonKingfisher
+            | Self::EurasianJay => {
+                // steal 1 food from another player's supply and cache it on this bird. they gain 1 [die] from the birdfeeder.
+                let food = match self {
+                    Self::RedBackedShrike => FoodIndex::Invertebrate,
+                    Self::LittleOwl => FoodIndex::Rodent,
+                    Self::CommonKingfisher => FoodIndex::Fish,
+                    _ => FoodIndex::Seed,
+                };
+                let Some((food, immediate_actions)) = steal_food(env, choice_idx, &[food])? else {
+                    return Ok(Default::default());
+                };
+                env.cache_food(habitat, bird_idx, food);
+
+                Ok(ActivateResult {
+                    immediate_actions,
+                    ..Default::default()
+                })
+            }
+            Self::BlackRedstart | Self::LesserWhitethroat => {
+                // choose a habitat with no [egg]. lay 1 [egg] on each bird in that habitat.
+                // Eggs may have been laid since the choice was made, then nothing happens
+                let Some(&choice_habitat) = habitats_without_eggs(env).get(choice_idx as usize)
+                else {
+                    return Ok(Default::default());
+                };
+                let num_birds = env
+                    .current_player()
+                    .get_mat()
+                    .get_row(&choice_habitat)
+                    .get_birds()
+                    .len();
+
+                for iter_bird_idx in 0..num_birds {
+                    let _ = env.lay_egg(&choice_habitat, iter_bird_idx, 0);
+                }
+                Ok(Default::default())
+            }
+            Self::LittleBustard | Self::SnowyOwl => {
+                // then gain 1 [card] or lay 1 [egg] on any bird.
+                let action = match choice_idx {
+                    0 => Action::GetBirdCard,
+                    1 => Action::GetEgg,
+                    _ => return Err(WingError::InvalidAction),
+                };
+                Ok(ActivateResult {
+                    immediate_actions: vec![action],
+                    ..Default::default()
+                })
+            }
+            Self::EuropeanTurtleDove => {
+                // then gain 1 [die] from the birdfeeder, lay 1 [egg] on any bird, or draw 1 [card].
+                let action = match choice_idx {
+                    0 => Action::GetFood,
+                    1 => Action::GetEgg,
+                    2 => Action::GetBirdCard,
+                    _ => return Err(WingError::InvalidAction),
+                };
+                Ok(ActivateResult {
+                    immediate_actions: vec![action],
+                    ..Default::default()
+                })
+            }
+            Self::Hawfinch
+            | Self::Bullfinch
+            | Self::GreatTit
+            | Self::EuropeanBeeEater
+            | Self::EuropeanHoneyBuzzard => {
+                // reset the birdfeeder. if you do, gain food from the birdfeeder after resetting.
+                match choice_idx {
+                    0 => return Ok(Default::default()),
+                    1 => {}
+                    _ => return Err(WingError::InvalidAction),
+                }
+
+                env._bird_feeder.reroll(&mut env.rng);
+                env.push_event(GameEvent::BirdFeederRerolled {
+                    player_idx: env.current_player_idx(),
+                });
+
+                let action = match self {
+                    Self::Hawfinch => Action::GetFoodFromFeederChoice(Box::new([FoodIndex::Seed])),
+                    Self::Bullfinch => Action::GetFoodFromFeederChoice(Box::new([
+                        FoodIndex::Seed,
+                        FoodIndex::Fruit,
+                    ])),
+                    Self::GreatTit => Action::GetFood,
+                    Self::EuropeanBeeEater => {
+                        Action::GetFoodFromFeederChoice(Box::new([FoodIndex::Invertebrate]))
+                    }
+                    _ => {
+                        // gain all [invertebrate] in the birdfeeder after resetting.
+                        while env
+                            ._bird_feeder
+                            .take_specific_food(FoodIndex::Invertebrate)
+                            .is_ok()
+                        {
+                            env.gain_food_from_feeder(FoodIndex::Invertebrate, 1);
+                        }
+                        return Ok(Default::default());
+                    }
+                };
+                Ok(ActivateResult {
+                    immediate_actions: vec![action],
+                    ..Default::default()
+                })
+            }
+            Self::MuteSwan => {
+                // choose 1-3 birds in your [wetland]. tuck 1 [card] from your hand behind each.
+                let num_birds = env
+                    .current_player()
+                    .get_mat()
+                    .get_row(&Habitat::Wetland)
+                    .get_birds()
+                    .len();
+                // Birds may have left the habitat since the choice was made, then nothing is tucked
+                if choice_idx as usize >= num_birds {
+                    return Ok(Default::default());
+                }
+
+                Ok(ActivateResult {
+                    immediate_actions: vec![Action::TuckBirdCard(
+                        Habitat::Wetland,
+                        choice_idx as usize,
+                    )],
+                    ..Default::default()
+                })
+            }
+            Self::Bluethroat | Self::CommonNightingale => {
+                // choose a food type. all players gain 1 of that food from the supply.
+                let food = *env
+                    .wild_foods()
+                    .get(choice_idx as usize)
+                    .ok_or(WingError::InvalidAction)?;
+
+                all_players_gain_food_from_supply(env, food);
+                Ok(Default::default())
+            }
+            Self::CarrionCrow | Self::GriffonVulture => {
+                // choose any 1 player (including yourself). cache 1 [rodent] from the supply on this bird for each [predator] that player has.
+                let num_players = env.config().num_players;
+                if choice_idx as usize >= num_players {
+                    return Err(WingError::InvalidAction);
+                }
+
+                let player_idx = (env.current_player_idx() + choice_idx as usize) % num_players;
+                let num_predators = env
+                    .get_player(player_idx)
+                    .get_birds_on_mat()
+                    .iter()
+                    .flat_map(|birds| birds.iter())
+                    .filter(|bird_card| bird_card.is_predator())
+                    .count();
+
+                for _ in 0..num_predators {
+                    env.cache_food(habitat, bird_idx, FoodIndex::Rodent);
+                }
+                Ok(Default::default())
+            }
+            Self::BlackThroatedDiver | Self::WhiteStork | Self::WhiteThroatedDipper => {
+                // discard all remaining face-up [card] and refill the tray. if you do, draw 1 of the new face-up [card].
+                match choice_idx {
+                    0 => Ok(Default::default()),
+                    1 => {
+                        env._bird_deck.reset_display();
+                        Ok(ActivateResult {
+                            immediate_actions: vec![Action::GetBirdCardFromDisplay(None)],
+                            ..Default::default()
+                        })
+                    }
+                    _ => Err(WingError::InvalidAction),
+                }
+            }
+            Self::BonellisEagle
+            | Self::EasternImperialEagle
+            | Self::EurasianSparrowhawk
+            | Self::NorthernGoshawk
+            | Self::CommonBuzzard
+            | Self::EurasianHobby
+            | Self::MontagusHarrier
+            | Self::RedKite => {
+                // Choice of how to pay for the bird being played (see `Action::PlayBird`)
+                let payment = *env
+                    .current_player()
+                    .payment_options(self, habitat)
+                    .get(choice_idx as usize)
+                    .ok_or(WingError::InvalidAction)?;
+
+                // Covered bird no longer reacts to anything
+                if let BirdPayment::OnTopOf(covered_bird_idx) = payment {
+                    env.remove_callbacks_of_bird(
+                        env.current_player_idx(),
+                        habitat,
+                        covered_bird_idx,
+                    );
+                }
+
+                let played_bird = env
+                    .current_player_mut()
+                    .play_bird_card_with_payment(*self, *habitat, payment)?;
+                resolve_played_bird(env, played_bird);
+                Ok(Default::default())
             }
             _ => Err(WingError::InvalidBird(format!(
                 "Bird {self:?} was called in callback, but it doesn't invoke such."
@@ -2407,29 +3147,139 @@ impl BirdCard {
     }
 }
 
-fn is_last_bird_played_in_habitat(
+// "Do X up to N times. For each, do Y" as a chain of options. Declining one of them ends the chain.
+// All of the Xs are done before any of the Ys (i.e. cards drawn for tucking cannot be tucked).
+fn do_then_up_to(times: usize, action_req: Action, action_reward: Action) -> Action {
+    let mut action = Action::DoThen(
+        Box::new(action_req.clone()),
+        Box::new(action_reward.clone()),
+    );
+    for _ in 1..times {
+        action = Action::DoThen(
+            Box::new(action_req.clone()),
+            // Actions are a LIFO queue, so the next option goes on top of the reward
+            Box::new(Action::MultipleActions(vec![action_reward.clone(), action])),
+        );
+    }
+    action
+}
+
+// Each player, starting with the current one, gains 1 food of the type from the supply.
+fn all_players_gain_food_from_supply(env: &mut WingspanEnv, food: FoodIndex) {
+    let cur_player_idx = env.current_player_idx();
+    let num_players = env.config().num_players;
+
+    for player_offset in 0..num_players {
+        env.set_current_player((cur_player_idx + player_offset) % num_players);
+        env.gain_food_from_supply(food, 1);
+    }
+
+    env.set_current_player(cur_player_idx);
+}
+
+// Other player chosen out of `num_players - 1` choices, in turn order starting after the current player
+fn chosen_other_player_idx(env: &WingspanEnv, choice_idx: u8) -> WingResult<usize> {
+    let num_players = env.config().num_players;
+    let choice_idx = choice_idx as usize;
+    if choice_idx + 1 >= num_players {
+        return Err(WingError::InvalidAction);
+    }
+
+    Ok((env.current_player_idx() + 1 + choice_idx) % num_players)
+}
+
+// Pairs of (other player, food) that can be stolen, in turn order starting after the current player
+fn foods_to_steal(env: &WingspanEnv, foods: &[FoodIndex]) -> Vec<(usize, FoodIndex)> {
+    let num_players = env.config().num_players;
+    let mut choices = vec![];
+    for player_offset in 1..num_players {
+        let player_idx = (env.current_player_idx() + player_offset) % num_players;
+        let player_foods = env.get_player(player_idx).get_foods();
+        for food in foods {
+            if player_foods[*food as usize] > 0 {
+                choices.push((player_idx, *food));
+            }
+        }
+    }
+    choices
+}
+
+// Takes the food of choice (see `foods_to_steal`) from the other player.
+// Returns the food, and actions with which the robbed player gains 1 [die] from the birdfeeder.
+//
+// Number of choices is counted when the power activates, and other powers can resolve before the choice.
+// If the food is no longer there, nothing is stolen.
+fn steal_food(
     env: &mut WingspanEnv,
-    action_type_taken: &Action,
-    action_taken: u8,
-    bird_habitat: &Habitat,
-) -> bool {
-    *action_type_taken == Action::PlayBirdHabitat(*bird_habitat)
-        || (*action_type_taken == Action::PlayBird
-            && env
-                .current_player()
-                .get_playable_card_hab_combos()
-                .get(action_taken as usize)
-                .map(|x| x.1 == *bird_habitat)
-                .unwrap_or(false))
+    choice_idx: u8,
+    foods: &[FoodIndex],
+) -> WingResult<Option<(FoodIndex, Vec<Action>)>> {
+    let Some(&(player_idx, food)) = foods_to_steal(env, foods).get(choice_idx as usize) else {
+        return Ok(None);
+    };
+
+    env.get_player_mut(player_idx).discard_food(food, 1)?;
+    env.push_event(GameEvent::FoodDiscarded {
+        player_idx,
+        food,
+        num_food: 1,
+    });
+
+    // Actions are a LIFO queue, so push them in reverse order
+    Ok(Some((
+        food,
+        vec![
+            Action::ChangePlayer(env.current_player_idx()),
+            Action::GetFood,
+            Action::ChangePlayer(player_idx),
+        ],
+    )))
+}
+
+// Habitats of the current player with at least one bird, and no eggs on any of them
+fn habitats_without_eggs(env: &WingspanEnv) -> Vec<Habitat> {
+    let mat = env.current_player().get_mat();
+    HABITATS
+        .into_iter()
+        .filter(|habitat| {
+            let row = mat.get_row(habitat);
+            !row.get_birds().is_empty() && row.get_eggs().iter().all(|eggs| *eggs == 0)
+        })
+        .collect()
+}
+
+// Birds of the current player with at least one egg, except for the one at (habitat, bird_idx)
+fn other_birds_with_eggs(
+    env: &WingspanEnv,
+    habitat: &Habitat,
+    bird_idx: usize,
+) -> Box<[(Habitat, usize)]> {
+    let mat = env.current_player().get_mat();
+    let mut choices = vec![];
+    for iter_habitat in HABITATS {
+        let row = mat.get_row(&iter_habitat);
+        for iter_bird_idx in 0..row.get_birds().len() {
+            if (iter_habitat != *habitat || iter_bird_idx != bird_idx)
+                && row.can_discard_egg(iter_bird_idx)
+            {
+                choices.push((iter_habitat, iter_bird_idx));
+            }
+        }
+    }
+    choices.into_boxed_slice()
 }
 
 #[cfg(test)]
 mod tests {
     use std::panic;
 
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
     use crate::{
-        bird_card::get_deck,
+        action::Action,
+        bird_card::{get_deck, BirdCard, BirdCardColor},
         expansion::Expansion,
+        habitat::Habitat,
         wingspan_env::{WingspanEnv, WingspanEnvConfigBuilder},
     };
 
@@ -2463,4 +3313,111 @@ mod tests {
             bad, good
         );
     }
+
+    // Activates every brown power of the last expansion next to a random neighbour, making random choices
+    fn assert_brown_powers_resolve(expansions: &[Expansion]) {
+        let deck = get_deck(&expansions[expansions.len() - 1..]);
+        let brown_birds: Vec<_> = deck
+            .iter()
+            .filter(|bird_card| bird_card.color() == &BirdCardColor::Brown)
+            .collect();
+        let config = WingspanEnvConfigBuilder::default()
+            .expansions(expansions.to_vec())
+            .build()
+            .unwrap();
+
+        for seed in 0..4 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            for bird_card in brown_birds.iter() {
+                let mut env = WingspanEnv::try_new(config.clone());
+                env.reset(Some(seed));
+                while env.round_idx() < 0 {
+                    let action_idx = env.valid_actions()[0];
+                    env.step(action_idx).unwrap();
+                }
+
+                // Put a neighbour with eggs next to the bird, so that powers have something to work with
+                let habitat = *bird_card.habitats().choose(&mut rng).unwrap();
+                let neighbour = **brown_birds.choose(&mut rng).unwrap();
+                for bird_card in [neighbour, **bird_card] {
+                    env.current_player_mut()
+                        .get_mat_mut()
+                        .put_bird_card(bird_card, &habitat)
+                        .unwrap();
+                }
+                for _ in 0..2 {
+                    let _ = env.lay_egg(&habitat, 0, 0);
+                }
+
+                env.push_action(Action::BirdActionFromHabitat(habitat));
+                let mut num_steps = 0;
+                while !matches!(env.next_action(), Some(Action::ChooseAction)) {
+                    let valid_actions = env.valid_actions();
+                    assert!(
+                        !valid_actions.is_empty(),
+                        "No valid actions for {:?} after activating {bird_card:?} (seed {seed})",
+                        env.next_action()
+                    );
+                    let action_idx = *valid_actions.choose(&mut rng).unwrap();
+                    let result = env.step(action_idx);
+                    assert!(
+                        result.is_ok(),
+                        "Activating {bird_card:?} failed with {result:?} (seed {seed})"
+                    );
+
+                    num_steps += 1;
+                    assert!(num_steps < 100, "Activating {bird_card:?} does not end");
+                }
+                assert_eq!(
+                    env.current_player_idx(),
+                    env.current_turn_player_idx(),
+                    "Activating {bird_card:?} did not give the turn back (seed {seed})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn core_brown_powers_resolve() {
+        assert_brown_powers_resolve(&[Expansion::Core]);
+    }
+
+    #[test]
+    fn european_brown_powers_resolve() {
+        assert_brown_powers_resolve(&[Expansion::Core, Expansion::European]);
+    }
+
+    #[test]
+    fn hermit_thrush_feeds_players_with_fewest_forest_birds() {
+        let mut env = WingspanEnv::try_new(
+            WingspanEnvConfigBuilder::default()
+                .num_players(3usize)
+                .build()
+                .unwrap(),
+        );
+        env.reset(Some(0));
+        env.set_current_player(1);
+        for bird_card in [BirdCard::HermitThrush, BirdCard::Mallard] {
+            let habitat = bird_card.habitats()[0];
+            env.current_player_mut()
+                .get_mat_mut()
+                .put_bird_card(bird_card, &habitat)
+                .unwrap();
+        }
+
+        let result = BirdCard::HermitThrush
+            .activate(&mut env, &Habitat::Forest, 0)
+            .unwrap();
+        // Only players 2 and 0 have no forest birds. They go in turn order, and the queue is LIFO
+        assert_eq!(
+            result.immediate_actions,
+            vec![
+                Action::ChangePlayer(1),
+                Action::GetFood,
+                Action::ChangePlayer(0),
+                Action::GetFood,
+                Action::ChangePlayer(2),
+            ]
+        );
+    }
 }
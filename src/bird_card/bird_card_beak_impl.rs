@@ -1,7 +1,8 @@
 use super::BirdCard;
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[pyclass(eq, eq_int)]
 pub enum BeakDirection {
   Left = 0,
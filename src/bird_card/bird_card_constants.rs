@@ -1,6 +1,7 @@
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[pyclass(eq, eq_int)]
 pub enum BirdCardColor {
     White = 0,
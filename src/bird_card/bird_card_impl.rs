@@ -1,6 +1,7 @@
 // This code is generated automatically via a script in code_gen/ folder
 use strum_macros::EnumIter;
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use super::BirdCardColor;
 use crate::{
@@ -11,7 +12,7 @@ use crate::{
     nest::NestType,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, Hash, Serialize, Deserialize)]
 #[pyclass(eq, eq_int)]
 pub enum BirdCard {
     AbbottsBooby,
@@ -1367,1028 +1368,2170 @@ impl BirdCard {
 
     pub fn cost(&self) -> &'static BirdCardCost {
         match self {
-            Self::AbbottsBooby => &([None, None, Some(2), None, None], 2, CostAlternative::No),
-            Self::AcornWoodpecker => &([None, Some(3), None, None, None], 3, CostAlternative::No),
-            Self::AmericanAvocet => &([Some(2), Some(1), None, None, None], 3, CostAlternative::No),
+            Self::AbbottsBooby => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AcornWoodpecker => &(
+                [None, Some(3), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::AmericanAvocet => &(
+                [Some(2), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
             Self::AmericanBittern => &(
-                [Some(1), None, Some(1), None, Some(1)],
+                [Some(1), None, Some(1), None, Some(1), None],
                 3,
                 CostAlternative::No,
             ),
-            Self::AmericanCoot => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::AmericanCrow => &([None, None, None, None, None], 1, CostAlternative::No),
-            Self::AmericanGoldfinch => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::AmericanKestrel => {
-                &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No)
-            }
-            Self::AmericanOystercatcher => {
-                &([Some(2), None, None, None, None], 2, CostAlternative::No)
-            }
-            Self::AmericanRedstart => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
+            Self::AmericanCoot => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AmericanCrow => &([None, None, None, None, None, None], 1, CostAlternative::No),
+            Self::AmericanGoldfinch => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AmericanKestrel => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AmericanOystercatcher => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AmericanRedstart => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::AmericanRobin => &(
-                [Some(1), None, None, Some(1), None],
+                [Some(1), None, None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::AmericanWhitePelican => {
-                &([None, None, Some(2), None, None], 2, CostAlternative::No)
-            }
-            Self::AmericanWoodcock => {
-                &([Some(2), Some(1), None, None, None], 3, CostAlternative::No)
-            }
-            Self::Anhinga => &([None, None, Some(2), None, None], 2, CostAlternative::No),
-            Self::AnnasHummingbird => &([None, None, None, None, None], 1, CostAlternative::No),
-            Self::AshThroatedFlycatcher => {
-                &([Some(2), None, None, Some(1), None], 3, CostAlternative::No)
-            }
-            Self::AsianEmeraldDove => {
-                &([None, Some(1), None, Some(2), None], 3, CostAlternative::No)
-            }
-            Self::AsianKoel => &([Some(1), None, None, Some(1), None], 2, CostAlternative::No),
-            Self::AtlanticPuffin => &([None, None, Some(3), None, None], 3, CostAlternative::No),
-            Self::AudouinsGull => &([None, None, Some(1), None, None], 2, CostAlternative::No),
-            Self::AustralasianPipit => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::AustralasianShoveler => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::AustralianIbis => &([Some(1), None, Some(1), None, None], 3, CostAlternative::No),
-            Self::AustralianMagpie => {
-                &([Some(1), None, None, None, Some(2)], 3, CostAlternative::No)
+            Self::AmericanWhitePelican => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AmericanWoodcock => &(
+                [Some(2), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::Anhinga => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AnnasHummingbird => {
+                &([None, None, None, None, None, None], 1, CostAlternative::No)
             }
+            Self::AshThroatedFlycatcher => &(
+                [Some(2), None, None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::AsianEmeraldDove => &(
+                [None, Some(1), None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::AsianKoel => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AtlanticPuffin => &(
+                [None, None, Some(3), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::AudouinsGull => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AustralasianPipit => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AustralasianShoveler => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AustralianIbis => &(
+                [Some(1), None, Some(1), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::AustralianMagpie => &(
+                [Some(1), None, None, None, Some(2), None],
+                3,
+                CostAlternative::No,
+            ),
             Self::AustralianOwletNightjar => &(
-                [Some(1), None, None, None, Some(1)],
+                [Some(1), None, None, None, Some(1), None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::AustralianRaven => {
-                &([Some(1), None, None, None, Some(1)], 3, CostAlternative::No)
-            }
-            Self::AustralianReedWarbler => {
-                &([Some(1), None, None, None, None], 1, CostAlternative::No)
-            }
-            Self::AustralianShelduck => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
+            Self::AustralianRaven => &(
+                [Some(1), None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::AustralianReedWarbler => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::AustralianShelduck => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::AustralianZebraFinch => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::AzureTit => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BairdsSparrow => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BaldEagle => &(
+                [None, None, Some(2), None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BaltimoreOriole => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BarnOwl => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BarnSwallow => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::BarredOwl => &(
+                [None, None, None, None, Some(1), None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::BarrowsGoldeneye => &(
+                [Some(1), Some(1), Some(1), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BayaWeaver => &(
+                [Some(1), Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BeardedReedling => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BellsVireo => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BeltedKingfisher => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BewicksWren => &(
+                [Some(2), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlackDrongo => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlackNoddy => &(
+                [None, None, Some(3), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlackRedstart => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlackSkimmer => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlackStork => &(
+                [None, None, Some(1), None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlackSwan => &(
+                [None, Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlackTern => &(
+                [Some(1), None, Some(1), None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::BlackVulture => &([None, None, None, None, None, None], 0, CostAlternative::No),
+            Self::BlackWoodpecker => &(
+                [Some(2), None, None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlackBelliedWhistlingDuck => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlackBilledMagpie => {
+                &([None, None, None, None, None, None], 2, CostAlternative::No)
             }
-            Self::AustralianZebraFinch => {
-                &([None, Some(1), None, None, None], 1, CostAlternative::No)
+            Self::BlackChinnedHummingbird => {
+                &([None, None, None, None, None, None], 1, CostAlternative::No)
             }
-            Self::AzureTit => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::BairdsSparrow => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::BaldEagle => &([None, None, Some(2), None, Some(1)], 3, CostAlternative::No),
-            Self::BaltimoreOriole => {
-                &([Some(1), None, None, Some(2), None], 3, CostAlternative::No)
+            Self::BlackCrownedNightHeron => &(
+                [Some(1), None, Some(1), None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlackHeadedGull => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlackNapedOriole => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlackNeckedStilt => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlackShoulderedKite => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlackTailedGodwit => &(
+                [Some(2), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlackThroatedDiver => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlueGrosbeak => &(
+                [Some(1), Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlueJay => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BlueRockThrush => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::BlueGrayGnatcatcher => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::BlueWingedWarbler => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Bluethroat => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BlythsHornbill => &(
+                [None, None, None, Some(3), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::Bobolink => &(
+                [Some(1), Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BonellisEagle => &(
+                [None, None, None, None, Some(3), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BrahminyKite => &(
+                [Some(1), None, Some(1), None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::Brambling => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Brant => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BrewersBlackbird => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BroadWingedHawk => &(
+                [None, None, None, None, Some(1), None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::Brolga => &(
+                [Some(1), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::BronzedCowbird => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BrownFalcon => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BrownPelican => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BrownShrike => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BrownHeadedCowbird => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::Budgerigar => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::Bullfinch => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::BurrowingOwl => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Bushtit => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CaliforniaCondor => {
+                &([None, None, None, None, None, None], 0, CostAlternative::No)
             }
-            Self::BarnOwl => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::BarnSwallow => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::BarredOwl => &([None, None, None, None, Some(1)], 1, CostAlternative::No),
-            Self::BarrowsGoldeneye => &(
-                [Some(1), Some(1), Some(1), None, None],
+            Self::CaliforniaQuail => &(
+                [Some(1), Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CanadaGoose => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Canvasback => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CarolinaChickadee => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CarolinaWren => &(
+                [Some(1), None, None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CarrionCrow => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CassinsFinch => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CassinsSparrow => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CedarWaxwing => &(
+                [None, None, None, Some(2), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CeruleanWarbler => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CettisWarbler => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::ChestnutCollaredLongspur => &(
+                [Some(1), Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::ChihuahuanRaven => &(
+                [None, None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::ChimneySwift => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::ChippingSparrow => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::ClarksGrebe => &(
+                [None, None, Some(1), None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::ClarksNutcracker => &(
+                [None, Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CoalTit => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Cockatiel => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::CommonBlackbird => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CommonBuzzard => &(
+                [None, None, None, None, Some(1), None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::CommonChaffinch => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CommonChiffchaff => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CommonCuckoo => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CommonGoldeneye => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CommonGrackle => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CommonGreenMagpie => &(
+                [Some(2), None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CommonIora => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::CommonKingfisher => &(
+                [Some(1), None, Some(2), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CommonLittleBittern => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CommonLoon => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CommonMerganser => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CommonMoorhen => &(
+                [Some(1), Some(1), Some(1), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CommonMyna => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CommonNighthawk => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CommonNightingale => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CommonRaven => &(
+                [None, None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CommonSandpiper => &(
+                [Some(1), None, Some(1), None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CommonStarling => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CommonSwift => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::CommonTailorbird => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CommonTeal => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::CommonYellowthroat => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::CoopersHawk => &(
+                [None, None, None, None, Some(1), None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::CoppersmithBarbet => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CorsicanNuthatch => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CountRaggisBirdofParadise => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CrestedIbis => &(
+                [Some(1), None, Some(2), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::CrestedLark => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::CrestedPigeon => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::CrimsonChat => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::DarkEyedJunco => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::DesertFinch => &(
+                [None, Some(3), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::DesertWheatear => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::Dickcissel => &(
+                [Some(1), Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::DoubleCrestedCormorant => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::DownyWoodpecker => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::Dunnock => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EasternBluebird => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EasternImperialEagle => &(
+                [None, None, None, None, Some(3), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::EasternKingbird => &(
+                [Some(1), None, None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::EasternPhoebe => &(
+                [Some(1), None, None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::EasternRosella => &(
+                [None, Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::EasternScreechOwl => &(
+                [Some(1), None, None, None, Some(1), None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::EasternWhipbird => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EleonorasFalcon => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Emu => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::EurasianCollaredDove => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EurasianCoot => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::EurasianEagleOwl => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EurasianGoldenOriole => &(
+                [Some(1), None, None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::EurasianGreenWoodpecker => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EurasianHobby => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EurasianHoopoe => &(
+                [Some(2), None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::EurasianJay => &(
+                [Some(1), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::EurasianKestrel => &(
+                [None, None, None, None, Some(1), None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::EurasianMagpie => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EurasianMarshHarrier => &(
+                [None, None, None, None, Some(1), None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::EurasianNutcracker => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::EurasianNuthatch => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EurasianSparrowhawk => &(
+                [None, None, None, None, Some(1), None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::EurasianTreeSparrow => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EurasianTreecreeper => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::EuropeanBeeEater => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EuropeanGoldfinch => &(
+                [None, Some(2), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::EuropeanHoneyBuzzard => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EuropeanRobin => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::EuropeanRoller => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::EuropeanTurtleDove => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::FerruginousHawk => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::FireFrontedSerin => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::FishCrow => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::ForestOwlet => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::ForstersTern => &(
+                [Some(1), None, Some(1), None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::FranklinsGull => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Galah => &(
+                [None, Some(3), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::Goldcrest => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::GoldenEagle => &(
+                [None, None, None, None, Some(3), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GoldenPheasant => &([None, None, None, None, None, None], 3, CostAlternative::No),
+            Self::GoldenHeadedCisticola => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::GouldsFinch => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::GracefulPrinia => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Grandala => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GrasshopperSparrow => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::GrayCatbird => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GrayWagtail => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GreatBlueHeron => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GreatCormorant => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GreatCrestedFlycatcher => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GreatCrestedGrebe => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GreatEgret => &(
+                [None, None, Some(2), None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreatHornbill => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreatHornedOwl => &(
+                [None, None, None, None, Some(3), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreatIndianBustard => &(
+                [None, Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreatSpottedWoodpecker => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreatTit => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreaterAdjutant => &(
+                [None, None, Some(1), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreaterFlamingo => &(
+                [Some(1), Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreaterPrairieChicken => &(
+                [Some(1), Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreaterRoadrunner => &(
+                [Some(1), None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreenBeeEater => &(
+                [Some(3), None, None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreenHeron => &(
+                [Some(1), None, Some(1), None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::GreenPheasant => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreenPygmyGoose => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GreyButcherbird => &(
+                [Some(1), None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GreyHeron => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GreyShrikethrush => &(
+                [Some(1), None, None, None, Some(1), None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::GreyTeal => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::GreyWarbler => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::GreyHeadedMannikin => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::GreylagGoose => &(
+                [None, Some(3), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::GriffonVulture => &([None, None, None, None, None, None], 0, CostAlternative::No),
+            Self::Hawfinch => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::HermitThrush => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::HimalayanMonal => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::HoodedCrow => &(
+                [Some(1), None, None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::HoodedMerganser => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::HoodedWarbler => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::HornedLark => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::HorsfieldsBronzeCuckoo => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::HorsfieldsBushlark => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::HouseCrow => &([None, None, None, None, None, None], 1, CostAlternative::No),
+            Self::HouseFinch => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::HouseSparrow => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::HouseWren => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::Ibisbill => &(
+                [Some(2), None, Some(1), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::IncaDove => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::IndianPeafowl => &(
+                [Some(1), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::IndianVulture => &([None, None, None, None, None, None], 0, CostAlternative::No),
+            Self::IndigoBunting => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::JuniperTitmouse => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Kakapo => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Kea => &(
+                [None, None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::KelpGull => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Kereru => &(
+                [None, None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Killdeer => &(
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::KingRail => &(
+                [Some(1), None, Some(1), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::Korimako => &(
+                [Some(1), None, None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::LargeBilledCrow => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::LaughingKookaburra => &(
+                [Some(1), None, Some(1), None, Some(1), None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::LazuliBunting => &(
+                [Some(1), Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::LesserFrigatebird => &(
+                [Some(1), None, Some(2), None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::BayaWeaver => &([Some(1), Some(2), None, None, None], 3, CostAlternative::No),
-            Self::BeardedReedling => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::BellsVireo => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::BeltedKingfisher => &([None, None, Some(1), None, None], 2, CostAlternative::No),
-            Self::BewicksWren => &([Some(2), Some(1), None, None, None], 3, CostAlternative::No),
-            Self::BlackDrongo => &([Some(1), None, None, None, None], 2, CostAlternative::No),
-            Self::BlackNoddy => &([None, None, Some(3), None, None], 3, CostAlternative::No),
-            Self::BlackRedstart => &(
-                [Some(1), Some(1), None, Some(1), None],
-                3,
+            Self::LesserWhitethroat => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
                 CostAlternative::No,
             ),
-            Self::BlackSkimmer => &([None, None, Some(2), None, None], 2, CostAlternative::No),
-            Self::BlackStork => &([None, None, Some(1), None, Some(1)], 2, CostAlternative::No),
-            Self::BlackSwan => &([None, Some(1), None, None, None], 3, CostAlternative::No),
-            Self::BlackTern => &(
-                [Some(1), None, Some(1), None, None],
-                1,
-                CostAlternative::Yes,
+            Self::LewinsHoneyeater => &(
+                [None, None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::BlackVulture => &([None, None, None, None, None], 0, CostAlternative::No),
-            Self::BlackWoodpecker => {
-                &([Some(2), None, None, Some(1), None], 3, CostAlternative::No)
-            }
-            Self::BlackBelliedWhistlingDuck => {
-                &([None, Some(2), None, None, None], 2, CostAlternative::No)
-            }
-            Self::BlackBilledMagpie => &([None, None, None, None, None], 2, CostAlternative::No),
-            Self::BlackChinnedHummingbird => {
-                &([None, None, None, None, None], 1, CostAlternative::No)
-            }
-            Self::BlackCrownedNightHeron => &(
-                [Some(1), None, Some(1), None, Some(1)],
-                3,
+            Self::LincolnsSparrow => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
                 CostAlternative::No,
             ),
-            Self::BlackHeadedGull => &([Some(1), None, None, None, None], 2, CostAlternative::No),
-            Self::BlackNapedOriole => {
-                &([Some(1), None, None, Some(2), None], 3, CostAlternative::No)
-            }
-            Self::BlackNeckedStilt => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::BlackShoulderedKite => {
-                &([None, None, None, None, Some(2)], 2, CostAlternative::No)
-            }
-            Self::BlackTailedGodwit => {
-                &([Some(2), Some(1), None, None, None], 3, CostAlternative::No)
-            }
-            Self::BlackThroatedDiver => {
-                &([None, None, Some(2), None, None], 2, CostAlternative::No)
-            }
-            Self::BlueGrosbeak => &([Some(1), Some(2), None, None, None], 3, CostAlternative::No),
-            Self::BlueJay => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::BlueRockThrush => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::LittleBustard => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::LittleEgret => &(
+                [Some(1), None, Some(1), None, Some(1), None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::BlueGrayGnatcatcher => {
-                &([Some(1), None, None, None, None], 1, CostAlternative::No)
-            }
-            Self::BlueWingedWarbler => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::Bluethroat => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::LittleGrebe => &(
+                [Some(2), None, Some(1), None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::BlythsHornbill => &([None, None, None, Some(3), None], 3, CostAlternative::No),
-            Self::Bobolink => &([Some(1), Some(2), None, None, None], 3, CostAlternative::No),
-            Self::BonellisEagle => &([None, None, None, None, Some(3)], 3, CostAlternative::No),
-            Self::BrahminyKite => &(
-                [Some(1), None, Some(1), None, Some(1)],
+            Self::LittleOwl => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::LittlePenguin => &(
+                [None, None, Some(3), None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::Brambling => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::Brant => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::BrewersBlackbird => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::BroadWingedHawk => &([None, None, None, None, Some(1)], 1, CostAlternative::No),
-            Self::Brolga => &([Some(1), Some(1), None, None, None], 3, CostAlternative::No),
-            Self::BronzedCowbird => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::BrownFalcon => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::BrownPelican => &([None, None, Some(2), None, None], 2, CostAlternative::No),
-            Self::BrownShrike => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::BrownHeadedCowbird => {
-                &([None, Some(1), None, None, None], 1, CostAlternative::No)
-            }
-            Self::Budgerigar => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::Bullfinch => &([None, Some(1), None, Some(1), None], 2, CostAlternative::No),
-            Self::BurrowingOwl => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::Bushtit => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::CaliforniaCondor => &([None, None, None, None, None], 0, CostAlternative::No),
-            Self::CaliforniaQuail => {
-                &([Some(1), Some(2), None, None, None], 3, CostAlternative::No)
-            }
-            Self::CanadaGoose => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::Canvasback => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::CarolinaChickadee => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
+            Self::LittlePiedCormorant => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::CarolinaWren => &(
-                [Some(1), None, None, Some(1), None],
+            Self::LittleRingedPlover => &(
+                [Some(1), None, None, None, None, None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
             ),
-            Self::CarrionCrow => &([Some(1), None, None, None, None], 2, CostAlternative::No),
-            Self::CassinsFinch => &([None, Some(1), None, Some(1), None], 2, CostAlternative::No),
-            Self::CassinsSparrow => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::CedarWaxwing => &([None, None, None, Some(2), None], 2, CostAlternative::No),
-            Self::CeruleanWarbler => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::CettisWarbler => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::ChestnutCollaredLongspur => {
-                &([Some(1), Some(2), None, None, None], 3, CostAlternative::No)
-            }
-            Self::ChihuahuanRaven => &([None, None, None, None, Some(1)], 3, CostAlternative::No),
-            Self::ChimneySwift => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::ChippingSparrow => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
+            Self::LoggerheadShrike => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
             ),
-            Self::ClarksGrebe => &([None, None, Some(1), None, None], 1, CostAlternative::No),
-            Self::ClarksNutcracker => &([None, Some(2), None, None, None], 3, CostAlternative::No),
-            Self::CoalTit => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::Cockatiel => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::CommonBlackbird => {
-                &([Some(1), None, None, Some(2), None], 3, CostAlternative::No)
-            }
-            Self::CommonBuzzard => &([None, None, None, None, Some(1)], 1, CostAlternative::No),
-            Self::CommonChaffinch => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
+            Self::LongTailedTit => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::CommonChiffchaff => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::MagpieLark => &(
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::CommonCuckoo => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::CommonGoldeneye => {
-                &([Some(1), None, Some(1), None, None], 2, CostAlternative::No)
-            }
-            Self::CommonGrackle => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::CommonGreenMagpie => {
-                &([Some(2), None, None, None, Some(1)], 3, CostAlternative::No)
-            }
-            Self::CommonIora => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::CommonKingfisher => {
-                &([Some(1), None, Some(2), None, None], 3, CostAlternative::No)
-            }
-            Self::CommonLittleBittern => {
-                &([Some(1), None, Some(1), None, None], 2, CostAlternative::No)
-            }
-            Self::CommonLoon => &([None, None, Some(1), None, None], 2, CostAlternative::No),
-            Self::CommonMerganser => &([None, None, Some(1), None, None], 2, CostAlternative::No),
-            Self::CommonMoorhen => &(
-                [Some(1), Some(1), Some(1), None, None],
-                3,
+            Self::MajorMitchellsCockatoo => &(
+                [None, Some(2), None, None, None, None],
+                2,
                 CostAlternative::No,
             ),
-            Self::CommonMyna => &(
-                [Some(1), Some(1), None, None, None],
+            Self::Mallard => &(
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::CommonNighthawk => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::CommonNightingale => &(
-                [Some(1), Some(1), None, Some(1), None],
-                1,
-                CostAlternative::Yes,
+            Self::Malleefowl => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::CommonRaven => &([None, None, None, None, Some(1)], 3, CostAlternative::No),
-            Self::CommonSandpiper => &(
-                [Some(1), None, Some(1), None, None],
-                1,
-                CostAlternative::Yes,
+            Self::MandarinDuck => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::CommonStarling => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::ManedDuck => &([None, None, None, None, None, None], 2, CostAlternative::No),
+            Self::ManyColoredFruitDove => &(
+                [None, None, None, Some(1), None, None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
             ),
-            Self::CommonSwift => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::CommonTailorbird => &([Some(1), None, None, None, None], 2, CostAlternative::No),
-            Self::CommonTeal => &(
-                [Some(1), Some(1), None, None, None],
+            Self::MaskedLapwing => &(
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::CommonYellowthroat => {
-                &([Some(1), None, None, None, None], 1, CostAlternative::No)
-            }
-            Self::CoopersHawk => &([None, None, None, None, Some(1)], 1, CostAlternative::No),
-            Self::CoppersmithBarbet => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::CorsicanNuthatch => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::CountRaggisBirdofParadise => {
-                &([Some(1), None, None, Some(2), None], 3, CostAlternative::No)
-            }
-            Self::CrestedIbis => &([Some(1), None, Some(2), None, None], 3, CostAlternative::No),
-            Self::CrestedLark => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::CrestedPigeon => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::CrimsonChat => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::MississippiKite => &(
+                [Some(1), None, None, None, Some(1), None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::DarkEyedJunco => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::DesertFinch => &([None, Some(3), None, None, None], 3, CostAlternative::No),
-            Self::DesertWheatear => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
+            Self::Mistletoebird => &(
+                [None, None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
             ),
-            Self::Dickcissel => &([Some(1), Some(2), None, None, None], 3, CostAlternative::No),
-            Self::DoubleCrestedCormorant => {
-                &([None, None, Some(1), None, None], 2, CostAlternative::No)
-            }
-            Self::DownyWoodpecker => &(
-                [Some(1), Some(1), None, Some(1), None],
-                1,
-                CostAlternative::Yes,
+            Self::MoltonisWarbler => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::Dunnock => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::EasternBluebird => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::EasternImperialEagle => {
-                &([None, None, None, None, Some(3)], 3, CostAlternative::No)
-            }
-            Self::EasternKingbird => &(
-                [Some(1), None, None, Some(1), None],
+            Self::MontagusHarrier => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::MountainBluebird => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::MountainChickadee => &(
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::EasternPhoebe => &(
-                [Some(1), None, None, Some(1), None],
+            Self::MourningDove => &(
+                [None, Some(1), None, None, None, None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
             ),
-            Self::EasternRosella => &([None, Some(1), None, None, None], 3, CostAlternative::No),
-            Self::EasternScreechOwl => &(
-                [Some(1), None, None, None, Some(1)],
+            Self::MuskDuck => &(
+                [Some(1), Some(1), Some(1), None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::EasternWhipbird => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::EleonorasFalcon => {
-                &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No)
-            }
-            Self::Emu => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::MuteSwan => &(
+                [Some(1), Some(1), None, None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::EurasianCollaredDove => {
-                &([None, Some(1), None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::EurasianCoot => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::EurasianEagleOwl => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::EurasianGoldenOriole => {
-                &([Some(1), None, None, Some(1), None], 3, CostAlternative::No)
-            }
-            Self::EurasianGreenWoodpecker => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::EurasianHobby => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::EurasianHoopoe => &([Some(2), None, None, None, Some(1)], 3, CostAlternative::No),
-            Self::EurasianJay => &([Some(1), Some(1), None, None, None], 3, CostAlternative::No),
-            Self::EurasianKestrel => &([None, None, None, None, Some(1)], 1, CostAlternative::No),
-            Self::EurasianMagpie => &([Some(1), None, None, None, None], 2, CostAlternative::No),
-            Self::EurasianMarshHarrier => {
-                &([None, None, None, None, Some(1)], 1, CostAlternative::No)
-            }
-            Self::EurasianNutcracker => {
-                &([None, Some(1), None, None, None], 1, CostAlternative::No)
+            Self::NewHollandHoneyeater => {
+                &([None, None, None, None, None, None], 1, CostAlternative::No)
             }
-            Self::EurasianNuthatch => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::EurasianSparrowhawk => {
-                &([None, None, None, None, Some(1)], 1, CostAlternative::No)
-            }
-            Self::EurasianTreeSparrow => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::EurasianTreecreeper => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
+            Self::NoisyMiner => &(
+                [Some(1), None, None, None, None, None],
+                3,
+                CostAlternative::No,
             ),
-            Self::EuropeanBeeEater => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::EuropeanGoldfinch => {
-                &([None, Some(2), None, Some(1), None], 3, CostAlternative::No)
-            }
-            Self::EuropeanHoneyBuzzard => {
-                &([Some(1), None, None, None, None], 2, CostAlternative::No)
-            }
-            Self::EuropeanRobin => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::NorthIslandBrownKiwi => &(
+                [Some(2), Some(1), None, None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::EuropeanRoller => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::EuropeanTurtleDove => {
-                &([None, Some(2), None, None, None], 2, CostAlternative::No)
-            }
-            Self::FerruginousHawk => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::FireFrontedSerin => {
-                &([None, Some(1), None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::FishCrow => &([None, None, Some(1), None, None], 2, CostAlternative::No),
-            Self::ForestOwlet => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::ForstersTern => &(
-                [Some(1), None, Some(1), None, None],
+            Self::NorthernBobwhite => &(
+                [None, Some(3), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::NorthernCardinal => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::NorthernFlicker => &(
+                [Some(1), Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::FranklinsGull => &([None, None, Some(1), None, None], 2, CostAlternative::No),
-            Self::Galah => &([None, Some(3), None, None, None], 3, CostAlternative::No),
-            Self::Goldcrest => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::GoldenEagle => &([None, None, None, None, Some(3)], 3, CostAlternative::No),
-            Self::GoldenPheasant => &([None, None, None, None, None], 3, CostAlternative::No),
-            Self::GoldenHeadedCisticola => {
-                &([Some(1), None, None, None, None], 1, CostAlternative::No)
-            }
-            Self::GouldsFinch => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::GracefulPrinia => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::Grandala => &([Some(1), None, None, Some(1), None], 2, CostAlternative::No),
-            Self::GrasshopperSparrow => &(
-                [Some(1), Some(1), None, None, None],
+            Self::NorthernGannet => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::NorthernGoshawk => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::NorthernHarrier => &(
+                [None, None, None, None, Some(1), None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
             ),
-            Self::GrayCatbird => &([Some(1), None, None, Some(2), None], 3, CostAlternative::No),
-            Self::GrayWagtail => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::GreatBlueHeron => &([Some(1), None, Some(1), None, None], 2, CostAlternative::No),
-            Self::GreatCormorant => &([None, None, Some(2), None, None], 2, CostAlternative::No),
-            Self::GreatCrestedFlycatcher => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::GreatCrestedGrebe => &([None, None, Some(2), None, None], 2, CostAlternative::No),
-            Self::GreatEgret => &([None, None, Some(2), None, Some(1)], 3, CostAlternative::No),
-            Self::GreatHornbill => &([Some(1), None, None, Some(2), None], 3, CostAlternative::No),
-            Self::GreatHornedOwl => &([None, None, None, None, Some(3)], 3, CostAlternative::No),
-            Self::GreatIndianBustard => {
-                &([None, Some(2), None, None, None], 3, CostAlternative::No)
-            }
-            Self::GreatSpottedWoodpecker => &(
-                [Some(1), Some(1), None, Some(1), None],
-                3,
+            Self::NorthernMockingbird => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
                 CostAlternative::No,
             ),
-            Self::GreatTit => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::NorthernShoveler => &(
+                [Some(1), Some(2), None, None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::GreaterAdjutant => &([None, None, Some(1), None, None], 3, CostAlternative::No),
-            Self::GreaterFlamingo => {
-                &([Some(1), Some(2), None, None, None], 3, CostAlternative::No)
-            }
-            Self::GreaterPrairieChicken => {
-                &([Some(1), Some(2), None, None, None], 3, CostAlternative::No)
+            Self::OliveBackedSunbird => {
+                &([None, None, None, None, None, None], 1, CostAlternative::No)
             }
-            Self::GreaterRoadrunner => {
-                &([Some(1), None, None, None, Some(1)], 3, CostAlternative::No)
-            }
-            Self::GreenBeeEater => &([Some(3), None, None, None, None], 3, CostAlternative::No),
-            Self::GreenHeron => &(
-                [Some(1), None, Some(1), None, None],
+            Self::OrangeFootedScrubfowl => &(
+                [None, Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::GreenPheasant => &(
-                [Some(1), Some(1), None, Some(1), None],
-                3,
+            Self::OrientalBayOwl => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
                 CostAlternative::No,
             ),
-            Self::GreenPygmyGoose => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::GreyButcherbird => {
-                &([Some(1), None, None, None, Some(1)], 3, CostAlternative::No)
-            }
-            Self::GreyHeron => &([None, None, Some(2), None, None], 2, CostAlternative::No),
-            Self::GreyShrikethrush => &(
-                [Some(1), None, None, None, Some(1)],
+            Self::OrientalMagpieRobin => &(
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::GreyTeal => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::GreyWarbler => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::Osprey => &(
+                [None, None, Some(1), None, None, None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
             ),
-            Self::GreyHeadedMannikin => {
-                &([None, Some(1), None, None, None], 1, CostAlternative::No)
-            }
-            Self::GreylagGoose => &([None, Some(3), None, None, None], 3, CostAlternative::No),
-            Self::GriffonVulture => &([None, None, None, None, None], 0, CostAlternative::No),
-            Self::Hawfinch => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::HermitThrush => &([Some(1), None, None, Some(2), None], 3, CostAlternative::No),
-            Self::HimalayanMonal => &(
-                [Some(1), Some(1), None, Some(1), None],
-                3,
+            Self::PacificBlackDuck => &(
+                [None, Some(1), None, None, None, None],
+                2,
                 CostAlternative::No,
             ),
-            Self::HoodedCrow => &([Some(1), None, None, None, None], 3, CostAlternative::No),
-            Self::HoodedMerganser => {
-                &([Some(1), None, Some(1), None, None], 2, CostAlternative::No)
-            }
-            Self::HoodedWarbler => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::HornedLark => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::HorsfieldsBronzeCuckoo => {
-                &([Some(1), None, None, None, None], 1, CostAlternative::No)
-            }
-            Self::HorsfieldsBushlark => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::HouseCrow => &([None, None, None, None, None], 1, CostAlternative::No),
-            Self::HouseFinch => &([None, Some(1), None, Some(1), None], 2, CostAlternative::No),
-            Self::HouseSparrow => &([None, Some(1), None, Some(1), None], 2, CostAlternative::No),
-            Self::HouseWren => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::Ibisbill => &([Some(2), None, Some(1), None, None], 3, CostAlternative::No),
-            Self::IncaDove => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::IndianPeafowl => &([Some(1), Some(1), None, None, None], 3, CostAlternative::No),
-            Self::IndianVulture => &([None, None, None, None, None], 0, CostAlternative::No),
-            Self::IndigoBunting => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::PaintedBunting => &(
+                [Some(1), Some(2), None, None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::JuniperTitmouse => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::Kakapo => &([None, Some(1), None, Some(1), None], 2, CostAlternative::No),
-            Self::Kea => &([None, None, None, Some(1), None], 2, CostAlternative::No),
-            Self::KelpGull => &([None, None, Some(1), None, None], 2, CostAlternative::No),
-            Self::Kereru => &([None, None, None, Some(1), None], 2, CostAlternative::No),
-            Self::Killdeer => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
-            ),
-            Self::KingRail => &([Some(1), None, Some(1), None, None], 3, CostAlternative::No),
-            Self::Korimako => &([Some(1), None, None, Some(1), None], 3, CostAlternative::No),
-            Self::LargeBilledCrow => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::LaughingKookaburra => &(
-                [Some(1), None, Some(1), None, Some(1)],
+            Self::PaintedWhitestart => &(
+                [Some(1), None, None, None, None, None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
             ),
-            Self::LazuliBunting => &(
-                [Some(1), Some(1), None, Some(1), None],
+            Self::ParrotCrossbill => &(
+                [None, Some(3), None, None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::LesserFrigatebird => {
-                &([Some(1), None, Some(2), None, None], 3, CostAlternative::No)
-            }
-            Self::LesserWhitethroat => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::LewinsHoneyeater => &([None, None, None, Some(1), None], 2, CostAlternative::No),
-            Self::LincolnsSparrow => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::LittleBustard => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::LittleEgret => &(
-                [Some(1), None, Some(1), None, Some(1)],
+            Self::PeacefulDove => &(
+                [None, Some(1), None, None, None, None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
             ),
-            Self::LittleGrebe => &([Some(2), None, Some(1), None, None], 3, CostAlternative::No),
-            Self::LittleOwl => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::LittlePenguin => &([None, None, Some(3), None, None], 3, CostAlternative::No),
-            Self::LittlePiedCormorant => {
-                &([Some(1), None, Some(1), None, None], 2, CostAlternative::No)
-            }
-            Self::LittleRingedPlover => {
-                &([Some(1), None, None, None, None], 1, CostAlternative::No)
-            }
-            Self::LoggerheadShrike => {
-                &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No)
-            }
-            Self::LongTailedTit => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::MagpieLark => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
+            Self::PeregrineFalcon => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
             ),
-            Self::MajorMitchellsCockatoo => {
-                &([None, Some(2), None, None, None], 2, CostAlternative::No)
-            }
-            Self::Mallard => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
+            Self::PesquetsParrot => &(
+                [None, None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::Malleefowl => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::MandarinDuck => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::ManedDuck => &([None, None, None, None, None], 2, CostAlternative::No),
-            Self::ManyColoredFruitDove => {
-                &([None, None, None, Some(1), None], 1, CostAlternative::No)
-            }
-            Self::MaskedLapwing => &(
-                [Some(1), Some(1), None, None, None],
-                1,
-                CostAlternative::Yes,
+            Self::PheasantCoucal => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
             ),
-            Self::MississippiKite => &(
-                [Some(1), None, None, None, Some(1)],
-                1,
-                CostAlternative::Yes,
+            Self::PhilippineEagle => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
             ),
-            Self::Mistletoebird => &([None, None, None, Some(2), None], 3, CostAlternative::No),
-            Self::MoltonisWarbler => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::MontagusHarrier => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::MountainBluebird => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::MountainChickadee => &(
-                [Some(1), Some(1), None, None, None],
+            Self::PiedBilledGrebe => &(
+                [Some(1), None, Some(1), None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::MourningDove => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::MuskDuck => &(
-                [Some(1), Some(1), Some(1), None, None],
-                1,
-                CostAlternative::Yes,
+            Self::PileatedWoodpecker => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::MuteSwan => &([Some(1), Some(1), None, None, None], 3, CostAlternative::No),
-            Self::NewHollandHoneyeater => &([None, None, None, None, None], 1, CostAlternative::No),
-            Self::NoisyMiner => &([Some(1), None, None, None, None], 3, CostAlternative::No),
-            Self::NorthIslandBrownKiwi => {
-                &([Some(2), Some(1), None, None, None], 3, CostAlternative::No)
-            }
-            Self::NorthernBobwhite => &([None, Some(3), None, None, None], 3, CostAlternative::No),
-            Self::NorthernCardinal => {
-                &([None, Some(1), None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::NorthernFlicker => &(
-                [Some(1), Some(1), None, Some(1), None],
-                1,
-                CostAlternative::Yes,
+            Self::PineSiskin => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::NorthernGannet => &([None, None, Some(2), None, None], 2, CostAlternative::No),
-            Self::NorthernGoshawk => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::NorthernHarrier => &([None, None, None, None, Some(1)], 1, CostAlternative::No),
-            Self::NorthernMockingbird => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::NorthernShoveler => {
-                &([Some(1), Some(2), None, None, None], 3, CostAlternative::No)
-            }
-            Self::OliveBackedSunbird => &([None, None, None, None, None], 1, CostAlternative::No),
-            Self::OrangeFootedScrubfowl => &(
-                [None, Some(1), None, Some(1), None],
-                1,
-                CostAlternative::Yes,
+            Self::PinkEaredDuck => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
             ),
-            Self::OrientalBayOwl => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::OrientalMagpieRobin => &(
-                [Some(1), Some(1), None, None, None],
+            Self::PlainsWanderer => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::PlumbeousRedstart => &(
+                [Some(1), None, None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::Osprey => &([None, None, Some(1), None, None], 1, CostAlternative::No),
-            Self::PacificBlackDuck => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::PaintedBunting => &([Some(1), Some(2), None, None, None], 3, CostAlternative::No),
-            Self::PaintedWhitestart => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::ParrotCrossbill => &([None, Some(3), None, None, None], 3, CostAlternative::No),
-            Self::PeacefulDove => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::PeregrineFalcon => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::PesquetsParrot => &([None, None, None, Some(1), None], 2, CostAlternative::No),
-            Self::PheasantCoucal => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::PhilippineEagle => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::PiedBilledGrebe => &(
-                [Some(1), None, Some(1), None, None],
+            Self::PrincessStephaniesAstrapia => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::ProthonotaryWarbler => &(
+                [Some(2), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::Pukeko => &(
+                [None, Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::PurpleGallinule => &(
+                [None, Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::PurpleHeron => &(
+                [Some(1), None, Some(1), None, Some(1), None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::PileatedWoodpecker => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::PineSiskin => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::PinkEaredDuck => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::PlainsWanderer => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::PlumbeousRedstart => &(
-                [Some(1), None, None, Some(1), None],
+            Self::PurpleMartin => &(
+                [Some(1), None, None, None, None, None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
             ),
-            Self::PrincessStephaniesAstrapia => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::ProthonotaryWarbler => {
-                &([Some(2), Some(1), None, None, None], 3, CostAlternative::No)
-            }
-            Self::Pukeko => &([None, Some(2), None, None, None], 3, CostAlternative::No),
-            Self::PurpleGallinule => {
-                &([None, Some(1), None, Some(1), None], 3, CostAlternative::No)
-            }
-            Self::PurpleHeron => &(
-                [Some(1), None, Some(1), None, Some(1)],
+            Self::PygmyNuthatch => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RainbowLorikeet => &(
+                [None, None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedAvadavat => &(
+                [None, Some(1), None, None, None, None],
                 1,
-                CostAlternative::Yes,
+                CostAlternative::No,
+            ),
+            Self::RedCrossbill => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedJunglefowl => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedKite => &(
+                [None, None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedKnot => &(
+                [Some(3), None, None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::RedWattlebird => &([None, None, None, None, None, None], 2, CostAlternative::No),
+            Self::RedBackedFairywren => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedBackedShrike => &(
+                [Some(1), None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
             ),
-            Self::PurpleMartin => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::PygmyNuthatch => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::RainbowLorikeet => &([None, None, None, Some(1), None], 2, CostAlternative::No),
-            Self::RedAvadavat => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::RedCrossbill => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::RedJunglefowl => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::RedKite => &([None, None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::RedKnot => &([Some(3), None, None, None, None], 3, CostAlternative::No),
-            Self::RedWattlebird => &([None, None, None, None, None], 2, CostAlternative::No),
-            Self::RedBackedFairywren => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::RedBackedShrike => {
-                &([Some(1), None, None, None, Some(1)], 3, CostAlternative::No)
-            }
             Self::RedBelliedWoodpecker => &(
-                [Some(1), Some(1), None, None, None],
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::RedBreastedMerganser => {
-                &([Some(1), None, Some(1), None, None], 2, CostAlternative::No)
-            }
+            Self::RedBreastedMerganser => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::RedBreastedNuthatch => &(
-                [Some(1), Some(1), None, None, None],
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::RedCappedRobin => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::RedCockadedWoodpecker => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::RedCrownedCrane => {
-                &([Some(1), Some(1), None, None, None], 3, CostAlternative::No)
-            }
+            Self::RedCappedRobin => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::RedCockadedWoodpecker => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedCrownedCrane => &(
+                [Some(1), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
             Self::RedEyedVireo => &(
-                [Some(1), None, None, Some(1), None],
+                [Some(1), None, None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::RedHeadedWoodpecker => {
-                &([Some(1), Some(1), None, None, None], 3, CostAlternative::No)
-            }
-            Self::RedLeggedPartridge => {
-                &([None, Some(3), None, None, None], 3, CostAlternative::No)
-            }
-            Self::RedNeckedAvocet => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::RedShoulderedHawk => &([None, None, None, None, Some(1)], 1, CostAlternative::No),
-            Self::RedTailedHawk => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::RedVentedBulbul => {
-                &([Some(1), None, None, Some(2), None], 3, CostAlternative::No)
-            }
-            Self::RedWattledLapwing => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::RedWingedBlackbird => {
-                &([None, Some(1), None, None, None], 1, CostAlternative::No)
-            }
-            Self::RedWingedParrot => {
-                &([None, Some(1), None, Some(1), None], 3, CostAlternative::No)
-            }
+            Self::RedHeadedWoodpecker => &(
+                [Some(1), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::RedLeggedPartridge => &(
+                [None, Some(3), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::RedNeckedAvocet => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedShoulderedHawk => &(
+                [None, None, None, None, Some(1), None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::RedTailedHawk => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedVentedBulbul => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::RedWattledLapwing => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RedWingedBlackbird => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::RedWingedParrot => &(
+                [None, Some(1), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
             Self::RegentBowerbird => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::RhinocerosAuklet => &([None, None, Some(1), None, None], 1, CostAlternative::No),
-            Self::RingBilledGull => &([None, None, None, None, None], 2, CostAlternative::No),
+            Self::RhinocerosAuklet => &(
+                [None, None, Some(1), None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::RingBilledGull => &([None, None, None, None, None, None], 2, CostAlternative::No),
             Self::RockPigeon => &(
-                [Some(1), Some(1), None, None, None],
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::Rook => &([None, None, None, None, None], 1, CostAlternative::No),
+            Self::Rook => &([None, None, None, None, None, None], 1, CostAlternative::No),
             Self::RoseBreastedGrosbeak => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 3,
                 CostAlternative::No,
             ),
             Self::RoseRingedParakeet => &(
-                [None, Some(1), None, Some(1), None],
+                [None, Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
             Self::RoseateSpoonbill => &(
-                [Some(1), Some(1), Some(1), None, None],
+                [Some(1), Some(1), Some(1), None, None, None],
                 3,
                 CostAlternative::No,
             ),
             Self::RosyStarling => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::RoyalSpoonbill => &([Some(1), None, Some(1), None, None], 2, CostAlternative::No),
+            Self::RoyalSpoonbill => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::RubyCrownedKinglet => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
             Self::RubyThroatedHummingbird => {
-                &([None, None, None, None, None], 1, CostAlternative::No)
+                &([None, None, None, None, None, None], 1, CostAlternative::No)
             }
             Self::RuddyDuck => &(
-                [Some(1), Some(1), None, None, None],
+                [Some(1), Some(1), None, None, None, None],
+                1,
+                CostAlternative::Yes,
+            ),
+            Self::RuddyShelduck => &(
+                [None, Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::Ruff => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RufousNightHeron => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::RufousOwl => &(
+                [None, None, None, None, Some(3), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::RufousBandedHoneyeater => &(
+                [Some(1), None, None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::RuddyShelduck => &([None, Some(2), None, None, None], 3, CostAlternative::No),
-            Self::Ruff => &([Some(1), None, None, None, None], 2, CostAlternative::No),
-            Self::RufousNightHeron => {
-                &([Some(1), None, Some(1), None, None], 2, CostAlternative::No)
-            }
-            Self::RufousOwl => &([None, None, None, None, Some(3)], 3, CostAlternative::No),
-            Self::RufousBandedHoneyeater => {
-                &([Some(1), None, None, None, None], 1, CostAlternative::Yes)
-            }
             Self::SacredKingfisher => &(
-                [Some(1), None, Some(1), None, Some(1)],
+                [Some(1), None, Some(1), None, Some(1), None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::SandhillCrane => &([None, Some(2), None, None, None], 3, CostAlternative::No),
-            Self::SarusCrane => &([None, None, None, None, None], 3, CostAlternative::No),
-            Self::SatyrTragopan => &([None, Some(1), None, Some(1), None], 2, CostAlternative::No),
+            Self::SandhillCrane => &(
+                [None, Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::SarusCrane => &([None, None, None, None, None, None], 3, CostAlternative::No),
+            Self::SatyrTragopan => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::SavannahSparrow => &(
-                [Some(1), Some(1), None, None, None],
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::SavisWarbler => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::SaysPhoebe => &([Some(3), None, None, None, None], 3, CostAlternative::No),
-            Self::ScaledQuail => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::ScalyBreastedMunia => {
-                &([None, Some(1), None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::ScissorTailedFlycatcher => {
-                &([Some(2), None, None, Some(1), None], 3, CostAlternative::No)
-            }
-            Self::ShortToedTreecreeper => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::Silvereye => &([Some(1), None, None, None, None], 2, CostAlternative::No),
-            Self::SmallMinivet => &([Some(1), None, None, None, None], 1, CostAlternative::No),
+            Self::SavisWarbler => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::SaysPhoebe => &(
+                [Some(3), None, None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::ScaledQuail => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::ScalyBreastedMunia => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::ScissorTailedFlycatcher => &(
+                [Some(2), None, None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::ShortToedTreecreeper => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::Silvereye => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::SmallMinivet => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
             Self::Smew => &(
-                [Some(1), Some(1), Some(1), None, None],
+                [Some(1), Some(1), Some(1), None, None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::SnowBunting => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
+            Self::SnowBunting => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::SnowyEgret => &(
-                [Some(1), None, Some(1), None, None],
+                [Some(1), None, Some(1), None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::SnowyOwl => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
+            Self::SnowyOwl => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
             Self::SongSparrow => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::SouthIslandRobin => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::SouthernCassowary => &([None, None, None, Some(2), None], 3, CostAlternative::No),
-            Self::SpangledDrongo => &([Some(1), None, None, None, None], 3, CostAlternative::No),
-            Self::SplendidFairywren => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::SpoonBilledSandpiper => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
+            Self::SouthIslandRobin => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::SouthernCassowary => &(
+                [None, None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::SpangledDrongo => &(
+                [Some(1), None, None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::SplendidFairywren => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::SpoonBilledSandpiper => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::SpotlessCrake => &(
-                [Some(1), Some(1), None, None, None],
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::SpottedDove => &([None, Some(1), None, None, None], 1, CostAlternative::No),
-            Self::SpottedOwl => &([None, None, None, None, Some(2)], 2, CostAlternative::No),
-            Self::SpottedSandpiper => &([Some(1), None, None, None, None], 1, CostAlternative::No),
+            Self::SpottedDove => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::SpottedOwl => &(
+                [None, None, None, None, Some(2), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::SpottedSandpiper => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
             Self::SpottedTowhee => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::SpraguesPipit => &([Some(1), Some(1), None, None, None], 2, CostAlternative::No),
-            Self::SquaccoHeron => &([Some(1), None, Some(1), None, None], 2, CostAlternative::No),
+            Self::SpraguesPipit => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::SquaccoHeron => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::SriLankaBlueMagpie => &(
-                [Some(1), None, None, Some(1), Some(1)],
+                [Some(1), None, None, Some(1), Some(1), None],
                 3,
                 CostAlternative::No,
             ),
-            Self::SriLankaFrogmouth => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::StellersJay => &([None, Some(2), None, None, None], 3, CostAlternative::No),
-            Self::StorkBilledKingfisher => {
-                &([None, None, Some(2), None, None], 2, CostAlternative::No)
-            }
+            Self::SriLankaFrogmouth => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::StellersJay => &(
+                [None, Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::StorkBilledKingfisher => &(
+                [None, None, Some(2), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::StubbleQuail => &(
-                [Some(1), Some(1), None, None, None],
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::SulphurCrestedCockatoo => {
-                &([None, Some(1), None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::SuperbLyrebird => &([Some(2), None, None, None, None], 3, CostAlternative::No),
-            Self::SwainsonsHawk => &([Some(1), None, None, None, Some(1)], 2, CostAlternative::No),
-            Self::TawnyFrogmouth => &([Some(2), None, None, None, Some(1)], 3, CostAlternative::No),
-            Self::TheklasLark => &([Some(2), Some(1), None, None, None], 3, CostAlternative::No),
-            Self::TreeSwallow => &([Some(1), None, None, Some(1), None], 2, CostAlternative::No),
-            Self::TrumpeterFinch => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::TrumpeterSwan => &([None, Some(2), None, None, None], 3, CostAlternative::No),
+            Self::SulphurCrestedCockatoo => &(
+                [None, Some(1), None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::SuperbLyrebird => &(
+                [Some(2), None, None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::SwainsonsHawk => &(
+                [Some(1), None, None, None, Some(1), None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::TawnyFrogmouth => &(
+                [Some(2), None, None, None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::TheklasLark => &(
+                [Some(2), Some(1), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::TreeSwallow => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::TrumpeterFinch => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::TrumpeterSwan => &(
+                [None, Some(2), None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
             Self::TuftedTitmouse => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::Tui => &([Some(1), None, None, None, None], 3, CostAlternative::No),
-            Self::TurkeyVulture => &([None, None, None, None, None], 0, CostAlternative::No),
-            Self::Twite => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::VauxsSwift => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::VerditerFlycatcher => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::VioletCuckoo => &([Some(1), None, None, Some(1), None], 2, CostAlternative::No),
-            Self::VioletGreenSwallow => {
-                &([Some(2), None, None, None, None], 2, CostAlternative::No)
-            }
-            Self::WedgeTailedEagle => &([None, None, None, None, Some(3)], 3, CostAlternative::No),
-            Self::WelcomeSwallow => &([Some(2), None, None, None, None], 2, CostAlternative::No),
-            Self::WesternMeadowlark => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
-            Self::WesternTanager => &([Some(2), None, None, Some(1), None], 3, CostAlternative::No),
-            Self::WhiteStork => &([Some(1), None, Some(1), None, None], 3, CostAlternative::No),
-            Self::WhiteWagtail => &([Some(2), None, None, None, None], 2, CostAlternative::No),
+            Self::Tui => &(
+                [Some(1), None, None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::TurkeyVulture => &([None, None, None, None, None, None], 0, CostAlternative::No),
+            Self::Twite => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::VauxsSwift => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::VerditerFlycatcher => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::VioletCuckoo => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::VioletGreenSwallow => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::WedgeTailedEagle => &(
+                [None, None, None, None, Some(3), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::WelcomeSwallow => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::WesternMeadowlark => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::WesternTanager => &(
+                [Some(2), None, None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::WhiteStork => &(
+                [Some(1), None, Some(1), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::WhiteWagtail => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::WhiteBackedWoodpecker => &(
-                [Some(1), None, None, Some(1), None],
+                [Some(1), None, None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::WhiteBelliedSeaEagle => {
-                &([None, None, Some(1), None, Some(2)], 3, CostAlternative::No)
-            }
+            Self::WhiteBelliedSeaEagle => &(
+                [None, None, Some(1), None, Some(2), None],
+                3,
+                CostAlternative::No,
+            ),
             Self::WhiteBreastedNuthatch => &(
-                [Some(1), Some(1), None, None, None],
+                [Some(1), Some(1), None, None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::WhiteBreastedWoodswallow => {
-                &([Some(2), None, None, None, None], 2, CostAlternative::No)
-            }
-            Self::WhiteBrowedTitWarbler => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
+            Self::WhiteBreastedWoodswallow => &(
+                [Some(2), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::WhiteBrowedTitWarbler => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::WhiteCrestedLaughingthrush => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::WhiteCrownedSparrow => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
+            Self::WhiteCrownedSparrow => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::WhiteFacedHeron => &(
-                [Some(1), None, Some(1), None, Some(1)],
+                [Some(1), None, Some(1), None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::WhiteFacedIbis => &(
+                [Some(2), None, Some(1), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::WhiteHeadedDuck => &(
+                [None, Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::WhiteThroatedDipper => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::WhiteThroatedKingfisher => &(
+                [None, None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::WhiteThroatedSwift => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::WhoopingCrane => &([None, None, None, None, None, None], 3, CostAlternative::No),
+            Self::WildTurkey => &(
+                [None, Some(2), None, Some(1), None, None],
                 3,
                 CostAlternative::No,
             ),
-            Self::WhiteFacedIbis => &([Some(2), None, Some(1), None, None], 3, CostAlternative::No),
-            Self::WhiteHeadedDuck => &([None, Some(1), None, None, None], 2, CostAlternative::No),
-            Self::WhiteThroatedDipper => {
-                &([Some(1), None, Some(1), None, None], 2, CostAlternative::No)
-            }
-            Self::WhiteThroatedKingfisher => {
-                &([None, None, Some(1), None, None], 2, CostAlternative::No)
-            }
-            Self::WhiteThroatedSwift => {
-                &([Some(1), None, None, None, None], 1, CostAlternative::No)
-            }
-            Self::WhoopingCrane => &([None, None, None, None, None], 3, CostAlternative::No),
-            Self::WildTurkey => &([None, Some(2), None, Some(1), None], 3, CostAlternative::No),
             Self::Willet => &(
-                [Some(1), None, Some(1), None, None],
+                [Some(1), None, Some(1), None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::WillieWagtail => &([Some(1), None, None, None, None], 2, CostAlternative::No),
+            Self::WillieWagtail => &(
+                [Some(1), None, None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::WillowTit => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::WilsonsSnipe => &([Some(1), None, None, None, None], 1, CostAlternative::No),
-            Self::WilsonsStormPetrel => {
-                &([Some(1), None, Some(2), None, None], 3, CostAlternative::No)
-            }
-            Self::WoodDuck => &([None, Some(2), None, Some(1), None], 3, CostAlternative::No),
-            Self::WoodStork => &([None, None, Some(1), None, Some(1)], 3, CostAlternative::No),
-            Self::Wrybill => &([Some(1), None, Some(1), None, None], 2, CostAlternative::No),
+            Self::WilsonsSnipe => &(
+                [Some(1), None, None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
+            Self::WilsonsStormPetrel => &(
+                [Some(1), None, Some(2), None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::WoodDuck => &(
+                [None, Some(2), None, Some(1), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::WoodStork => &(
+                [None, None, Some(1), None, Some(1), None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::Wrybill => &(
+                [Some(1), None, Some(1), None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::YellowBittern => &(
-                [Some(1), None, Some(1), None, None],
+                [Some(1), None, Some(1), None, None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::YellowBelliedSapsucker => {
-                &([Some(1), None, None, Some(1), None], 2, CostAlternative::No)
-            }
-            Self::YellowBilledCuckoo => {
-                &([Some(2), None, None, None, None], 3, CostAlternative::No)
-            }
-            Self::YellowBreastedChat => {
-                &([Some(1), None, None, Some(2), None], 3, CostAlternative::No)
-            }
-            Self::YellowHeadedBlackbird => {
-                &([Some(1), Some(1), None, None, None], 2, CostAlternative::No)
-            }
+            Self::YellowBelliedSapsucker => &(
+                [Some(1), None, None, Some(1), None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::YellowBilledCuckoo => &(
+                [Some(2), None, None, None, None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::YellowBreastedChat => &(
+                [Some(1), None, None, Some(2), None, None],
+                3,
+                CostAlternative::No,
+            ),
+            Self::YellowHeadedBlackbird => &(
+                [Some(1), Some(1), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
             Self::YellowRumpedWarbler => &(
-                [Some(1), Some(1), None, Some(1), None],
+                [Some(1), Some(1), None, Some(1), None, None],
                 1,
                 CostAlternative::Yes,
             ),
-            Self::Yellowhammer => &([None, Some(2), None, None, None], 2, CostAlternative::No),
-            Self::ZebraDove => &([None, Some(1), None, None, None], 1, CostAlternative::No),
+            Self::Yellowhammer => &(
+                [None, Some(2), None, None, None, None],
+                2,
+                CostAlternative::No,
+            ),
+            Self::ZebraDove => &(
+                [None, Some(1), None, None, None, None],
+                1,
+                CostAlternative::No,
+            ),
         }
     }
 
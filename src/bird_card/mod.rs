@@ -11,13 +11,12 @@ pub use bird_card_impl::*;
 pub use bird_card_beak_impl::*;
 use strum::IntoEnumIterator;
 
-use crate::{expansion::Expansion, food::Foods};
+use crate::{
+    expansion::Expansion,
+    food::{FoodIndex, Foods},
+};
 
 pub(crate) fn get_deck(expansions: &[Expansion]) -> Vec<BirdCard> {
-    if expansions.len() != 1 && expansions.first().unwrap() != &Expansion::Core {
-        todo!("Only core is supported so far. Expansions add new logic which we have not implemented yet.")
-    }
-
     let expansions = HashSet::<Expansion>::from_iter(expansions.iter().cloned());
 
     BirdCard::iter()
@@ -25,6 +24,41 @@ pub(crate) fn get_deck(expansions: &[Expansion]) -> Vec<BirdCard> {
         .collect()
 }
 
+// Birds of the European expansion, which change rules of the game rather than having a power
+impl BirdCard {
+    /// "instead of paying any costs, you may play this bird on top of another bird on your player mat."
+    pub fn can_be_played_on_top(&self) -> bool {
+        matches!(
+            self,
+            Self::CommonBuzzard | Self::EurasianHobby | Self::MontagusHarrier | Self::RedKite
+        )
+    }
+
+    /// "for each [rodent] in this bird's cost, you may pay 1 [card] from your hand instead."
+    pub fn can_pay_rodents_with_cards(&self) -> bool {
+        matches!(
+            self,
+            Self::BonellisEagle
+                | Self::EasternImperialEagle
+                | Self::EurasianSparrowhawk
+                | Self::NorthernGoshawk
+        )
+    }
+
+    /// "at any time, you may spend [seed] cached on this card."
+    pub fn has_spendable_cache(&self) -> bool {
+        matches!(self, Self::CoalTit | Self::EurasianNuthatch)
+    }
+
+    /// "this bird counts double toward the end-of-round goal, if it qualifies for the goal."
+    pub fn counts_double_for_round_goal(&self) -> bool {
+        matches!(
+            self,
+            Self::CettisWarbler | Self::EurasianGreenWoodpecker | Self::GreylagGoose
+        )
+    }
+}
+
 pub(crate) fn is_enough_food_to_play_a_card(card: &BirdCard, player_food: &Foods) -> bool {
     let (food_req, total_food_needed, is_cost_alt) = card.cost();
 
@@ -33,14 +67,24 @@ pub(crate) fn is_enough_food_to_play_a_card(card: &BirdCard, player_food: &Foods
         return false;
     }
 
+    // Nectar is wild, so it can be paid instead of any other food
+    let nectar_idx = FoodIndex::Nectar as usize;
+    let nectar = player_food[nectar_idx];
     match is_cost_alt {
         crate::food::CostAlternative::Yes => food_req
             .iter()
             .zip(player_food)
-            .all(|(req, res)| req.is_some_and(|req| req <= *res)),
-        crate::food::CostAlternative::No => food_req
-            .iter()
-            .zip(player_food)
-            .all(|(req, res)| req.is_none_or(|req| req <= *res)),
+            .any(|(req, res)| req.is_some_and(|req| req <= *res + nectar)),
+        crate::food::CostAlternative::No => {
+            let missing_food: u8 = food_req
+                .iter()
+                .zip(player_food)
+                .enumerate()
+                .filter(|(food_idx, _)| *food_idx != nectar_idx)
+                .map(|(_, (req, res))| req.unwrap_or_default().saturating_sub(*res))
+                .sum();
+
+            food_req[nectar_idx].unwrap_or_default() + missing_food <= nectar
+        }
     }
 }
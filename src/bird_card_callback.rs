@@ -1,7 +1,8 @@
 use crate::{bird_card::BirdCard, habitat::Habitat};
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[pyclass]
 pub struct BirdCardCallback {
     pub card: BirdCard,
@@ -1,4 +1,6 @@
-use rand::{rngs::StdRng, Rng};
+use rand::Rng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::Action,
@@ -6,14 +8,31 @@ use crate::{
     food::FoodIndex,
 };
 
-fn sample_dice(rng: &mut StdRng, num_times: usize) -> Vec<u8> {
-    (0..num_times).map(|_| rng.gen_range(0..6u8)).collect()
+// Die faces:
+// 0-4 - single food, following FoodIndex
+// 5 - Invertebrate/Seed
+// 6 - Nectar/Fruit (Oceania)
+// 7 - Nectar/Seed (Oceania)
+const CORE_DIE_FACES: [u8; 6] = [0, 1, 2, 3, 4, 5];
+const OCEANIA_DIE_FACES: [u8; 6] = [0, 2, 4, 5, 6, 7];
+
+// Food a player chooses from, when taking a die with the given face
+fn die_face_foods(dice_face: u8) -> Box<[FoodIndex]> {
+    match dice_face {
+        0..=4 => Box::new([FoodIndex::from(dice_face)]),
+        5 => Box::new([FoodIndex::Seed, FoodIndex::Invertebrate]),
+        6 => Box::new([FoodIndex::Nectar, FoodIndex::Fruit]),
+        7 => Box::new([FoodIndex::Nectar, FoodIndex::Seed]),
+        _ => panic!("Incorrect dice face: {}", dice_face),
+    }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BirdFeeder {
     dice_in_birdfeeder: Vec<u8>,
     dice_out_birdfeeder: Vec<u8>,
+    // Oceania dice replace the core ones
+    oceania_dice: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -23,14 +42,32 @@ pub(crate) enum BirdFeederActionResult {
 }
 
 impl BirdFeeder {
-    pub fn reroll(&mut self, rng: &mut StdRng) {
+    pub fn new(oceania_dice: bool) -> Self {
+        Self {
+            oceania_dice,
+            ..Default::default()
+        }
+    }
+
+    fn sample_dice(&self, rng: &mut ChaCha12Rng, num_times: usize) -> Vec<u8> {
+        let faces = if self.oceania_dice {
+            &OCEANIA_DIE_FACES
+        } else {
+            &CORE_DIE_FACES
+        };
+        (0..num_times)
+            .map(|_| faces[rng.gen_range(0..faces.len() as u8) as usize])
+            .collect()
+    }
+
+    pub fn reroll(&mut self, rng: &mut ChaCha12Rng) {
         self.dice_out_birdfeeder.clear();
-        self.dice_in_birdfeeder = sample_dice(rng, 5);
+        self.dice_in_birdfeeder = self.sample_dice(rng, 5);
     }
 
     pub fn take_dice_and_update_state(
         &mut self,
-        rng: &mut StdRng,
+        rng: &mut ChaCha12Rng,
         idx: u8,
         action: Action,
     ) -> WingResult<BirdFeederActionResult> {
@@ -59,35 +96,17 @@ impl BirdFeeder {
         self.dice_out_birdfeeder.push(dice_face);
 
         // Update state of env
-        let result =
-            match dice_face {
-                0..=4 => BirdFeederActionResult::GainFood(FoodIndex::from(dice_face)),
-                5 => BirdFeederActionResult::FollowupAction(Action::GetFoodFromSupplyChoice(
-                    Box::new([FoodIndex::Seed, FoodIndex::Invertebrate]),
-                )),
-                _ => panic!("Incorrect dice face: {}", dice_face),
-            };
-
-        Ok(result)
-    }
-
-    pub fn count(&self, food_idx: FoodIndex) -> usize {
-        let allowed_dice = match food_idx {
-            FoodIndex::Fish | FoodIndex::Fruit | FoodIndex::Rodent => vec![food_idx as u8],
-            FoodIndex::Invertebrate | FoodIndex::Seed => vec![food_idx as u8, 5],
+        let foods = die_face_foods(dice_face);
+        let result = match *foods {
+            [food_idx] => BirdFeederActionResult::GainFood(food_idx),
+            _ => BirdFeederActionResult::FollowupAction(Action::GetFoodFromSupplyChoice(foods)),
         };
 
-        self.dice_in_birdfeeder
-            .iter()
-            .filter(|d| allowed_dice.contains(*d))
-            .count()
+        Ok(result)
     }
 
     pub fn take_specific_food(&mut self, food_idx: FoodIndex) -> WingResult<()> {
-        let allowed_dice = match food_idx {
-            FoodIndex::Fish | FoodIndex::Fruit | FoodIndex::Rodent => vec![food_idx as u8],
-            FoodIndex::Invertebrate | FoodIndex::Seed => vec![food_idx as u8, 5],
-        };
+        let allowed_dice = food_idx.dice_sides();
 
         let dice_to_remove = self
             .dice_in_birdfeeder
@@ -104,28 +123,36 @@ impl BirdFeeder {
 
         let dice_to_remove = dice_to_remove.ok_or(WingError::InvalidAction)?;
 
-        self.dice_in_birdfeeder.remove(dice_to_remove);
+        let dice_face = self.dice_in_birdfeeder.remove(dice_to_remove);
+        self.dice_out_birdfeeder.push(dice_face);
+        Ok(())
+    }
+
+    /// Takes the die out of the birdfeeder, without gaining its food.
+    pub fn remove_die(&mut self, idx: usize) -> WingResult<()> {
+        if idx >= self.dice_in_birdfeeder.len() {
+            return Err(WingError::InvalidAction);
+        }
+
+        let dice_face = self.dice_in_birdfeeder.remove(idx);
+        self.dice_out_birdfeeder.push(dice_face);
         Ok(())
     }
 
+    pub fn dice_in_birdfeeder(&self) -> &Vec<u8> {
+        &self.dice_in_birdfeeder
+    }
+
     pub fn num_dice_in(&self) -> usize {
         self.dice_in_birdfeeder.len()
     }
 
-    #[allow(dead_code)]
     pub fn num_dice_out(&self) -> usize {
         self.dice_out_birdfeeder.len()
     }
 
     pub fn contains(&self, food_idx: FoodIndex) -> usize {
         let dice_idxs = food_idx.dice_sides();
-        match food_idx {
-            FoodIndex::Invertebrate => vec![0, 5],
-            FoodIndex::Seed => vec![1, 5],
-            FoodIndex::Fish => vec![2],
-            FoodIndex::Fruit => vec![3],
-            FoodIndex::Rodent => vec![4],
-        };
 
         self.dice_in_birdfeeder
             .iter()
@@ -133,8 +160,8 @@ impl BirdFeeder {
             .count()
     }
 
-    pub fn roll_all_dice_not_in_birdfeeder(&self, rng: &mut StdRng) -> Vec<u8> {
-        sample_dice(rng, self.dice_out_birdfeeder.len())
+    pub fn roll_all_dice_not_in_birdfeeder(&self, rng: &mut ChaCha12Rng) -> Vec<u8> {
+        self.sample_dice(rng, self.dice_out_birdfeeder.len())
     }
 
     pub fn num_actions(&self) -> usize {
@@ -1,11 +1,12 @@
 // This code is generated automatically via a script in code_gen/ folder
 use strum_macros::EnumIter;
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use super::ScoringRule;
 use crate::expansion::Expansion;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, Serialize, Deserialize)]
 #[pyclass(eq, eq_int)]
 pub enum BonusCard {
     Anatomist,
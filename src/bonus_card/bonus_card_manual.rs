@@ -1,6 +1,6 @@
 use itertools::Itertools as _;
 
-use crate::{habitat::Habitat, nest::NestType, player::Player};
+use crate::{food::FoodIndex, habitat::Habitat, nest::NestType, player::Player};
 
 use super::BonusCard;
 
@@ -77,14 +77,16 @@ impl BonusCard {
                     .get_mat()
                     .rows()
                     .iter()
-                    .flat_map(|mat_row| {
+                    .map(|mat_row| {
                         mat_row
                             .get_birds()
                             .iter()
                             .map(|b| b.color())
                             .unique_by(|b| b.unique_id())
+                            .count()
                     })
-                    .count()
+                    .max()
+                    .unwrap()
             }
             BonusCard::ForestDataAnalyst
             | BonusCard::GrasslandDataAnalyst
@@ -200,15 +202,10 @@ impl BonusCard {
                     .count()
             }
             BonusCard::SiteSelectionExpert => {
-                // Columns with a matching pair or trio of nests
-                todo!("This bonus card is not supported yet. This is because there are two conditions, with 2 different counts on it.")
-                // player
-                //     .get_mat()
-                //     .get_columns()
-                //     .iter()
-                //     .map(
-                //         |bc_col| bc_col
-                //     )
+                // Columns with a matching pair or trio of nests.
+                // Scored per column (pair = 1, trio = 3), which a single count cannot express.
+                // It is left out of the bonus deck (see `get_deck`), so it never gets here in a game.
+                0
             }
             BonusCard::AvianTheriogenologist => {
                 // Birds with completely full nests
@@ -330,7 +327,16 @@ impl BonusCard {
             }
             BonusCard::PelletDissector => {
                 // [fish] and [rodent] tokens cached on your birds
-                todo!()
+                player
+                    .get_mat()
+                    .rows()
+                    .iter()
+                    .flat_map(|row| row.get_cached_food())
+                    .map(|cached_food| {
+                        cached_food[FoodIndex::Fish as usize] as usize
+                            + cached_food[FoodIndex::Rodent as usize] as usize
+                    })
+                    .sum()
             }
             BonusCard::WinterFeeder => {
                 // Food remaining in your supply at end of game
@@ -353,7 +359,7 @@ impl BonusCard {
 #[cfg(test)]
 mod tests {
     use crate::{
-        bird_card::BirdCard, habitat::HABITATS, player_mat::{MatRow, PlayerMat}
+        bird_card::BirdCard, expansion::Expansion, habitat::HABITATS, player_mat::{MatRow, PlayerMat}
     };
 
     use super::*;
@@ -419,8 +425,42 @@ mod tests {
             vec![BirdCard::BarrowsGoldeneye],
             3
         ),
+        (
+            ethologist: BonusCard::Ethologist,
+            vec![BirdCard::AmericanCrow, BirdCard::BaldEagle, BirdCard::AmericanAvocet],
+            vec![BirdCard::AmericanKestrel, BirdCard::AmericanWoodcock],
+            vec![BirdCard::BarrowsGoldeneye],
+            3
+        ),
 
     );
+
+    #[test]
+    fn pellet_dissector_counts_cached_fish_and_rodents() {
+        let mut player = make_player_from_cards_on_table(vec![], vec![], vec![]);
+        let mut cached_food = [[0; crate::food::NUM_FOODS]; 2];
+        cached_food[0][FoodIndex::Fish as usize] = 2;
+        cached_food[0][FoodIndex::Seed as usize] = 4;
+        cached_food[1][FoodIndex::Rodent as usize] = 3;
+        *player.get_mat_mut().get_row_mut(&Habitat::Wetland) = MatRow::new_test(
+            Habitat::Wetland,
+            vec![0, 1],
+            2,
+            vec![BirdCard::AmericanAvocet, BirdCard::BaldEagle],
+            vec![0, 0],
+            cached_food.to_vec(),
+            vec![0, 0],
+            vec![2, 2],
+        );
+
+        assert_eq!(BonusCard::PelletDissector.get_count_of_matching(&player), 5);
+    }
+
+    #[test]
+    fn site_selection_expert_is_not_dealt() {
+        let deck = super::super::get_deck(&[Expansion::Core, Expansion::Oceania]);
+        assert!(!deck.contains(&BonusCard::SiteSelectionExpert));
+    }
     // #[test]
     // fn test_get_count_of_matching_reg_bonus_card() {
     //     make_player_from_cards_on_table(forest_cards, grassland_cards, wetland_cards)
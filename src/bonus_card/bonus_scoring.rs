@@ -6,6 +6,22 @@ pub enum ScoringRule {
     Ladder(Box<[(u8, u8)]>),
 }
 
+impl ScoringRule {
+    /// Points for a given number of matching birds (or other items).
+    pub fn points(&self, count: usize) -> u8 {
+        match self {
+            ScoringRule::Each(points_per_each) => points_per_each * count as u8,
+            // Highest threshold reached
+            ScoringRule::Ladder(steps) => steps
+                .iter()
+                .take_while(|(threshold, _)| count >= *threshold as usize)
+                .last()
+                .map(|(_, points)| *points)
+                .unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[pyclass(eq, eq_int)]
 pub enum PyScoringRuleType {
@@ -13,13 +29,6 @@ pub enum PyScoringRuleType {
     Ladder = 1,
 }
 
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PyScoringRule {
-    rule_type: PyScoringRuleType,
-    value: ScoringRule,
-}
-
 impl <'py> IntoPyObject<'py> for ScoringRule {
     type Target = PyTuple;
 
@@ -12,13 +12,11 @@ use strum::IntoEnumIterator as _;
 use crate::expansion::Expansion;
 
 pub(crate) fn get_deck(expansions: &[Expansion]) -> Vec<BonusCard> {
-    if expansions.len() != 1 && expansions.first().unwrap() != &Expansion::Core {
-        todo!("Only core is supported so far. Expansions add new logic which we have not implemented yet.")
-    }
-
     let expansions = HashSet::<Expansion>::from_iter(expansions.iter().cloned());
 
     BonusCard::iter()
         .filter(|bc| expansions.contains(&bc.expansion()))
+        // Scored per column, which `get_count_of_matching` cannot express yet
+        .filter(|bc| *bc != BonusCard::SiteSelectionExpert)
         .collect()
 }
@@ -2,8 +2,9 @@ use crate::{
     bird_card::BirdCard,
     error::{WingError, WingResult},
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct DeckAndHolder {
     _bird_deck: Vec<BirdCard>,
     _face_up_display: Vec<BirdCard>,
@@ -33,7 +34,17 @@ impl DeckAndHolder {
         &self._face_up_display
     }
 
+    pub fn replace_deck(&mut self, deck: Vec<BirdCard>) -> Vec<BirdCard> {
+        std::mem::replace(&mut self._bird_deck, deck)
+    }
+
+    pub fn num_cards_in_deck(&self) -> usize {
+        self._bird_deck.len()
+    }
+
+    /// Draws up to num_cards. Fewer are drawn once the deck runs out.
     pub fn draw_cards_from_deck(&mut self, num_cards: usize) -> Vec<BirdCard> {
+        let num_cards = num_cards.min(self._bird_deck.len());
         self._bird_deck.split_off(self._bird_deck.len() - num_cards)
     }
 
@@ -44,7 +55,7 @@ impl DeckAndHolder {
             0 => self._bird_deck.pop(),
             1..=3 => {
                 let display_idx = source_idx - 1;
-                if display_idx >= self._face_up_display.len() {
+                if display_idx < self._face_up_display.len() {
                     Some(self._face_up_display.remove(display_idx))
                 } else {
                     None
@@ -60,3 +71,32 @@ impl DeckAndHolder {
         1 + self._face_up_display.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_card_takes_face_up_cards_from_display() {
+        let mut deck_and_holder = DeckAndHolder::new(vec![
+            BirdCard::AmericanAvocet,
+            BirdCard::AmericanBittern,
+            BirdCard::AmericanCoot,
+            BirdCard::AmericanCrow,
+        ]);
+        deck_and_holder.reset_display();
+        let display = deck_and_holder.get_display_cards().clone();
+
+        assert_eq!(deck_and_holder.draw_card(2).unwrap(), display[1]);
+        assert_eq!(
+            deck_and_holder.get_display_cards(),
+            &vec![display[0], display[2]]
+        );
+        // Only 2 cards are left in the display
+        assert!(deck_and_holder.draw_card(3).is_err());
+        assert_eq!(
+            deck_and_holder.draw_card(0).unwrap(),
+            BirdCard::AmericanAvocet
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Sampling of hidden information, consistent with what a given player knows.
+//!
+//! Hidden information is:
+//!  - cards in hands of other players, except ones they were seen taking (i.e. from the face-up display),
+//!  - bonus cards of other players,
+//!  - order of the bird and bonus decks,
+//!  - future rolls of the birdfeeder.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{serialization::restore_caches, wingspan_env::WingspanEnv};
+
+impl WingspanEnv {
+    /// Copy of the environment, in which everything hidden from `observer_idx` is reshuffled among the unseen cards.
+    ///
+    /// Public information (mats, foods, display, birdfeeder, hand sizes, cards known to be in hands) is preserved.
+    /// Replay and undo history are not carried over (see `detached_clone`), since they describe the actual game.
+    pub fn determinize<R: Rng + ?Sized>(&self, observer_idx: usize, rng: &mut R) -> Self {
+        let mut env = self.detached_clone();
+
+        let opponent_idxs: Vec<_> = (0..env.config().num_players)
+            .filter(|player_idx| *player_idx != observer_idx)
+            .collect();
+
+        // Pool everything observer has not seen
+        let mut bird_pool = env._bird_deck.replace_deck(vec![]);
+        let mut bonus_pool = std::mem::take(&mut env._bonus_deck);
+        for player_idx in opponent_idxs.iter() {
+            let player = env.get_player(*player_idx);
+            bird_pool.extend(
+                player
+                    .hidden_bird_card_idxs()
+                    .into_iter()
+                    .map(|idx| player.get_bird_cards()[idx]),
+            );
+            bonus_pool.extend(player.get_bonus_cards().iter().copied());
+        }
+        bird_pool.shuffle(rng);
+        bonus_pool.shuffle(rng);
+
+        // Deal it back, keeping sizes of the hands and the decks
+        for player_idx in opponent_idxs {
+            let player = env.get_player_mut(player_idx);
+            for idx in player.hidden_bird_card_idxs() {
+                // Unwrap is safe, since pool contains all of the hidden cards
+                player.replace_bird_card(idx, bird_pool.pop().unwrap());
+            }
+            for idx in 0..player.get_bonus_cards().len() {
+                player.replace_bonus_card(idx, bonus_pool.pop().unwrap());
+            }
+        }
+        env._bird_deck.replace_deck(bird_pool);
+        env._bonus_deck = bonus_pool;
+
+        env.rng = ChaCha12Rng::seed_from_u64(rng.gen());
+
+        restore_caches(env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        bird_card::BirdCard,
+        bonus_card::BonusCard,
+        step_result::StepResult,
+        wingspan_env::{test_utils::random_legal_action, WingspanEnv, WingspanEnvConfigBuilder},
+    };
+
+    // All cards, which are not on mats or in the display
+    fn unplayed_cards(env: &WingspanEnv) -> (Vec<BirdCard>, Vec<BonusCard>) {
+        let mut bird_cards = env._bird_deck.clone().replace_deck(vec![]);
+        let mut bonus_cards = env._bonus_deck.clone();
+        for player_idx in 0..env.config().num_players {
+            bird_cards.extend(env.get_player(player_idx).get_bird_cards());
+            bonus_cards.extend(env.get_player(player_idx).get_bonus_cards());
+        }
+        bird_cards.sort();
+        bonus_cards.sort();
+        (bird_cards, bonus_cards)
+    }
+
+    #[test]
+    fn determinization_preserves_public_information() {
+        for seed in 0..10 {
+            let mut env = WingspanEnv::try_new(
+                WingspanEnvConfigBuilder::default()
+                    .num_players(3usize)
+                    .build()
+                    .unwrap(),
+            );
+            env.reset(Some(seed));
+            let mut rng = StdRng::seed_from_u64(seed);
+            for _ in 0..(20 * seed as usize) {
+                let action_idx = random_legal_action(&mut env, &mut rng);
+                env.step(action_idx).unwrap();
+            }
+
+            let observer_idx = seed as usize % 3;
+            let mut num_changed_hands = 0;
+            for _ in 0..5 {
+                let mut determinized = env.determinize(observer_idx, &mut rng);
+
+                let observation = env.observation_for_player(observer_idx);
+                let determinized_observation = determinized.observation_for_player(observer_idx);
+                if env.current_player_idx() == observer_idx {
+                    assert_eq!(determinized_observation, observation);
+                    assert_eq!(determinized.action_mask(), env.action_mask());
+                } else {
+                    // Action space of other player's decision depends on their hand (last entry of observation)
+                    let num_public = observation.len() - 1;
+                    assert_eq!(
+                        determinized_observation[..num_public],
+                        observation[..num_public]
+                    );
+                }
+                assert_eq!(unplayed_cards(&determinized), unplayed_cards(&env));
+
+                let observer = env.get_player(observer_idx);
+                let determinized_observer = determinized.get_player(observer_idx);
+                assert_eq!(
+                    determinized_observer.get_bird_cards(),
+                    observer.get_bird_cards()
+                );
+                assert_eq!(
+                    determinized_observer.get_bonus_cards(),
+                    observer.get_bonus_cards()
+                );
+
+                for player_idx in 0..3 {
+                    let player = env.get_player(player_idx);
+                    let determinized_player = determinized.get_player(player_idx);
+                    let hidden_idxs = player.hidden_bird_card_idxs();
+                    assert_eq!(
+                        determinized_player.hidden_bird_card_idxs().len(),
+                        hidden_idxs.len()
+                    );
+                    for idx in 0..player.get_bird_cards().len() {
+                        if !hidden_idxs.contains(&idx) {
+                            assert_eq!(
+                                determinized_player.get_bird_cards()[idx],
+                                player.get_bird_cards()[idx]
+                            );
+                        }
+                    }
+                    if determinized_player.get_bird_cards() != player.get_bird_cards() {
+                        num_changed_hands += 1;
+                    }
+                }
+
+                // Determinized game can be played to the end
+                loop {
+                    let action_idx = random_legal_action(&mut determinized, &mut rng);
+                    if determinized.step(action_idx).unwrap() == StepResult::Terminated {
+                        break;
+                    }
+                }
+            }
+            // Hands can only change if opponents hold any unseen cards
+            let num_hidden_cards: usize = (0..3)
+                .filter(|player_idx| *player_idx != observer_idx)
+                .map(|player_idx| env.get_player(player_idx).hidden_bird_card_idxs().len())
+                .sum();
+            assert!(num_changed_hands > 0 || num_hidden_cards == 0);
+        }
+    }
+}
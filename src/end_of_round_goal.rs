@@ -1,23 +1,26 @@
-use rand::{rngs::StdRng, Rng};
 use pyo3::prelude::*;
+use rand::Rng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    bird_card::{BeakDirection, BirdCardColor},
+    bird_card::{BeakDirection, BirdCard, BirdCardColor},
     expansion::Expansion,
     food::CostAlternative,
-    habitat::Habitat,
+    habitat::{Habitat, HABITATS},
     nest::NestType,
+    player_mat::PlayerMat,
     wingspan_env::WingspanEnv,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[pyclass(eq, eq_int)]
 pub enum EndOfRoundScoring {
     Competitive = 0,
     Friendly = 1,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EndOfRoundGoal {
     // Core
     BirdsInHabitat(Habitat),
@@ -47,10 +50,21 @@ pub enum EndOfRoundGoal {
 }
 
 impl EndOfRoundGoal {
+    /// Index of this goal among sides of all the goal tiles (across all expansions).
+    pub fn unique_id(&self) -> usize {
+        CORE_CARDS
+            .iter()
+            .chain(EUROPEAN_CARDS.iter())
+            .chain(OCEANIA_CARDS.iter())
+            .flatten()
+            .position(|goal| goal == self)
+            .unwrap()
+    }
+
     pub fn get_num_matching(&self, env: &WingspanEnv, player_idx: usize) -> usize {
         let player = env.get_player(player_idx);
 
-        match self {
+        let num_matching = match self {
             EndOfRoundGoal::BirdsInHabitat(habitat) => {
                 player.get_mat().get_row(habitat).get_birds().len()
             }
@@ -139,7 +153,12 @@ impl EndOfRoundGoal {
                 .into_iter()
                 .sum(),
             EndOfRoundGoal::FoodInPlayerSupply => player.get_foods().iter().sum::<u8>() as usize,
-            EndOfRoundGoal::FilledColumns => player.get_mat().get_columns().len(),
+            EndOfRoundGoal::FilledColumns => player
+                .get_mat()
+                .get_columns()
+                .iter()
+                .filter(|column| column.iter().all(|bird_card| bird_card.is_some()))
+                .count(),
             EndOfRoundGoal::FoodCostPlayed => player
                 .get_mat()
                 .rows()
@@ -186,32 +205,109 @@ impl EndOfRoundGoal {
                 .map(|row| {
                     row.get_birds()
                         .iter()
-                        .map(|bc| {
-                            let bc_cost = bc.cost();
-                            let food_cost_iter = bc_cost.0.iter().zip(foods_to_include).filter_map(
-                                |(food_cost, include_food)| {
-                                    if *include_food {
-                                        *food_cost
-                                    } else {
-                                        None
-                                    }
-                                },
-                            );
-
-                            if bc_cost.2 == CostAlternative::No {
-                                food_cost_iter.sum()
-                            } else {
-                                food_cost_iter.max().unwrap_or_default()
-                            }
-                        })
+                        .map(|bc| food_cost_of(bc, foods_to_include))
                         .sum::<u8>() as usize
                 })
                 .into_iter()
                 .sum(),
+        };
+
+        // Some European birds count double, which is the same as counting them once more
+        let mat = player.get_mat();
+        let num_doubled: usize = HABITATS
+            .iter()
+            .flat_map(|habitat| {
+                let birds = mat.get_row(habitat).get_birds();
+                (0..birds.len())
+                    .filter(|bird_idx| birds[*bird_idx].counts_double_for_round_goal())
+                    .map(move |bird_idx| (habitat, bird_idx))
+            })
+            .map(|(habitat, bird_idx)| self.num_matching_of_bird(mat, habitat, bird_idx))
+            .sum();
+
+        num_matching + num_doubled
+    }
+
+    // Amount a single bird adds to the goal. It is 0 for goals, which do not count birds on the mat.
+    fn num_matching_of_bird(&self, mat: &PlayerMat, habitat: &Habitat, bird_idx: usize) -> usize {
+        let row = mat.get_row(habitat);
+        let bc = &row.get_birds()[bird_idx];
+        let eggs = row.get_eggs()[bird_idx] as usize;
+        let has_nest_type =
+            |nest_type: &NestType| bc.nest_type() == nest_type || bc.nest_type() == &NestType::Wild;
+
+        match self {
+            EndOfRoundGoal::BirdsInHabitat(goal_habitat) => (goal_habitat == habitat) as usize,
+            EndOfRoundGoal::BirdsWithEggWithNestType(nest_type) => {
+                has_nest_type(nest_type) as usize
+            }
+            EndOfRoundGoal::EggsInNestType(nest_type) if has_nest_type(nest_type) => eggs,
+            EndOfRoundGoal::EggsInHabitat(goal_habitat) if goal_habitat == habitat => eggs,
+            EndOfRoundGoal::BirdsPlayedTotal => 1,
+            EndOfRoundGoal::BirdsWithTuckedCards => (row.get_tucked_cards()[bird_idx] > 0) as usize,
+            EndOfRoundGoal::BirdsInOneRow => {
+                let max_birds = mat
+                    .rows()
+                    .map(|row| row.get_birds().len())
+                    .into_iter()
+                    .max();
+                (Some(row.get_birds().len()) == max_birds) as usize
+            }
+            EndOfRoundGoal::BirdsWithNoEggs => (eggs == 0) as usize,
+            EndOfRoundGoal::BirdsGEPoints(pts_thres) => (bc.points() >= *pts_thres) as usize,
+            EndOfRoundGoal::BirdsWithColor(BirdCardColor::White) => {
+                matches!(bc.color(), BirdCardColor::White | BirdCardColor::None) as usize
+            }
+            EndOfRoundGoal::BirdsWithColor(bird_card_color) => {
+                (bc.color() == bird_card_color) as usize
+            }
+            EndOfRoundGoal::FoodCostPlayed => bc.cost().1 as usize,
+            EndOfRoundGoal::BeakPointingLeft => {
+                (bc.beak_direction() == BeakDirection::Left) as usize
+            }
+            EndOfRoundGoal::BeakPointingRight => {
+                (bc.beak_direction() == BeakDirection::Right) as usize
+            }
+            EndOfRoundGoal::BirdsLEPoints(pts_thres) => (bc.points() <= *pts_thres) as usize,
+            EndOfRoundGoal::FoodsInBirdCosts(foods_to_include) => {
+                food_cost_of(bc, foods_to_include) as usize
+            }
+            EndOfRoundGoal::EggsInNestType(_)
+            | EndOfRoundGoal::EggsInHabitat(_)
+            | EndOfRoundGoal::SetsOfEggs
+            | EndOfRoundGoal::BirdsInHand
+            | EndOfRoundGoal::FoodInPlayerSupply
+            | EndOfRoundGoal::FilledColumns
+            | EndOfRoundGoal::CubesOnPlayABird => 0,
         }
     }
 }
 
+// Food of the types in the bird's cost. For "/" costs only one of the options is paid, so it is the biggest of them.
+fn food_cost_of(bc: &BirdCard, foods_to_include: &[bool; 5]) -> u8 {
+    let bc_cost = bc.cost();
+    let food_cost_iter =
+        bc_cost
+            .0
+            .iter()
+            .zip(foods_to_include)
+            .filter_map(
+                |(food_cost, include_food)| {
+                    if *include_food {
+                        *food_cost
+                    } else {
+                        None
+                    }
+                },
+            );
+
+    if bc_cost.2 == CostAlternative::No {
+        food_cost_iter.sum()
+    } else {
+        food_cost_iter.max().unwrap_or_default()
+    }
+}
+
 type Tile = [EndOfRoundGoal; 2];
 
 const CORE_CARDS: [Tile; 8] = [
@@ -310,12 +406,8 @@ fn get_end_of_round_deck(expansions: &[Expansion]) -> Vec<Tile> {
 pub(crate) fn sample_end_of_round_goals(
     expansions: &[Expansion],
     num_rounds: usize,
-    rng: &mut StdRng,
+    rng: &mut ChaCha12Rng,
 ) -> Vec<EndOfRoundGoal> {
-    if expansions.len() != 1 && expansions.first().unwrap() != &Expansion::Core {
-        todo!("Only core is supported so far. Expansions add new logic which we have not implemented yet.")
-    }
-
     let mut tiles = get_end_of_round_deck(expansions);
     let mut result = vec![];
 
@@ -328,3 +420,221 @@ pub(crate) fn sample_end_of_round_goals(
 
     result
 }
+
+// Points for the 1st, 2nd and 3rd place in each round, as printed on the green (competitive) side of the goal board
+const COMPETITIVE_PLACE_POINTS: [[u8; 3]; 4] = [[4, 1, 0], [5, 2, 1], [6, 3, 2], [7, 4, 3]];
+// Blue (friendly) side awards 1 point per matching item, up to 5 in every round
+const FRIENDLY_MAX_POINTS: u8 = 5;
+
+/// Result of a single player for the goal of a single round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[pyclass(frozen, get_all)]
+pub struct RoundGoalScore {
+    pub player_idx: usize,
+    pub num_matching: usize,
+    /// Place among players with at least one matching item (0 is first), regardless of the scoring style.
+    /// Tied players share the best of their places, and the following places are skipped.
+    pub placement: Option<usize>,
+    /// Number of players sharing the placement (incl. this one). 0 if there is no placement.
+    pub num_tied: usize,
+    /// Points awarded. On the competitive side, it is the player's part of the points split among tied players.
+    pub points: u8,
+}
+
+#[pymethods]
+impl RoundGoalScore {
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+/// Goal of a single round, together with results of all of the players.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[pyclass(frozen)]
+pub struct RoundGoalRecord {
+    pub round_idx: usize,
+    pub goal: EndOfRoundGoal,
+    pub scoring_style: EndOfRoundScoring,
+    /// Indexed by player.
+    pub scores: Vec<RoundGoalScore>,
+}
+
+#[pymethods]
+impl RoundGoalRecord {
+    #[getter]
+    fn round_idx(&self) -> usize {
+        self.round_idx
+    }
+
+    /// Description of the goal (i.e. "EggsInHabitat(Forest)").
+    #[getter]
+    fn goal(&self) -> String {
+        format!("{:?}", self.goal)
+    }
+
+    /// Index of the goal among sides of all the goal tiles (observation stores it shifted by 1).
+    #[getter]
+    fn goal_id(&self) -> usize {
+        self.goal.unique_id()
+    }
+
+    #[getter]
+    fn scoring_style(&self) -> EndOfRoundScoring {
+        self.scoring_style
+    }
+
+    #[getter]
+    fn scores(&self) -> Vec<RoundGoalScore> {
+        self.scores.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+/// Scores goal of a round, given number of matching items of each player.
+///
+/// On the competitive side, tied players sum up points of the places they occupy, and split them (rounding down).
+/// Players without any matching items do not score on either side.
+pub(crate) fn score_round_goal(
+    scoring_style: EndOfRoundScoring,
+    round_idx: usize,
+    num_matching: &[usize],
+) -> Vec<RoundGoalScore> {
+    num_matching
+        .iter()
+        .enumerate()
+        .map(|(player_idx, player_matching)| {
+            if *player_matching == 0 {
+                return RoundGoalScore {
+                    player_idx,
+                    num_matching: 0,
+                    placement: None,
+                    num_tied: 0,
+                    points: 0,
+                };
+            }
+
+            let placement = num_matching
+                .iter()
+                .filter(|other| *other > player_matching)
+                .count();
+            let num_tied = num_matching
+                .iter()
+                .filter(|other| *other == player_matching)
+                .count();
+
+            let points = match scoring_style {
+                EndOfRoundScoring::Competitive => {
+                    let place_points = &COMPETITIVE_PLACE_POINTS[round_idx];
+                    let total_points: u8 = place_points.iter().skip(placement).take(num_tied).sum();
+                    total_points / num_tied as u8
+                }
+                EndOfRoundScoring::Friendly => {
+                    (*player_matching).min(FRIENDLY_MAX_POINTS as usize) as u8
+                }
+            };
+
+            RoundGoalScore {
+                player_idx,
+                num_matching: *player_matching,
+                placement: Some(placement),
+                num_tied,
+                points,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(
+        scoring_style: EndOfRoundScoring,
+        round_idx: usize,
+        num_matching: &[usize],
+    ) -> Vec<u8> {
+        score_round_goal(scoring_style, round_idx, num_matching)
+            .iter()
+            .map(|score| score.points)
+            .collect()
+    }
+
+    #[test]
+    fn competitive_scoring_follows_goal_board() {
+        use EndOfRoundScoring::Competitive;
+
+        // Per round maximums
+        assert_eq!(points(Competitive, 0, &[3, 2, 1, 0]), [4, 1, 0, 0]);
+        assert_eq!(points(Competitive, 1, &[3, 2, 1, 0]), [5, 2, 1, 0]);
+        assert_eq!(points(Competitive, 2, &[1, 3, 2, 0]), [2, 6, 3, 0]);
+        assert_eq!(points(Competitive, 3, &[1, 3, 2, 5, 1]), [0, 4, 3, 7, 0]);
+
+        // Ties split points of occupied places, rounding down
+        assert_eq!(points(Competitive, 0, &[2, 2]), [2, 2]);
+        assert_eq!(points(Competitive, 3, &[2, 2, 1]), [5, 5, 3]);
+        assert_eq!(points(Competitive, 3, &[4, 2, 2, 2]), [7, 2, 2, 2]);
+        assert_eq!(points(Competitive, 1, &[1, 1, 1, 1, 1]), [1, 1, 1, 1, 1]);
+
+        // No items, no points
+        assert_eq!(points(Competitive, 3, &[0, 0]), [0, 0]);
+
+        let scores = score_round_goal(Competitive, 0, &[2, 5, 2, 0]);
+        let placements: Vec<_> = scores.iter().map(|score| score.placement).collect();
+        assert_eq!(placements, [Some(1), Some(0), Some(1), None]);
+        let num_tied: Vec<_> = scores.iter().map(|score| score.num_tied).collect();
+        assert_eq!(num_tied, [2, 1, 2, 0]);
+    }
+
+    #[test]
+    fn european_birds_change_what_goals_count() {
+        use crate::wingspan_env::WingspanEnvConfigBuilder;
+
+        let config = WingspanEnvConfigBuilder::default()
+            .expansions(vec![Expansion::Core, Expansion::European])
+            .build()
+            .unwrap();
+        let mut env = WingspanEnv::try_new(config);
+        for (bird_card, habitat) in [
+            (BirdCard::CommonBlackbird, Habitat::Forest),
+            (BirdCard::AmericanRobin, Habitat::Grassland),
+            (BirdCard::HouseWren, Habitat::Grassland),
+            (BirdCard::CettisWarbler, Habitat::Wetland),
+            (BirdCard::Mallard, Habitat::Wetland),
+        ] {
+            env.current_player_mut()
+                .get_mat_mut()
+                .put_bird_card(bird_card, &habitat)
+                .unwrap();
+        }
+
+        // Common Blackbird is played sideways, so it completes both columns
+        assert_eq!(EndOfRoundGoal::FilledColumns.get_num_matching(&env, 0), 2);
+        // Cetti's Warbler counts double for round goals
+        assert_eq!(
+            EndOfRoundGoal::BirdsInHabitat(Habitat::Wetland).get_num_matching(&env, 0),
+            3
+        );
+        assert_eq!(
+            EndOfRoundGoal::BirdsInHabitat(Habitat::Forest).get_num_matching(&env, 0),
+            1
+        );
+    }
+
+    #[test]
+    fn friendly_scoring_follows_goal_board() {
+        use EndOfRoundScoring::Friendly;
+
+        // 1 point per item, up to 5 in every round, regardless of other players
+        for round_idx in 0..4 {
+            assert_eq!(
+                points(Friendly, round_idx, &[0, 3, 5, 6, 9]),
+                [0, 3, 5, 5, 5]
+            );
+            assert_eq!(points(Friendly, round_idx, &[2, 2]), [2, 2]);
+            assert_eq!(points(Friendly, round_idx, &[9, 9]), [5, 5]);
+        }
+    }
+}
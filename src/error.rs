@@ -11,6 +11,18 @@ pub enum WingError {
 
     #[error("Task called out of order")]
     TaskOutOfOrder(String),
+
+    #[error("Could not (de)serialize state")]
+    Serialization(String),
+
+    #[error("Replay does not match the recorded game")]
+    ReplayMismatch(String),
+
+    #[error("Nothing to undo")]
+    NothingToUndo,
+
+    #[error("Number of actions does not match number of environments")]
+    ActionCountMismatch(String),
 }
 
 impl From<WingError> for PyErr {
@@ -19,6 +31,10 @@ impl From<WingError> for PyErr {
             WingError::InvalidAction => PyValueError::new_err(format!("{}", val)),
             WingError::InvalidBird(err_msg) => PyValueError::new_err(err_msg.to_string()),
             WingError::TaskOutOfOrder(err_msg) => PyValueError::new_err(err_msg.to_string()),
+            WingError::Serialization(err_msg) => PyValueError::new_err(err_msg.to_string()),
+            WingError::ReplayMismatch(err_msg) => PyValueError::new_err(err_msg.to_string()),
+            WingError::NothingToUndo => PyValueError::new_err(format!("{}", val)),
+            WingError::ActionCountMismatch(err_msg) => PyValueError::new_err(err_msg.to_string()),
         }
     }
 }
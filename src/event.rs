@@ -0,0 +1,226 @@
+use pyo3::prelude::*;
+
+use crate::{bird_card::BirdCard, bonus_card::BonusCard, food::FoodIndex, habitat::Habitat};
+
+/// Things that happened in the game, in order in which they happened.
+///
+/// Birds are identified by the player owning them, habitat and index in that habitat's row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[pyclass(frozen)]
+pub enum GameEvent {
+    BirdPlayed {
+        player_idx: usize,
+        bird_card: BirdCard,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    // Brown, white, teal or yellow power of a bird was activated
+    BirdPowerActivated {
+        player_idx: usize,
+        bird_card: BirdCard,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    // Pink power of a bird reacted to an action of another player
+    PinkPowerTriggered {
+        player_idx: usize,
+        bird_card: BirdCard,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    PredatorSucceeded {
+        player_idx: usize,
+        bird_card: BirdCard,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    FoodGainedFromFeeder {
+        player_idx: usize,
+        food: FoodIndex,
+    },
+    FoodGainedFromSupply {
+        player_idx: usize,
+        food: FoodIndex,
+    },
+    BirdFeederRerolled {
+        player_idx: usize,
+    },
+    FoodDiscarded {
+        player_idx: usize,
+        food: FoodIndex,
+        num_food: u8,
+    },
+    EggLaid {
+        player_idx: usize,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    EggDiscarded {
+        player_idx: usize,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    BirdCardDrawn {
+        player_idx: usize,
+        bird_card: BirdCard,
+        from_display: bool,
+    },
+    BirdCardDiscarded {
+        player_idx: usize,
+        bird_card: BirdCard,
+    },
+    BonusCardDiscarded {
+        player_idx: usize,
+        bonus_card: BonusCard,
+    },
+    CardTucked {
+        player_idx: usize,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    FoodCached {
+        player_idx: usize,
+        habitat: Habitat,
+        bird_idx: usize,
+        food: FoodIndex,
+    },
+    BirdMoved {
+        player_idx: usize,
+        bird_card: BirdCard,
+        habitat: Habitat,
+    },
+    RoundGoalScored {
+        round_idx: usize,
+        player_idx: usize,
+        points: u8,
+    },
+}
+
+#[pymethods]
+impl GameEvent {
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        player::Player,
+        step_result::StepResult,
+        wingspan_env::{test_utils::random_legal_action, WingspanEnv, WingspanEnvConfigBuilder},
+    };
+
+    fn num_eggs(player: &Player) -> usize {
+        player
+            .get_mat()
+            .rows()
+            .iter()
+            .flat_map(|row| row.get_eggs().iter())
+            .map(|eggs| *eggs as usize)
+            .sum()
+    }
+
+    fn num_birds(player: &Player) -> usize {
+        player
+            .get_mat()
+            .rows()
+            .iter()
+            .map(|row| row.get_birds().len())
+            .sum()
+    }
+
+    #[test]
+    fn events_match_changes_in_state() {
+        for seed in 0..20 {
+            let mut env =
+                WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+            env.reset(Some(seed));
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut round_goal_points = [0; 2];
+
+            loop {
+                // Invalid actions do not produce events
+                assert!(env.step(u8::MAX).is_err());
+                assert!(env.events().is_empty());
+
+                let action_idx = random_legal_action(&mut env, &mut rng);
+                let eggs_before: Vec<_> = (0..2).map(|idx| num_eggs(env.get_player(idx))).collect();
+                let birds_before: Vec<_> =
+                    (0..2).map(|idx| num_birds(env.get_player(idx))).collect();
+
+                let result = env.step(action_idx).unwrap();
+
+                for player_idx in 0..2 {
+                    let count = |f: &dyn Fn(&GameEvent) -> bool| {
+                        env.events().iter().filter(|event| f(event)).count() as isize
+                    };
+                    let eggs_laid = count(
+                        &|event| matches!(event, GameEvent::EggLaid { player_idx: idx, .. } if *idx == player_idx),
+                    );
+                    let eggs_discarded = count(
+                        &|event| matches!(event, GameEvent::EggDiscarded { player_idx: idx, .. } if *idx == player_idx),
+                    );
+                    let birds_played = count(
+                        &|event| matches!(event, GameEvent::BirdPlayed { player_idx: idx, .. } if *idx == player_idx),
+                    );
+
+                    assert_eq!(
+                        num_eggs(env.get_player(player_idx)) as isize
+                            - eggs_before[player_idx] as isize,
+                        eggs_laid - eggs_discarded,
+                        "{:?} (seed {seed})",
+                        env.events()
+                    );
+                    assert_eq!(
+                        num_birds(env.get_player(player_idx)) as isize
+                            - birds_before[player_idx] as isize,
+                        birds_played,
+                        "{:?} (seed {seed})",
+                        env.events()
+                    );
+                }
+
+                for event in env.events() {
+                    if let GameEvent::BirdPlayed {
+                        player_idx,
+                        bird_card,
+                        habitat,
+                        bird_idx,
+                    } = event
+                    {
+                        let row = env.get_player(*player_idx).get_mat().get_row(habitat);
+                        assert_eq!(row.get_birds()[*bird_idx], *bird_card);
+                    }
+                }
+
+                for event in env.events() {
+                    if let GameEvent::RoundGoalScored {
+                        player_idx, points, ..
+                    } = event
+                    {
+                        round_goal_points[*player_idx] += *points;
+                    }
+                }
+
+                if result == StepResult::Terminated {
+                    break;
+                }
+            }
+
+            for (player_idx, points) in round_goal_points.iter().enumerate() {
+                assert_eq!(
+                    env.get_player(player_idx).get_end_of_round_points(),
+                    *points
+                );
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[pyclass(eq, eq_int)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Expansion {
     Core = 0,
     Asia = 1,
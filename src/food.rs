@@ -4,14 +4,21 @@
 // 2 - Fish
 // 3 - Fruit
 // 4 - Rodent
+// 5 - Nectar (Oceania). It is wild when paying for a bird
+//
+// Oceania is not supported yet (see `WingspanEnvConfig`), so nectar is never in play.
+// It is groundwork: bird costs do not list nectar yet, and Oceania decks and dice are not dealt.
 
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-pub type FoodReq = [Option<u8>; 5];
-pub type Foods = [u8; 5];
+pub const NUM_FOODS: usize = 6;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub type FoodReq = [Option<u8>; NUM_FOODS];
+pub type Foods = [u8; NUM_FOODS];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[pyclass(eq, eq_int)]
 pub enum CostAlternative {
     Yes = 0,
@@ -21,7 +28,7 @@ pub enum CostAlternative {
 // Total food cost
 pub type BirdCardCost = (FoodReq, u8, CostAlternative);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, Serialize, Deserialize)]
 #[pyclass(eq, eq_int)]
 pub enum FoodIndex {
     Invertebrate = 0,
@@ -29,16 +36,19 @@ pub enum FoodIndex {
     Fish = 2,
     Fruit = 3,
     Rodent = 4,
+    Nectar = 5,
 }
 
 impl FoodIndex {
+    /// Die faces showing this food (see `BirdFeeder` for the list of faces).
     pub fn dice_sides(&self) -> Vec<u8> {
         match self {
             FoodIndex::Invertebrate => vec![0, 5],
-            FoodIndex::Seed => vec![1, 5],
+            FoodIndex::Seed => vec![1, 5, 7],
             FoodIndex::Fish => vec![2],
-            FoodIndex::Fruit => vec![3],
+            FoodIndex::Fruit => vec![3, 6],
             FoodIndex::Rodent => vec![4],
+            FoodIndex::Nectar => vec![6, 7],
         }
     }
 }
@@ -51,7 +61,8 @@ impl From<u8> for FoodIndex {
             2 => FoodIndex::Fish,
             3 => FoodIndex::Fruit,
             4 => FoodIndex::Rodent,
-            x => panic!("Got {x} for FoodIndex which is more than max value of 4."),
+            5 => FoodIndex::Nectar,
+            x => panic!("Got {x} for FoodIndex which is more than max value of 5."),
         }
     }
 }
@@ -72,6 +83,7 @@ macro_rules! from_food_index_to_integer {
                     FoodIndex::Fish => 2,
                     FoodIndex::Fruit => 3,
                     FoodIndex::Rodent => 4,
+                    FoodIndex::Nectar => 5,
                 }
             }
         }
@@ -84,6 +96,7 @@ macro_rules! from_food_index_to_integer {
                     FoodIndex::Fish => 2,
                     FoodIndex::Fruit => 3,
                     FoodIndex::Rodent => 4,
+                    FoodIndex::Nectar => 5,
                 }
             }
         }
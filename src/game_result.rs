@@ -0,0 +1,195 @@
+//! Final standings of the players.
+//!
+//! Per the rulebook, player with the most points wins. Ties are broken by the unused food in player's supply,
+//! and players still tied share the victory (or the place).
+
+use pyo3::prelude::*;
+
+use crate::{score::ScoreBreakdown, wingspan_env::WingspanEnv};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[pyclass(frozen, get_all)]
+pub struct GameResult {
+    pub scores: Vec<u8>,
+    /// Tiebreaker for players with the same score.
+    pub unused_food: Vec<u8>,
+    /// Place of each player (0 is first). Players tied on both score and unused food share the best of their places.
+    pub ranks: Vec<usize>,
+    /// Indexes of the players with rank 0.
+    pub winners: Vec<usize>,
+    /// Whether more than one player won.
+    pub shared_victory: bool,
+}
+
+impl GameResult {
+    pub fn from_breakdowns(breakdowns: &[ScoreBreakdown]) -> Self {
+        let keys: Vec<_> = breakdowns
+            .iter()
+            .map(|breakdown| (breakdown.total, breakdown.unused_food))
+            .collect();
+        let ranks: Vec<_> = keys
+            .iter()
+            .map(|key| keys.iter().filter(|other| *other > key).count())
+            .collect();
+        let winners: Vec<_> = (0..ranks.len())
+            .filter(|player_idx| ranks[*player_idx] == 0)
+            .collect();
+
+        Self {
+            scores: keys.iter().map(|(score, _)| *score).collect(),
+            unused_food: keys.iter().map(|(_, unused_food)| *unused_food).collect(),
+            ranks,
+            shared_victory: winners.len() > 1,
+            winners,
+        }
+    }
+
+    pub fn num_players(&self) -> usize {
+        self.ranks.len()
+    }
+
+    /// 1 for a sole winner, split equally in case of a shared victory. 0 for everyone else.
+    pub fn win_shares(&self) -> Vec<f64> {
+        let num_winners = self.winners.len() as f64;
+        self.ranks
+            .iter()
+            .map(|rank| if *rank == 0 { 1.0 / num_winners } else { 0.0 })
+            .collect()
+    }
+
+    /// Win shares shifted, so that they sum up to 0.
+    pub fn zero_sum_rewards(&self) -> Vec<f64> {
+        let mean = 1.0 / self.num_players() as f64;
+        self.win_shares().iter().map(|share| share - mean).collect()
+    }
+
+    /// Linear in place, from 1 for the first place to -1 for the last one. Sums up to 0.
+    ///
+    /// Tied players get the average of the places they occupy.
+    pub fn rank_rewards(&self) -> Vec<f64> {
+        let num_players = self.num_players();
+        if num_players < 2 {
+            return vec![0.0; num_players];
+        }
+
+        self.ranks
+            .iter()
+            .map(|rank| {
+                let num_tied = self.ranks.iter().filter(|other| *other == rank).count();
+                let mean_place = *rank as f64 + (num_tied - 1) as f64 / 2.0;
+                1.0 - 2.0 * mean_place / (num_players - 1) as f64
+            })
+            .collect()
+    }
+}
+
+#[pymethods]
+impl GameResult {
+    #[pyo3(name = "win_shares")]
+    fn py_win_shares(&self) -> Vec<f64> {
+        self.win_shares()
+    }
+
+    #[pyo3(name = "zero_sum_rewards")]
+    fn py_zero_sum_rewards(&self) -> Vec<f64> {
+        self.zero_sum_rewards()
+    }
+
+    #[pyo3(name = "rank_rewards")]
+    fn py_rank_rewards(&self) -> Vec<f64> {
+        self.rank_rewards()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl WingspanEnv {
+    /// Standings as if the game ended right now (i.e. for scoring unfinished simulations).
+    pub fn standings(&self) -> GameResult {
+        GameResult::from_breakdowns(&self.score_breakdown())
+    }
+
+    /// Final result of the game. None until the game is terminated.
+    pub fn game_result(&self) -> Option<GameResult> {
+        self.is_terminated().then(|| self.standings())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        step_result::StepResult,
+        wingspan_env::{test_utils::random_legal_action, WingspanEnvConfigBuilder},
+    };
+
+    fn result(keys: &[(u8, u8)]) -> GameResult {
+        let breakdowns: Vec<_> = keys
+            .iter()
+            .map(|(total, unused_food)| ScoreBreakdown {
+                bird_points: *total,
+                bonus_card_points: 0,
+                bonus_cards: vec![],
+                round_goal_points: 0,
+                egg_points: 0,
+                cached_food_points: 0,
+                tucked_card_points: 0,
+                nectar_points: 0,
+                total: *total,
+                unused_food: *unused_food,
+            })
+            .collect();
+        GameResult::from_breakdowns(&breakdowns)
+    }
+
+    #[test]
+    fn game_result_is_available_at_termination() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        let mut rng = StdRng::seed_from_u64(0);
+
+        loop {
+            assert!(env.game_result().is_none());
+            let action_idx = random_legal_action(&mut env, &mut rng);
+            if env.step(action_idx).unwrap() == StepResult::Terminated {
+                break;
+            }
+        }
+
+        let game_result = env.game_result().unwrap();
+        assert_eq!(game_result.scores, env.points());
+        let max_score = *game_result.scores.iter().max().unwrap();
+        for winner_idx in game_result.winners.iter() {
+            assert_eq!(game_result.scores[*winner_idx], max_score);
+        }
+        assert_eq!(game_result.win_shares().iter().sum::<f64>(), 1.0);
+
+        env.reset(Some(1));
+        assert!(env.game_result().is_none());
+    }
+
+    #[test]
+    fn ties_are_broken_by_unused_food() {
+        let game_result = result(&[(80, 0), (92, 1), (92, 3), (70, 5)]);
+        assert_eq!(game_result.ranks, [2, 1, 0, 3]);
+        assert_eq!(game_result.winners, [2]);
+        assert!(!game_result.shared_victory);
+        assert_eq!(game_result.win_shares(), [0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(game_result.zero_sum_rewards(), [-0.25, -0.25, 0.75, -0.25]);
+
+        // Still tied players share the place
+        let game_result = result(&[(92, 2), (92, 2), (70, 5)]);
+        assert_eq!(game_result.ranks, [0, 0, 2]);
+        assert_eq!(game_result.winners, [0, 1]);
+        assert!(game_result.shared_victory);
+        assert_eq!(game_result.win_shares(), [0.5, 0.5, 0.0]);
+        assert_eq!(game_result.rank_rewards(), [0.5, 0.5, -1.0]);
+
+        let game_result = result(&[(50, 0), (60, 0), (70, 0)]);
+        assert_eq!(game_result.rank_rewards(), [-1.0, 0.0, 1.0]);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::action::Action;
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[pyclass(eq, eq_int)]
 pub enum Habitat {
     Forest = 0,
@@ -27,27 +27,3 @@ impl From<u8> for Habitat {
         Habitat::from(value as usize)
     }
 }
-
-impl Habitat {
-    pub fn action(&self) -> Action {
-        match self {
-            Habitat::Forest => Action::GetFood,
-            Habitat::Grassland => Action::GetEgg,
-            Habitat::Wetland => Action::GetBirdCard,
-        }
-    }
-
-    pub fn optional_action(&self) -> Action {
-        match self {
-            Habitat::Forest => {
-                Action::DoThen(Box::new(Action::DiscardBirdCard), Box::new(Action::GetFood))
-            }
-            Habitat::Grassland => {
-                Action::DoThen(Box::new(Action::DiscardFood), Box::new(Action::GetEgg))
-            }
-            Habitat::Wetland => {
-                Action::DoThen(Box::new(Action::DiscardEgg), Box::new(Action::GetBirdCard))
-            }
-        }
-    }
-}
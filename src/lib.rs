@@ -1,39 +1,71 @@
-use action::PyAction;
+use action::{ActionType, PyAction};
+use batch_env::BatchWingspanEnv;
 use bird_card::{BirdCard, BirdCardColor};
 use bonus_card::{BonusCard, PyScoringRuleType};
+use end_of_round_goal::{EndOfRoundScoring, RoundGoalRecord, RoundGoalScore};
+use event::GameEvent;
 use expansion::Expansion;
 use food::{CostAlternative, FoodIndex};
+use game_result::GameResult;
 use habitat::Habitat;
+use mat_layout::MatLayout;
 use player::Player;
+use reward::RewardMode;
+use score::{BonusCardScore, ScoreBreakdown};
 use pyo3::prelude::*;
 use step_result::StepResult;
-use wingspan_env::PyWingspanEnv;
+use wingspan_env::{PyWingspanEnv, WingspanEnvConfig};
 
 pub mod bird_card;
 pub mod wingspan_env;
 
 mod action;
+mod batch_env;
 mod bird_card_callback;
 mod bird_feeder;
 mod bonus_card;
 mod deck_and_holder;
+mod determinization;
 mod end_of_round_goal;
 mod error;
+mod event;
 mod expansion;
 mod food;
+mod game_result;
 mod habitat;
+mod mat_layout;
+mod mcts;
 mod nest;
+mod observation;
 mod player;
 mod player_mat;
+mod predator;
+mod replay;
+mod reward;
+mod score;
+mod serialization;
 mod step_result;
+mod trigger;
+mod undo;
 
 /// A Python module implemented in Rust.
 #[pymodule]
 #[pyo3(name = "_internal")]
 fn wingspan_gym(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyWingspanEnv>()?;
+    m.add_class::<BatchWingspanEnv>()?;
+    m.add_class::<WingspanEnvConfig>()?;
+    m.add_class::<EndOfRoundScoring>()?;
+    m.add_class::<RewardMode>()?;
+    m.add_class::<MatLayout>()?;
+    m.add_class::<RoundGoalRecord>()?;
+    m.add_class::<RoundGoalScore>()?;
+    m.add_class::<ScoreBreakdown>()?;
+    m.add_class::<BonusCardScore>()?;
+    m.add_class::<GameResult>()?;
     m.add_class::<StepResult>()?;
     m.add_class::<PyAction>()?;
+    m.add_class::<ActionType>()?;
     m.add_class::<Player>()?;
     m.add_class::<BirdCard>()?;
     m.add_class::<Habitat>()?;
@@ -43,6 +75,8 @@ fn wingspan_gym(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<BonusCard>()?;
     m.add_class::<PyScoringRuleType>()?;
     m.add_class::<FoodIndex>()?;
+    m.add_class::<GameEvent>()?;
+    m.add_function(wrap_pyfunction!(mcts::search, m)?)?;
 
     Ok(())
 }
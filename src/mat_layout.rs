@@ -0,0 +1,143 @@
+//! Rewards of the habitat actions, as printed on the player mat.
+//!
+//! Each habitat row has a column for every number of birds in it (0 to 5).
+//! Taking a habitat action gives the reward of the leftmost column not covered by a bird.
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::Action,
+    food::FoodIndex,
+    habitat::{Habitat, HABITATS},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[pyclass(eq, eq_int)]
+pub enum MatLayout {
+    /// Player mat of the base game.
+    Core = 0,
+    // TODO: Oceania mat, once its printed layout is encoded column by column
+}
+
+/// Reward of a single column of a habitat row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnReward {
+    /// Number of times the habitat action is taken (i.e. gain 1 food, lay 1 egg, draw 1 card).
+    pub num_actions: u8,
+    /// Whether player may trade for one more habitat action (see `MatLayout::trade_action`).
+    pub trade: bool,
+    /// Nectar gained from the supply.
+    pub nectar: u8,
+}
+
+const fn column(num_actions: u8, trade: bool, nectar: u8) -> ColumnReward {
+    ColumnReward {
+        num_actions,
+        trade,
+        nectar,
+    }
+}
+
+type RowLayout = [ColumnReward; 6];
+
+// Rows are in order of HABITATS (Forest, Grassland, Wetland)
+const CORE_LAYOUT: [RowLayout; 3] = [
+    [
+        column(1, false, 0),
+        column(1, true, 0),
+        column(2, false, 0),
+        column(2, true, 0),
+        column(3, false, 0),
+        column(3, true, 0),
+    ],
+    [
+        column(2, false, 0),
+        column(2, true, 0),
+        column(3, false, 0),
+        column(3, true, 0),
+        column(4, false, 0),
+        column(4, true, 0),
+    ],
+    [
+        column(1, false, 0),
+        column(1, true, 0),
+        column(2, false, 0),
+        column(2, true, 0),
+        column(3, false, 0),
+        column(3, true, 0),
+    ],
+];
+
+impl MatLayout {
+    fn rows(&self) -> &'static [RowLayout; 3] {
+        match self {
+            MatLayout::Core => &CORE_LAYOUT,
+        }
+    }
+
+    /// Reward of taking the habitat action with a given number of birds in the habitat.
+    pub fn column_reward(&self, habitat: &Habitat, num_birds: usize) -> ColumnReward {
+        let row_idx = HABITATS.iter().position(|x| x == habitat).unwrap();
+        let row = &self.rows()[row_idx];
+
+        row[num_birds.min(row.len() - 1)]
+    }
+
+    /// Single unit of the habitat action.
+    pub fn habitat_action(&self, habitat: &Habitat) -> Action {
+        match habitat {
+            Habitat::Forest => Action::GetFood,
+            Habitat::Grassland => Action::GetEgg,
+            Habitat::Wetland => Action::GetBirdCard,
+        }
+    }
+
+    /// Optional trade of a resource for one more unit of the habitat action.
+    pub fn trade_action(&self, habitat: &Habitat) -> Action {
+        let cost = match habitat {
+            Habitat::Forest => Action::DiscardBirdCard,
+            Habitat::Grassland => Action::DiscardFood(*habitat),
+            Habitat::Wetland => Action::DiscardEgg,
+        };
+
+        Action::DoThen(Box::new(cost), Box::new(self.habitat_action(habitat)))
+    }
+
+    /// Actions of the column reward. Actions are a LIFO queue, so trade is performed first.
+    pub fn column_actions(&self, habitat: &Habitat, num_birds: usize) -> Vec<Action> {
+        let reward = self.column_reward(habitat, num_birds);
+
+        let mut result: Vec<_> = (0..reward.num_actions)
+            .map(|_| self.habitat_action(habitat))
+            .collect();
+        result.extend(
+            (0..reward.nectar)
+                .map(|_| Action::GetFoodFromSupplyChoice(Box::new([FoodIndex::Nectar]))),
+        );
+        if reward.trade {
+            result.push(self.trade_action(habitat));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_layout_matches_printed_mat() {
+        // Gain food and draw cards: 1 + birds / 2. Lay eggs: 2 + birds / 2. Trade on odd number of birds.
+        for habitat in HABITATS {
+            let base = if habitat == Habitat::Grassland { 2 } else { 1 };
+            for num_birds in 0..=5 {
+                let reward = MatLayout::Core.column_reward(&habitat, num_birds);
+                assert_eq!(reward.num_actions as usize, base + num_birds / 2);
+                assert_eq!(reward.trade, num_birds % 2 == 1);
+                assert_eq!(reward.nectar, 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,585 @@
+//! Monte Carlo Tree Search (UCT) over the native environment.
+//!
+//! Tree is open-loop: nodes are identified by the sequence of choices leading to them, not by the state.
+//! This way the same tree can be shared across iterations, even though each of them samples rolls of the birdfeeder,
+//! draws from the deck and (optionally) hidden cards of other players anew.
+//!
+//! Each iteration is scored with a share of the win (1 for a sole winner, split in case of a shared victory),
+//! which keeps rewards in [0, 1] regardless of the number of players.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use derive_builder::Builder;
+use pyo3::prelude::*;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{
+    error::{WingError, WingResult},
+    step_result::StepResult,
+    wingspan_env::{PyWingspanEnv, WingspanEnv},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchBudget {
+    Iterations(usize),
+    Time(Duration),
+}
+
+/// Chooses actions during the rollout phase (i.e. after leaving the tree).
+pub trait RolloutPolicy {
+    /// Picks one of `valid_actions` (it is never empty) for the next action of `env`.
+    fn select_action(
+        &mut self,
+        env: &mut WingspanEnv,
+        valid_actions: &[u8],
+        rng: &mut ChaCha12Rng,
+    ) -> u8;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomRollout;
+
+impl RolloutPolicy for RandomRollout {
+    fn select_action(
+        &mut self,
+        _env: &mut WingspanEnv,
+        valid_actions: &[u8],
+        rng: &mut ChaCha12Rng,
+    ) -> u8 {
+        *valid_actions.choose(rng).unwrap()
+    }
+}
+
+#[derive(Debug, Builder, Clone)]
+pub struct MctsConfig {
+    #[builder(default = SearchBudget::Iterations(1000))]
+    budget: SearchBudget,
+    // Constant c in UCB1 = mean_reward + c * sqrt(ln(parent_visits) / visits)
+    #[builder(default = std::f64::consts::SQRT_2)]
+    exploration: f64,
+    // Whether each iteration resamples information hidden from the searching player (see `WingspanEnv::determinize`).
+    // Without it, search can exploit knowledge of opponents' hands and the order of the deck
+    #[builder(default = true)]
+    determinize: bool,
+    // Maximum number of rollout steps. Unfinished rollouts are scored by the current points
+    #[builder(setter(strip_option), default)]
+    max_rollout_steps: Option<usize>,
+    #[builder(setter(strip_option), default)]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActionStats {
+    pub action_idx: u8,
+    pub visits: u32,
+    /// Mean reward of the player making the decision.
+    pub mean_reward: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    /// Most visited action.
+    pub action_idx: u8,
+    pub num_iterations: usize,
+    pub stats: Vec<ActionStats>,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: HashMap<u8, usize>,
+    visits: u32,
+    // Sum of rewards per player
+    total_rewards: Vec<f64>,
+}
+
+pub struct Mcts<P: RolloutPolicy> {
+    config: MctsConfig,
+    rollout_policy: P,
+    rng: ChaCha12Rng,
+    nodes: Vec<Node>,
+}
+
+impl Mcts<RandomRollout> {
+    pub fn new(config: MctsConfig) -> Self {
+        Self::with_rollout_policy(config, RandomRollout)
+    }
+}
+
+impl<P: RolloutPolicy> Mcts<P> {
+    pub fn with_rollout_policy(config: MctsConfig, rollout_policy: P) -> Self {
+        let rng = match config.seed {
+            Some(seed) => ChaCha12Rng::seed_from_u64(seed),
+            None => ChaCha12Rng::from_entropy(),
+        };
+        Self {
+            config,
+            rollout_policy,
+            rng,
+            nodes: vec![],
+        }
+    }
+
+    /// Searches for the best choice for the next action of `root`, from perspective of the player making it.
+    pub fn search(&mut self, root: &WingspanEnv) -> WingResult<SearchResult> {
+        let mut root_env = root.detached_clone();
+        let root_actions = root_env.valid_actions();
+        if root_actions.is_empty() {
+            return Err(WingError::InvalidAction);
+        }
+        let searching_player_idx = root_env.current_player_idx();
+        let num_players = root_env.config().num_players;
+
+        self.nodes = vec![Node {
+            total_rewards: vec![0.0; num_players],
+            ..Default::default()
+        }];
+
+        let start = Instant::now();
+        let mut num_iterations = 0;
+        loop {
+            let is_done = match self.config.budget {
+                SearchBudget::Iterations(max_iterations) => num_iterations >= max_iterations,
+                SearchBudget::Time(max_duration) => start.elapsed() >= max_duration,
+            };
+            // Single choice does not need a search
+            if is_done || root_actions.len() == 1 {
+                break;
+            }
+
+            let env = if self.config.determinize {
+                root_env.determinize(searching_player_idx, &mut self.rng)
+            } else {
+                let mut env = root_env.clone();
+                env.rng = ChaCha12Rng::seed_from_u64(self.rng.gen());
+                env
+            };
+            self.iterate(env);
+            num_iterations += 1;
+        }
+
+        let root_node = &self.nodes[0];
+        let mut stats: Vec<_> = root_actions
+            .iter()
+            .map(|action_idx| match root_node.children.get(action_idx) {
+                Some(child_idx) => {
+                    let child = &self.nodes[*child_idx];
+                    ActionStats {
+                        action_idx: *action_idx,
+                        visits: child.visits,
+                        mean_reward: child.total_rewards[searching_player_idx]
+                            / child.visits.max(1) as f64,
+                    }
+                }
+                None => ActionStats {
+                    action_idx: *action_idx,
+                    visits: 0,
+                    mean_reward: 0.0,
+                },
+            })
+            .collect();
+        stats.sort_by_key(|stat| stat.action_idx);
+
+        // Ties are broken by the mean reward, and then by the lower index
+        let best = stats
+            .iter()
+            .max_by(|a, b| {
+                a.visits
+                    .cmp(&b.visits)
+                    .then(a.mean_reward.total_cmp(&b.mean_reward))
+                    .then(b.action_idx.cmp(&a.action_idx))
+            })
+            .unwrap();
+
+        Ok(SearchResult {
+            action_idx: best.action_idx,
+            num_iterations,
+            stats,
+        })
+    }
+
+    fn iterate(&mut self, mut env: WingspanEnv) {
+        let mut path = vec![0];
+        let mut is_terminated = false;
+
+        // Selection and expansion
+        loop {
+            let valid_actions = env.valid_actions();
+            if valid_actions.is_empty() {
+                is_terminated = true;
+                break;
+            }
+            let node_idx = *path.last().unwrap();
+
+            let untried: Vec<_> = valid_actions
+                .iter()
+                .filter(|action_idx| !self.nodes[node_idx].children.contains_key(action_idx))
+                .copied()
+                .collect();
+            let (action_idx, is_new) = match untried.choose(&mut self.rng) {
+                Some(action_idx) => (*action_idx, true),
+                None => (self.select_child(&env, node_idx, &valid_actions), false),
+            };
+
+            match env.step(action_idx) {
+                Ok(result) => {
+                    let child_idx = match self.nodes[node_idx].children.get(&action_idx) {
+                        Some(child_idx) => *child_idx,
+                        None => {
+                            self.nodes.push(Node {
+                                total_rewards: vec![0.0; env.config().num_players],
+                                ..Default::default()
+                            });
+                            let child_idx = self.nodes.len() - 1;
+                            self.nodes[node_idx].children.insert(action_idx, child_idx);
+                            child_idx
+                        }
+                    };
+                    path.push(child_idx);
+                    is_terminated = result == StepResult::Terminated;
+                    if is_new || is_terminated {
+                        break;
+                    }
+                }
+                // Choice was valid, but can not be carried out. Treat it as a dead end of this iteration
+                Err(_) => break,
+            }
+        }
+
+        // Rollout
+        if !is_terminated {
+            let mut num_steps = 0;
+            while self
+                .config
+                .max_rollout_steps
+                .is_none_or(|max_steps| num_steps < max_steps)
+            {
+                let valid_actions = env.valid_actions();
+                if valid_actions.is_empty() {
+                    break;
+                }
+                let action_idx =
+                    self.rollout_policy
+                        .select_action(&mut env, &valid_actions, &mut self.rng);
+                match env.step(action_idx) {
+                    Ok(StepResult::Terminated) => break,
+                    Ok(_) => {}
+                    Err(_) => {
+                        // Fall back to any other choice that works
+                        let mut worked = false;
+                        for action_idx in valid_actions {
+                            if let Ok(result) = env.step(action_idx) {
+                                worked = result != StepResult::Terminated;
+                                break;
+                            }
+                        }
+                        if !worked {
+                            break;
+                        }
+                    }
+                }
+                num_steps += 1;
+            }
+        }
+
+        // Backpropagation
+        let rewards = env.standings().win_shares();
+        for node_idx in path {
+            let node = &mut self.nodes[node_idx];
+            node.visits += 1;
+            for (total, reward) in node.total_rewards.iter_mut().zip(rewards.iter()) {
+                *total += reward;
+            }
+        }
+    }
+
+    fn select_child(&self, env: &WingspanEnv, node_idx: usize, valid_actions: &[u8]) -> u8 {
+        let node = &self.nodes[node_idx];
+        let player_idx = env.current_player_idx();
+        let log_visits = (node.visits.max(1) as f64).ln();
+
+        let ucb = |action_idx: &u8| {
+            let child = &self.nodes[node.children[action_idx]];
+            let visits = child.visits.max(1) as f64;
+            child.total_rewards[player_idx] / visits
+                + self.config.exploration * (log_visits / visits).sqrt()
+        };
+
+        *valid_actions
+            .iter()
+            .max_by(|a, b| ucb(a).total_cmp(&ucb(b)))
+            .unwrap()
+    }
+}
+
+/// Rollout policy implemented by a Python callable `policy(env, valid_actions) -> action_idx`.
+///
+/// Callable gets a copy of the environment, so it can not change the rollout.
+/// First error (an exception or an invalid choice) is kept, and returned after the search.
+struct PyRolloutPolicy {
+    policy: Py<PyAny>,
+    error: Option<PyErr>,
+}
+
+impl PyRolloutPolicy {
+    fn call(&self, env: &WingspanEnv, valid_actions: &[u8]) -> PyResult<u8> {
+        Python::with_gil(|py| {
+            let py_env = Py::new(
+                py,
+                PyWingspanEnv {
+                    inner: env.detached_clone(),
+                },
+            )?;
+            let action_idx: u8 = self
+                .policy
+                .call1(py, (py_env, valid_actions.to_vec()))?
+                .extract(py)?;
+
+            if valid_actions.contains(&action_idx) {
+                Ok(action_idx)
+            } else {
+                Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Rollout policy chose action {action_idx}, which is not one of {valid_actions:?}"
+                )))
+            }
+        })
+    }
+}
+
+impl RolloutPolicy for PyRolloutPolicy {
+    fn select_action(
+        &mut self,
+        env: &mut WingspanEnv,
+        valid_actions: &[u8],
+        rng: &mut ChaCha12Rng,
+    ) -> u8 {
+        if self.error.is_none() {
+            match self.call(env, valid_actions) {
+                Ok(action_idx) => return action_idx,
+                Err(err) => self.error = Some(err),
+            }
+        }
+
+        // Search fails after an error anyway, so the rest of it is not worth calling into Python
+        *valid_actions.choose(rng).unwrap()
+    }
+}
+
+// Number of visits and mean reward for each of the valid choices
+type VisitStats = HashMap<u8, (u32, f64)>;
+
+/// Runs MCTS from the current state of the environment, and returns the best choice for the next action,
+/// together with statistics of every valid choice.
+#[pyfunction]
+#[pyo3(signature = (env, budget, exploration=None, determinize=None, max_rollout_steps=None, seed=None, rollout_policy=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn search(
+    py: Python<'_>,
+    env: &Bound<'_, PyWingspanEnv>,
+    budget: &Bound<'_, PyAny>,
+    exploration: Option<f64>,
+    determinize: Option<bool>,
+    max_rollout_steps: Option<usize>,
+    seed: Option<u64>,
+    rollout_policy: Option<Py<PyAny>>,
+) -> PyResult<(u8, VisitStats)> {
+    // Integers are number of iterations, floats are seconds
+    let budget = match budget.extract::<usize>() {
+        Ok(iterations) => SearchBudget::Iterations(iterations),
+        Err(_) => SearchBudget::Time(
+            Duration::try_from_secs_f64(budget.extract::<f64>()?)
+                .map_err(|err| pyo3::exceptions::PyValueError::new_err(err.to_string()))?,
+        ),
+    };
+
+    let mut builder = MctsConfigBuilder::default();
+    builder.budget(budget);
+    if let Some(exploration) = exploration {
+        builder.exploration(exploration);
+    }
+    if let Some(determinize) = determinize {
+        builder.determinize(determinize);
+    }
+    if let Some(max_rollout_steps) = max_rollout_steps {
+        builder.max_rollout_steps(max_rollout_steps);
+    }
+    if let Some(seed) = seed {
+        builder.seed(seed);
+    }
+    let config = builder.build().map_err(|err| {
+        pyo3::exceptions::PyValueError::new_err(format!("Error building config: {err}"))
+    })?;
+
+    let root = env.borrow().inner.detached_clone();
+    let result = match rollout_policy {
+        None => py.allow_threads(|| Mcts::new(config).search(&root))?,
+        Some(policy) => {
+            let mut mcts = Mcts::with_rollout_policy(
+                config,
+                PyRolloutPolicy {
+                    policy,
+                    error: None,
+                },
+            );
+            // Policy takes the GIL back for each of its calls
+            let result = py.allow_threads(|| mcts.search(&root));
+            if let Some(err) = mcts.rollout_policy.error.take() {
+                return Err(err);
+            }
+            result?
+        }
+    };
+
+    Ok((
+        result.action_idx,
+        result
+            .stats
+            .iter()
+            .map(|stat| (stat.action_idx, (stat.visits, stat.mean_reward)))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wingspan_env::WingspanEnvConfigBuilder;
+
+    fn new_env(seed: u64) -> WingspanEnv {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(seed));
+        env
+    }
+
+    #[test]
+    fn search_returns_most_visited_valid_action() {
+        let mut env = new_env(0);
+        let config = MctsConfigBuilder::default()
+            .budget(SearchBudget::Iterations(50))
+            .seed(0)
+            .build()
+            .unwrap();
+        let result = Mcts::new(config.clone()).search(&env).unwrap();
+
+        assert_eq!(result.num_iterations, 50);
+        let valid_actions = env.valid_actions();
+        assert_eq!(
+            result
+                .stats
+                .iter()
+                .map(|stat| stat.action_idx)
+                .collect::<Vec<_>>(),
+            valid_actions
+        );
+        assert_eq!(result.stats.iter().map(|stat| stat.visits).sum::<u32>(), 50);
+        let max_visits = result.stats.iter().map(|stat| stat.visits).max().unwrap();
+        assert!(result
+            .stats
+            .iter()
+            .any(|stat| stat.action_idx == result.action_idx && stat.visits == max_visits));
+
+        // Same seed results in the same search, and the environment itself is not modified
+        let checksum = env.state_checksum().unwrap();
+        assert_eq!(Mcts::new(config).search(&env).unwrap(), result);
+        assert_eq!(env.state_checksum().unwrap(), checksum);
+    }
+
+    #[test]
+    fn search_respects_time_budget() {
+        let env = new_env(1);
+        let config = MctsConfigBuilder::default()
+            .budget(SearchBudget::Time(Duration::from_millis(50)))
+            .build()
+            .unwrap();
+        let start = Instant::now();
+        let result = Mcts::new(config).search(&env).unwrap();
+        assert!(result.num_iterations > 0);
+        // A single iteration (full game) takes way under a second
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    // Always picks the first choice
+    struct FirstChoice;
+
+    impl RolloutPolicy for FirstChoice {
+        fn select_action(
+            &mut self,
+            _: &mut WingspanEnv,
+            valid_actions: &[u8],
+            _: &mut ChaCha12Rng,
+        ) -> u8 {
+            valid_actions[0]
+        }
+    }
+
+    #[test]
+    fn search_plays_full_game_with_custom_policy() {
+        let mut env = new_env(2);
+        let config = MctsConfigBuilder::default()
+            .budget(SearchBudget::Iterations(5))
+            .max_rollout_steps(20usize)
+            .seed(2)
+            .build()
+            .unwrap();
+        let mut mcts = Mcts::with_rollout_policy(config, FirstChoice);
+
+        loop {
+            let action_idx = mcts.search(&env).unwrap().action_idx;
+            if env.step(action_idx).unwrap() == StepResult::Terminated {
+                break;
+            }
+        }
+        assert!(mcts.search(&env).is_err());
+    }
+
+    #[test]
+    fn search_calls_python_rollout_policy() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let env = Bound::new(py, PyWingspanEnv { inner: new_env(3) }).unwrap();
+            let valid_actions = env.borrow_mut().inner.valid_actions();
+            let eval = |code: &str| {
+                py.eval(&std::ffi::CString::new(code).unwrap(), None, None)
+                    .unwrap()
+                    .unbind()
+            };
+
+            let budget = 5usize.into_pyobject(py).unwrap().into_any();
+
+            let first_choice = eval("lambda env, valid_actions: valid_actions[0]");
+            let (action_idx, _) = search(
+                py,
+                &env,
+                &budget,
+                None,
+                None,
+                Some(20),
+                Some(0),
+                Some(first_choice),
+            )
+            .unwrap();
+            assert!(valid_actions.contains(&action_idx));
+
+            // Invalid choices and exceptions of the policy fail the search
+            for policy in [
+                "lambda env, valid_actions: 255",
+                "lambda env, valid_actions: 1 / 0",
+            ] {
+                let result = search(
+                    py,
+                    &env,
+                    &budget,
+                    None,
+                    None,
+                    None,
+                    Some(0),
+                    Some(eval(policy)),
+                );
+                assert!(result.is_err(), "{policy}");
+            }
+        });
+    }
+}
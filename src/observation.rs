@@ -0,0 +1,232 @@
+//! Fixed-size numeric encoding of the game state, as seen by a single player.
+//!
+//! Observation is a flat `f32` vector. Its size depends only on the config (number of players and rounds).
+//! Categorical values (birds, goals, action types) are encoded as `id + 1`, with `0` meaning an empty slot.
+//!
+//! Sections, in order:
+//!
+//! | Section         | Size                  | Contents                                                                       |
+//! |-----------------|-----------------------|--------------------------------------------------------------------------------|
+//! | `game`          | 3                     | round idx, seats from observer to player whose turn it is, cards left in deck  |
+//! | `player`        | 8 + birds + bonus + mat | foods (6), turns left, round goal points, hand (count per bird card index), bonus cards (count per bonus card index), mat |
+//! | `opponent_{i}`  | 10 + mat              | foods (6), turns left, round goal points, hand size, bonus cards count, mat    |
+//! | `bird_feeder`   | 9                     | number of dice showing each face (8), number of dice outside of the feeder     |
+//! | `display`       | 3                     | face-up bird cards                                                             |
+//! | `round_goals`   | num_rounds            | end of round goal for each round                                               |
+//! | `pending_action`| 2                     | type of the next action, its action space size                                 |
+//!
+//! Opponents are ordered by seat, starting with the player to the left of the observer.
+//!
+//! Mat is encoded as 3 habitats (Forest, Grassland, Wetland), each with 5 slots x 10 values:
+//! bird, eggs, eggs capacity, cached food (6, per FoodIndex), tucked cards;
+//! followed by nectar spent in the habitat.
+//!
+//! Dice faces 0-4 follow FoodIndex. Faces 5-7 are Invertebrate/Seed, Nectar/Fruit and Nectar/Seed
+//! (last two only on Oceania dice).
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    action::ActionType,
+    bird_card::BirdCard,
+    bonus_card::BonusCard,
+    food::NUM_FOODS,
+    habitat::HABITATS,
+    player::Player,
+    wingspan_env::{WingspanEnv, WingspanEnvConfig},
+};
+
+const NUM_DICE_FACES: usize = 8;
+const MAT_SLOTS_PER_ROW: usize = 5;
+const MAT_SLOT_SIZE: usize = 3 + NUM_FOODS + 1;
+const MAT_ROW_SIZE: usize = MAT_SLOTS_PER_ROW * MAT_SLOT_SIZE + 1;
+const MAT_SIZE: usize = HABITATS.len() * MAT_ROW_SIZE;
+
+const GAME_SIZE: usize = 3;
+const OPPONENT_SIZE: usize = NUM_FOODS + 4 + MAT_SIZE;
+const BIRD_FEEDER_SIZE: usize = NUM_DICE_FACES + 1;
+const DISPLAY_SIZE: usize = 3;
+const PENDING_ACTION_SIZE: usize = 2;
+
+fn player_size() -> usize {
+    NUM_FOODS + 2 + BirdCard::iter().count() + BonusCard::iter().count() + MAT_SIZE
+}
+
+/// Names and sizes of the consecutive sections of the observation vector.
+pub fn observation_layout(config: &WingspanEnvConfig) -> Vec<(String, usize)> {
+    let mut layout = vec![
+        ("game".to_string(), GAME_SIZE),
+        ("player".to_string(), player_size()),
+    ];
+    layout.extend(
+        (1..config.num_players)
+            .map(|opponent_idx| (format!("opponent_{opponent_idx}"), OPPONENT_SIZE)),
+    );
+    layout.extend([
+        ("bird_feeder".to_string(), BIRD_FEEDER_SIZE),
+        ("display".to_string(), DISPLAY_SIZE),
+        ("round_goals".to_string(), config.num_rounds),
+        ("pending_action".to_string(), PENDING_ACTION_SIZE),
+    ]);
+
+    layout
+}
+
+pub fn observation_size(config: &WingspanEnvConfig) -> usize {
+    observation_layout(config)
+        .iter()
+        .map(|(_, size)| size)
+        .sum()
+}
+
+fn push_mat(obs: &mut Vec<f32>, player: &Player) {
+    for row in player.get_mat().rows() {
+        for slot_idx in 0..MAT_SLOTS_PER_ROW {
+            match row.get_birds().get(slot_idx) {
+                Some(bird_card) => {
+                    obs.push((bird_card.index() + 1) as f32);
+                    obs.push(row.get_eggs()[slot_idx] as f32);
+                    obs.push(row.get_eggs_cap()[slot_idx] as f32);
+                    obs.extend(row.get_cached_food()[slot_idx].iter().map(|f| *f as f32));
+                    obs.push(row.get_tucked_cards()[slot_idx] as f32);
+                }
+                None => obs.extend([0.0; MAT_SLOT_SIZE]),
+            }
+        }
+        obs.push(row.get_spent_nectar() as f32);
+    }
+}
+
+fn push_player(obs: &mut Vec<f32>, player: &Player) {
+    obs.extend(player.get_foods().iter().map(|f| *f as f32));
+    obs.push(player.get_turns_left() as f32);
+    obs.push(player.get_end_of_round_points() as f32);
+
+    let mut hand = vec![0.0; BirdCard::iter().count()];
+    for bird_card in player.get_bird_cards() {
+        hand[bird_card.index() as usize] += 1.0;
+    }
+    obs.append(&mut hand);
+
+    let mut bonus_cards = vec![0.0; BonusCard::iter().count()];
+    for bonus_card in player.get_bonus_cards() {
+        bonus_cards[bonus_card.index() as usize] += 1.0;
+    }
+    obs.append(&mut bonus_cards);
+
+    push_mat(obs, player);
+}
+
+fn push_opponent(obs: &mut Vec<f32>, player: &Player) {
+    obs.extend(player.get_foods().iter().map(|f| *f as f32));
+    obs.push(player.get_turns_left() as f32);
+    obs.push(player.get_end_of_round_points() as f32);
+    obs.push(player.get_bird_cards().len() as f32);
+    obs.push(player.get_bonus_cards().len() as f32);
+
+    push_mat(obs, player);
+}
+
+impl WingspanEnv {
+    /// Observation of the player currently making a decision.
+    pub fn observation(&self) -> Vec<f32> {
+        self.observation_for_player(self.current_player_idx())
+    }
+
+    /// Observation of the game from perspective of a given player.
+    ///
+    /// It includes only information available to that player (i.e. hands of other players are hidden).
+    pub fn observation_for_player(&self, player_idx: usize) -> Vec<f32> {
+        let num_players = self.config().num_players;
+        let mut obs = Vec::with_capacity(observation_size(self.config()));
+
+        // Game
+        obs.push(self.round_idx() as f32);
+        obs.push(
+            ((self.current_turn_player_idx() + num_players - player_idx) % num_players) as f32,
+        );
+        obs.push(self._bird_deck.num_cards_in_deck() as f32);
+
+        // Players
+        push_player(&mut obs, self.get_player(player_idx));
+        for opponent_offset in 1..num_players {
+            push_opponent(&mut obs, self.get_player(player_idx + opponent_offset));
+        }
+
+        // Bird feeder
+        let mut dice = [0.0; NUM_DICE_FACES];
+        for dice_face in self._bird_feeder.dice_in_birdfeeder() {
+            dice[*dice_face as usize] += 1.0;
+        }
+        obs.extend(dice);
+        obs.push(self._bird_feeder.num_dice_out() as f32);
+
+        // Display
+        let display = self._bird_deck.get_display_cards();
+        obs.extend((0..DISPLAY_SIZE).map(|idx| {
+            display
+                .get(idx)
+                .map(|bc| (bc.index() + 1) as f32)
+                .unwrap_or_default()
+        }));
+
+        // Round goals
+        obs.extend(
+            self.end_of_round_goals()
+                .iter()
+                .map(|goal| (goal.unique_id() + 1) as f32),
+        );
+
+        // Pending action
+        match self.next_action() {
+            Some(action) => {
+                obs.push((ActionType::from(action) as usize + 1) as f32);
+                obs.push(action.action_space_size(self) as f32);
+            }
+            None => obs.extend([0.0; PENDING_ACTION_SIZE]),
+        }
+
+        obs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wingspan_env::WingspanEnvConfigBuilder;
+
+    #[test]
+    fn observation_matches_layout() {
+        for num_players in 1..=5 {
+            let config = WingspanEnvConfigBuilder::default()
+                .num_players(num_players)
+                .build()
+                .unwrap();
+            let mut env = WingspanEnv::try_new(config.clone());
+            env.reset(Some(42));
+
+            let expected_size = observation_size(&config);
+            for player_idx in 0..num_players {
+                assert_eq!(env.observation_for_player(player_idx).len(), expected_size);
+            }
+        }
+    }
+
+    #[test]
+    fn observation_hides_opponent_hands() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+
+        let obs = env.observation_for_player(0);
+        let hand_offset = GAME_SIZE + NUM_FOODS + 2;
+        let hand = &obs[hand_offset..hand_offset + BirdCard::iter().count()];
+        assert_eq!(hand.iter().sum::<f32>(), 5.0);
+        for bird_card in env.get_player(0).get_bird_cards() {
+            assert_eq!(hand[bird_card.index() as usize], 1.0);
+        }
+
+        // Opponent only exposes size of the hand
+        let opponent_offset = GAME_SIZE + player_size();
+        assert_eq!(obs[opponent_offset + NUM_FOODS + 2], 5.0);
+    }
+}
@@ -3,13 +3,24 @@ use crate::{
     bird_card::{is_enough_food_to_play_a_card, BirdCard},
     bonus_card::BonusCard,
     error::{WingError, WingResult},
-    food::{FoodIndex, Foods},
+    food::{BirdCardCost, CostAlternative, FoodIndex, Foods},
     habitat::{Habitat, HABITATS},
     player_mat::PlayerMat,
 };
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+/// How the player pays for a bird being played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BirdPayment {
+    /// Normal food and egg cost. Hawks of the European expansion may pay some of the [rodent] with cards from hand.
+    Food { num_cards_for_rodents: u8 },
+    /// Bird is played on top of the bird at the index, instead of paying any costs (European).
+    OnTopOf(usize),
+}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[pyclass]
 pub struct Player {
     #[pyo3(get)]
@@ -27,19 +38,31 @@ pub struct Player {
     #[pyo3(get)]
     end_of_round_points: u8,
 
+    // Action cubes placed during the current round, in order of `Action::ChooseAction` choices
+    // (i.e. play a bird, forest, grassland, wetland)
+    action_cubes: [u8; 4],
+
+    // Cards in hand which other players saw being taken (i.e. from the face-up display).
+    // Every other card in hand is hidden from them
+    known_bird_cards: Vec<BirdCard>,
+
     // Optimization that uses a fact, that before every bird play we check for resources etc.
+    // It is derived from the rest of the state, so it is not serialized
+    #[serde(skip)]
     _playable_card_hab_combos: Vec<(BirdCard, Habitat, usize)>,
 }
 
 impl Default for Player {
     fn default() -> Self {
         Self {
-            foods: [1, 1, 1, 1, 1],
+            foods: [1, 1, 1, 1, 1, 0],
             bird_cards: vec![],
             bonus_cards: vec![],
             turns_left: 8,
             mat: Default::default(),
             end_of_round_points: 0,
+            action_cubes: [0; 4],
+            known_bird_cards: vec![],
             _playable_card_hab_combos: vec![],
         }
     }
@@ -58,22 +81,28 @@ impl Player {
         self.turns_left = turns_left;
     }
 
-    pub fn discard_bird_card(&mut self, index: usize) -> WingResult<()> {
+    pub fn discard_bird_card(&mut self, index: usize) -> WingResult<BirdCard> {
         if index >= self.bird_cards.len() {
             return Err(WingError::InvalidAction);
         }
 
-        self.bird_cards.remove(index);
-        Ok(())
+        Ok(self.remove_bird_card(index))
+    }
+
+    fn remove_bird_card(&mut self, index: usize) -> BirdCard {
+        let bird_card = self.bird_cards.remove(index);
+        if let Some(known_idx) = self.known_bird_cards.iter().position(|x| *x == bird_card) {
+            self.known_bird_cards.remove(known_idx);
+        }
+        bird_card
     }
 
-    pub fn discard_bonus_card(&mut self, index: usize) -> WingResult<()> {
+    pub fn discard_bonus_card(&mut self, index: usize) -> WingResult<BonusCard> {
         if index >= self.bonus_cards.len() {
             return Err(WingError::InvalidAction);
         }
 
-        self.bonus_cards.remove(index);
-        Ok(())
+        Ok(self.bonus_cards.remove(index))
     }
 
     pub fn discard_food(&mut self, index: FoodIndex, num_food: u8) -> WingResult<()> {
@@ -81,7 +110,7 @@ impl Player {
         if index >= self.get_foods().len() {
             return Err(WingError::InvalidAction);
         }
-        if self.foods[index] == 0 {
+        if self.foods[index] < num_food {
             return Err(WingError::InvalidAction);
         }
 
@@ -89,33 +118,73 @@ impl Player {
         Ok(())
     }
 
-    pub fn discard_food_or_bird_card(&mut self, index: usize) -> WingResult<()> {
-        if index < 5 {
-            self.discard_food(index.into(), 1)
+    /// Indexes below `num_foods` are foods, and the rest are bird cards in hand.
+    pub fn discard_food_or_bird_card(
+        &mut self,
+        index: usize,
+        num_foods: usize,
+    ) -> WingResult<Option<BirdCard>> {
+        if index < num_foods {
+            self.discard_food(index.into(), 1)?;
+            Ok(None)
         } else {
-            self.discard_bird_card(index - 5)
+            self.discard_bird_card(index - num_foods).map(Some)
         }
     }
 
     pub fn can_play_a_bird_card(&mut self, habitats: Vec<Habitat>) -> bool {
         let mut playable_cards = vec![];
         for (idx, card) in self.bird_cards.iter().enumerate() {
-            if is_enough_food_to_play_a_card(card, &self.foods) {
-                let mut cur_card_habitat_combos: Vec<_> = self
-                    .mat
-                    .playable_habitats(card)
-                    .into_iter()
-                    .filter(|habitat| habitats.contains(habitat))
-                    .map(|habitat| (*card, habitat, idx))
-                    .collect();
-                playable_cards.append(&mut cur_card_habitat_combos)
-            }
+            let mut cur_card_habitat_combos: Vec<_> = card
+                .habitats()
+                .iter()
+                .filter(|habitat| habitats.contains(habitat))
+                .filter(|habitat| !self.payment_options(card, habitat).is_empty())
+                .map(|habitat| (*card, *habitat, idx))
+                .collect();
+            playable_cards.append(&mut cur_card_habitat_combos)
         }
         self._playable_card_hab_combos = playable_cards;
 
         !self._playable_card_hab_combos.is_empty()
     }
 
+    /// Ways in which the bird can be played in the habitat.
+    /// There is more than one of them only for some of the European birds.
+    pub fn payment_options(&self, bird_card: &BirdCard, habitat: &Habitat) -> Vec<BirdPayment> {
+        let mut result = vec![];
+
+        if self.mat.playable_habitats(bird_card).contains(habitat) {
+            // Hand includes the bird itself, so it cannot be used to pay for the [rodent]
+            let max_cards_for_rodents = if bird_card.can_pay_rodents_with_cards() {
+                let rodent_cost = bird_card.cost().0[FoodIndex::Rodent as usize];
+                (self.bird_cards.len() as u8)
+                    .saturating_sub(1)
+                    .min(rodent_cost.unwrap_or_default())
+            } else {
+                0
+            };
+
+            result.extend(
+                (0..=max_cards_for_rodents)
+                    .filter(|num_cards| {
+                        self.num_cached_food_to_spend(bird_card, *num_cards)
+                            .is_some()
+                    })
+                    .map(|num_cards_for_rodents| BirdPayment::Food {
+                        num_cards_for_rodents,
+                    }),
+            );
+        }
+
+        if bird_card.can_be_played_on_top() {
+            let num_birds = self.mat.get_row(habitat).get_birds().len();
+            result.extend((0..num_birds).map(BirdPayment::OnTopOf));
+        }
+
+        result
+    }
+
     pub fn play_a_bird_card(
         &mut self,
         bird_card_idx: u8,
@@ -125,74 +194,238 @@ impl Player {
             return Err(WingError::InvalidAction);
         }
 
-        let (bird_card, hab, orig_card_idx) = self._playable_card_hab_combos[bird_card_idx];
+        let (bird_card, hab, _) = self._playable_card_hab_combos[bird_card_idx];
+        let payment = *self
+            .payment_options(&bird_card, &hab)
+            .first()
+            .ok_or(WingError::InvalidAction)?;
+
+        self.play_bird_card_with_payment(bird_card, hab, payment)
+    }
+
+    /// Plays the bird from hand. Returns the bird, where it was played, and actions finishing the payment.
+    pub fn play_bird_card_with_payment(
+        &mut self,
+        bird_card: BirdCard,
+        hab: Habitat,
+        payment: BirdPayment,
+    ) -> WingResult<(BirdCard, Habitat, usize, Vec<Action>)> {
+        let orig_card_idx = self
+            .bird_cards
+            .iter()
+            .position(|card| *card == bird_card)
+            .ok_or(WingError::InvalidAction)?;
+
+        match payment {
+            BirdPayment::Food {
+                num_cards_for_rodents,
+            } => {
+                if !self.mat.playable_habitats(&bird_card).contains(&hab) {
+                    return Err(WingError::InvalidAction);
+                }
+                let num_cached_seed = self
+                    .num_cached_food_to_spend(&bird_card, num_cards_for_rodents)
+                    .ok_or(WingError::InvalidAction)?;
+
+                let mut discount = Foods::default();
+                discount[FoodIndex::Rodent as usize] = num_cards_for_rodents;
+                discount[FoodIndex::Seed as usize] = num_cached_seed;
+                self.spend_cached_seed(num_cached_seed)?;
+
+                let mut food_actions = self.pay_bird_cost(&bird_card, &hab, &discount)?;
+                let mut egg_actions = self.mat.put_bird_card(bird_card, &hab)?;
+                self.remove_bird_card(orig_card_idx);
+
+                let bird_idx = self.mat.get_row(&hab).get_birds().len() - 1;
+                food_actions.append(&mut egg_actions);
+                // Cards paid instead of the [rodent] are tucked behind the bird
+                food_actions.extend(
+                    (0..num_cards_for_rodents).map(|_| Action::TuckBirdCard(hab, bird_idx)),
+                );
+                Ok((bird_card, hab, bird_idx, food_actions))
+            }
+            BirdPayment::OnTopOf(bird_idx) => {
+                self.mat.get_row_mut(&hab).cover_bird(bird_idx, bird_card)?;
+                self.remove_bird_card(orig_card_idx);
+
+                // Covered bird becomes a tucked card
+                Ok((
+                    bird_card,
+                    hab,
+                    bird_idx,
+                    vec![Action::TuckBirdCardFromDeck(hab, bird_idx)],
+                ))
+            }
+        }
+    }
 
-        let mut food_actions = self.pay_bird_cost(&bird_card)?;
-        let mut egg_actions = self.mat.put_bird_card(bird_card, &hab)?;
-        self.bird_cards.remove(orig_card_idx);
+    // Number of [seed] cached on the birds with a spendable cache (European), which is needed to pay for the bird.
+    // None, if the bird cannot be paid for.
+    fn num_cached_food_to_spend(
+        &self,
+        bird_card: &BirdCard,
+        num_cards_for_rodents: u8,
+    ) -> Option<u8> {
+        let mut foods = self.foods;
+        foods[FoodIndex::Rodent as usize] += num_cards_for_rodents;
+
+        // Cached [seed] is only spent when needed, since it is worth points
+        let seed_idx = FoodIndex::Seed as usize;
+        (0..=self.num_spendable_cached_seed()).find(|num_seed| {
+            let mut foods = foods;
+            foods[seed_idx] += num_seed;
+            is_enough_food_to_play_a_card(bird_card, &foods)
+        })
+    }
 
-        food_actions.append(&mut egg_actions);
-        Ok((
-            bird_card,
-            hab,
-            self.mat.get_row(&hab).get_birds().len() - 1,
-            food_actions,
-        ))
+    fn num_spendable_cached_seed(&self) -> u8 {
+        self.mat
+            .rows()
+            .iter()
+            .flat_map(|row| row.get_birds().iter().zip(row.get_cached_food()))
+            .filter(|(bird_card, _)| bird_card.has_spendable_cache())
+            .map(|(_, cached_food)| cached_food[FoodIndex::Seed as usize])
+            .sum()
     }
 
-    fn pay_bird_cost(&mut self, bird_card: &BirdCard) -> WingResult<Vec<Action>> {
-        let (costs, total, is_alt) = bird_card.cost();
+    fn spend_cached_seed(&mut self, mut num_seed: u8) -> WingResult<()> {
+        for habitat in HABITATS {
+            let row = self.mat.get_row_mut(&habitat);
+            for bird_idx in 0..row.get_birds().len() {
+                while num_seed > 0
+                    && row.get_birds()[bird_idx].has_spendable_cache()
+                    && row.remove_cached_food(bird_idx, FoodIndex::Seed).is_ok()
+                {
+                    num_seed -= 1;
+                }
+            }
+        }
+
+        if num_seed > 0 {
+            return Err(WingError::InvalidAction);
+        }
+        Ok(())
+    }
 
-        if !is_enough_food_to_play_a_card(bird_card, &self.foods) {
+    // Pays the parts of the cost, which need no decision. Returns actions paying the rest of it.
+    //
+    // Nectar is wild, so it can be paid instead of any food. Spent nectar goes to the habitat the bird is played in.
+    // Discount are foods paid in other ways (i.e. cards or cached food in the European expansion).
+    fn pay_bird_cost(
+        &mut self,
+        bird_card: &BirdCard,
+        habitat: &Habitat,
+        discount: &Foods,
+    ) -> WingResult<Vec<Action>> {
+        let mut foods_with_discount = self.foods;
+        for (food, food_discount) in foods_with_discount.iter_mut().zip(discount) {
+            *food += food_discount;
+        }
+        if !is_enough_food_to_play_a_card(bird_card, &foods_with_discount) {
             return Err(WingError::InvalidAction);
         }
 
+        let (costs, total, is_alt) = apply_discount(bird_card.cost(), discount);
+        let (costs, total) = (&costs, &total);
+
         let result = match is_alt {
-            crate::food::CostAlternative::Yes => {
-                // Note: No need to keep track of total cost since it does not appear in "/" (or CostAlternative::Yes) cards
+            // Discount already paid one of the options
+            CostAlternative::Yes if *total == 0 => vec![],
+            CostAlternative::Yes => {
+                // Note: Every option of "/" (or CostAlternative::Yes) cards costs a single food
+                let mut options: Vec<_> = costs
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(food_idx, food_cost)| {
+                        food_cost.map(|_| FoodIndex::from(food_idx))
+                    })
+                    .collect();
+                if !options.contains(&FoodIndex::Nectar) {
+                    options.push(FoodIndex::Nectar);
+                }
+
+                vec![Action::PayFood(options.into_boxed_slice(), *habitat)]
+            }
+            CostAlternative::No => {
+                let nectar_idx = FoodIndex::Nectar as usize;
 
-                // First determine what are the discard options
-                let mut discard_options = vec![];
+                // Arbitrary costs are paid last, since actions are a LIFO queue
+                let total_defined_cost: u8 = costs.iter().flatten().sum();
+                let mut result: Vec<_> = (total_defined_cost..*total)
+                    .map(|_| Action::PayFood(FoodIndex::iter().collect(), *habitat))
+                    .collect();
+
+                // Nectar has to be paid for foods which player does not have
+                let mut nectar_to_pay = costs[nectar_idx].unwrap_or_default();
                 for (food_idx, food_cost) in costs.iter().enumerate() {
-                    if let Some(food_cost) = food_cost {
-                        if self.foods[food_idx] >= *food_cost {
-                            discard_options.push((food_idx.into(), *food_cost));
-                        }
+                    if food_idx != nectar_idx {
+                        nectar_to_pay += food_cost
+                            .unwrap_or_default()
+                            .saturating_sub(self.foods[food_idx]);
                     }
                 }
+                let mut spare_nectar = self.foods[nectar_idx] - nectar_to_pay;
 
-                // If there is only one option, just do it
-                if discard_options.len() == 1 {
-                    let (food_idx, food_cost) = discard_options.pop().unwrap();
-
-                    self.foods[food_idx as usize] -= food_cost;
-                    vec![]
-                } else {
-                    vec![Action::DiscardFoodChoice(
-                        discard_options.into_boxed_slice(),
-                    )]
-                }
-            }
-            crate::food::CostAlternative::No => {
-                // No Cost Alternative, so no choices needed
-                let mut total_defined_cost = 0;
                 for (food_idx, food_cost) in costs.iter().enumerate() {
-                    if let Some(food_cost) = food_cost {
-                        self.foods[food_idx] -= *food_cost;
-                        total_defined_cost += *food_cost;
+                    let Some(food_cost) = food_cost else {
+                        continue;
+                    };
+                    if food_idx == nectar_idx {
+                        continue;
                     }
+
+                    // While there is spare nectar, player decides whether to pay with it or the food itself
+                    let paid_with_food = (*food_cost).min(self.foods[food_idx]);
+                    let num_choices = paid_with_food.min(spare_nectar);
+                    spare_nectar -= num_choices;
+                    self.foods[food_idx] -= paid_with_food - num_choices;
+                    result.extend((0..num_choices).map(|_| {
+                        Action::PayFood(
+                            Box::new([FoodIndex::from(food_idx), FoodIndex::Nectar]),
+                            *habitat,
+                        )
+                    }));
                 }
 
-                // For all of the arbitrary costs, return actions needed
-                (0..total - total_defined_cost)
-                    .map(|_| Action::DiscardFood)
-                    .collect()
+                self.foods[nectar_idx] -= nectar_to_pay;
+                self.mat.get_row_mut(habitat).spend_nectar(nectar_to_pay);
+
+                result
             }
         };
 
         Ok(result)
     }
 
+    pub fn place_action_cube(&mut self, action_idx: u8) {
+        self.action_cubes[action_idx as usize] += 1;
+    }
+
+    pub fn clear_action_cubes(&mut self) {
+        self.action_cubes = [0; 4];
+    }
+
+    /// Action cubes placed this round, in order of `Action::ChooseAction` choices.
+    pub fn get_action_cubes(&self) -> &[u8; 4] {
+        &self.action_cubes
+    }
+
+    /// Action cubes placed this round on the row of the habitat (i.e. its habitat action was taken).
+    pub fn num_action_cubes_on(&self, habitat: &Habitat) -> u8 {
+        let habitat_idx = HABITATS.iter().position(|x| x == habitat).unwrap();
+        self.action_cubes[habitat_idx + 1]
+    }
+
+    /// Whether player took all 4 types of actions this round.
+    pub fn used_all_action_types(&self) -> bool {
+        self.action_cubes.iter().all(|num_cubes| *num_cubes > 0)
+    }
+
+    /// Unspent nectar is discarded at the end of each round. Returns the number of discarded nectar.
+    pub fn discard_unspent_nectar(&mut self) -> u8 {
+        std::mem::take(&mut self.foods[FoodIndex::Nectar as usize])
+    }
+
     pub fn can_discard_food(&self) -> bool {
         self.get_foods().iter().sum::<u8>() > 0
     }
@@ -201,71 +434,46 @@ impl Player {
         !self.bird_cards.is_empty()
     }
 
+    /// Final score of the player, as if the game ended right now (see `score_breakdown` for the details).
+    /// It excludes nectar majority, which depends on the other players (see `WingspanEnv::points`).
     pub fn calculate_points(&self) -> u8 {
-        // Get points from birds
-        let bird_points: u8 = self
-            .mat
-            .rows()
-            .iter()
-            .map(|mat_row| mat_row.get_birds().iter().map(|b| b.points()).sum::<u8>())
-            .sum();
+        self.score_breakdown().total
+    }
 
-        // Get points from eggs
-        let egg_points = self.mat.egg_count();
+    pub fn add_bird_card(&mut self, bird_card: BirdCard) {
+        self.bird_cards.push(bird_card);
+    }
 
-        // Points from tucked cards
-        let tucked_cards: u8 = self
-            .mat
-            .rows()
-            .iter()
-            .map(|mat_row| mat_row.get_tucked_cards().iter().sum::<u8>())
-            .sum();
+    pub fn append_bird_cards(&mut self, bird_card: &mut Vec<BirdCard>) {
+        self.bird_cards.append(bird_card);
+    }
 
-        // Points from cached cards
-        let cached_food: u8 = self
-            .mat
-            .rows()
-            .iter()
-            .map(|mat_row| mat_row.get_cached_food().iter().flatten().sum::<u8>())
-            .sum();
+    /// Marks card in hand as seen by other players.
+    pub fn reveal_bird_card(&mut self, bird_card: BirdCard) {
+        self.known_bird_cards.push(bird_card);
+    }
 
-        let bonus_points: u8 = self
-            .bonus_cards
-            .iter()
-            .map(|bc| {
-                let count = bc.get_count_of_matching(self) as u8;
-                match bc.scoring_rule() {
-                    crate::bonus_card::ScoringRule::Each(points_per_each) => {
-                        points_per_each * count
-                    }
-                    crate::bonus_card::ScoringRule::Ladder(steps) => {
-                        let mut points = 0;
-                        let mut idx = 0;
-                        while idx < steps.len() && count >= steps[idx].0 {
-                            points = steps[idx].1;
-                            idx += 1;
-                        }
-                        points
-                    }
+    /// Indexes of cards in hand that are hidden from other players.
+    pub fn hidden_bird_card_idxs(&self) -> Vec<usize> {
+        let mut known_bird_cards = self.known_bird_cards.clone();
+        let mut idxs = vec![];
+        for (idx, bird_card) in self.bird_cards.iter().enumerate() {
+            match known_bird_cards.iter().position(|x| x == bird_card) {
+                Some(known_idx) => {
+                    known_bird_cards.swap_remove(known_idx);
                 }
-            })
-            .sum();
-
-        self.end_of_round_points
-            + bird_points
-            + bonus_points
-            + egg_points
-            + tucked_cards
-            + cached_food
-            + (self.foods.iter().sum::<u8>() / 4)
+                None => idxs.push(idx),
+            }
+        }
+        idxs
     }
 
-    pub fn add_bird_card(&mut self, bird_card: BirdCard) {
-        self.bird_cards.push(bird_card);
+    pub fn replace_bird_card(&mut self, idx: usize, bird_card: BirdCard) -> BirdCard {
+        std::mem::replace(&mut self.bird_cards[idx], bird_card)
     }
 
-    pub fn append_bird_cards(&mut self, bird_card: &mut Vec<BirdCard>) {
-        self.bird_cards.append(bird_card);
+    pub fn replace_bonus_card(&mut self, idx: usize, bonus_card: BonusCard) -> BonusCard {
+        std::mem::replace(&mut self.bonus_cards[idx], bonus_card)
     }
 
     pub fn add_bonus_cards(&mut self, bonus_cards: &mut Vec<BonusCard>) {
@@ -292,6 +500,14 @@ impl Player {
         &self.foods
     }
 
+    pub fn get_turns_left(&self) -> u8 {
+        self.turns_left
+    }
+
+    pub fn get_end_of_round_points(&self) -> u8 {
+        self.end_of_round_points
+    }
+
     pub fn add_end_of_round_points(&mut self, pts: u8) {
         self.end_of_round_points += pts;
     }
@@ -309,6 +525,43 @@ impl Player {
     }
 }
 
+// Removes foods paid in other ways from the cost. Discount of a food left after its own cost goes to the arbitrary ([wild]) part of it.
+fn apply_discount(cost: &BirdCardCost, discount: &Foods) -> BirdCardCost {
+    let (mut costs, mut total, is_alt) = *cost;
+
+    match is_alt {
+        CostAlternative::Yes => {
+            // Every option costs a single food, so a discount of one of them pays for the bird
+            if costs
+                .iter()
+                .zip(discount)
+                .any(|(food_cost, food_discount)| {
+                    food_cost.is_some_and(|food_cost| food_cost <= *food_discount)
+                })
+            {
+                total = 0;
+            }
+        }
+        CostAlternative::No => {
+            let mut arbitrary_cost = total - costs.iter().flatten().sum::<u8>();
+            for (food_cost, food_discount) in costs.iter_mut().zip(discount) {
+                let mut food_discount = *food_discount;
+                if let Some(food_cost) = food_cost {
+                    let paid = food_discount.min(*food_cost);
+                    *food_cost -= paid;
+                    food_discount -= paid;
+                    total -= paid;
+                }
+                let paid = food_discount.min(arbitrary_cost);
+                arbitrary_cost -= paid;
+                total -= paid;
+            }
+        }
+    }
+
+    (costs, total, is_alt)
+}
+
 #[pymethods]
 impl Player {
     pub fn birds_on_mat(&self) -> [Vec<BirdCard>; 3] {
@@ -336,6 +589,8 @@ impl Player {
             turns_left,
             mat,
             end_of_round_points,
+            action_cubes: [0; 4],
+            known_bird_cards: vec![],
             _playable_card_hab_combos,
         }
     }
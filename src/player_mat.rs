@@ -2,15 +2,18 @@ use crate::{
     action::Action,
     bird_card::{BirdCard, BirdCardColor},
     error::{WingError, WingResult},
-    food::FoodIndex,
+    food::{FoodIndex, Foods},
     habitat::{Habitat, HABITATS},
+    mat_layout::MatLayout,
     nest::NestType,
     wingspan_env::WingspanEnv,
 };
+use serde::{Deserialize, Serialize};
 
-type BirdResourceRow = [u8; 5];
+// Cached food of a single bird, per FoodIndex
+type BirdResourceRow = Foods;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatRow {
     // Mapping from column idx -> index in birds. This is because some birds can cover multiple places
     habitat: Habitat,
@@ -21,6 +24,8 @@ pub struct MatRow {
     cached_food: Vec<BirdResourceRow>,
     eggs: Vec<u8>,
     eggs_cap: Vec<u8>,
+    // Nectar spent on this habitat (Oceania). Majority of it is worth points at the end of the game
+    spent_nectar: u8,
 }
 
 impl MatRow {
@@ -34,6 +39,7 @@ impl MatRow {
             cached_food: Vec::with_capacity(5),
             eggs: Vec::with_capacity(5),
             eggs_cap: Vec::with_capacity(5),
+            spent_nectar: 0,
         }
     }
 
@@ -49,28 +55,32 @@ impl MatRow {
         Some(*self.birds.get(*self.bird_col_idxs.get(idx)?)?)
     }
 
-    pub fn get_bird_actions(&self, env: &mut WingspanEnv) -> (Vec<Action>, Vec<Action>) {
+    /// Activates birds of the given color from right to left.
+    ///
+    /// Returns immediate and end of turn actions, ready to be put onto the queue.
+    pub fn get_bird_actions(
+        &self,
+        env: &mut WingspanEnv,
+        color: BirdCardColor,
+    ) -> (Vec<Action>, Vec<Action>) {
         let mut actions = vec![];
         let mut end_of_turn_actions = vec![];
 
         // Iterate through birds from right to left
         for (bird_idx, bird) in self.birds.iter().enumerate().rev() {
-            if bird.color() != &BirdCardColor::Brown {
+            if bird.color() != &color {
                 continue;
             }
 
             if let Ok(mut action_res) = bird.activate(env, &self.habitat, bird_idx) {
-                actions.append(&mut action_res.immediate_actions);
+                // Actions are a LIFO queue, so actions of birds activated later go underneath.
+                // Actions of a single bird are already in the queue order.
+                actions.splice(0..0, action_res.immediate_actions);
                 end_of_turn_actions.append(&mut action_res.end_of_turn_actions);
-
-                if action_res.was_successful && bird.is_predator() {
-                    env.predator_succeeded();
-                }
             }
         }
 
-        // Actions are pushed onto back of the queue, so reverse to match order of actions
-        (actions.into_iter().rev().collect(), end_of_turn_actions)
+        (actions, end_of_turn_actions)
     }
 
     pub fn num_spots_to_place_eggs(&self) -> usize {
@@ -95,7 +105,7 @@ impl MatRow {
         bird_idx < self.eggs.len() && self.eggs[bird_idx] > 0
     }
 
-    pub fn place_egg(&mut self, idx: usize) -> Result<(), usize> {
+    pub fn place_egg(&mut self, idx: usize) -> Result<usize, usize> {
         let mut count = 0;
 
         for (bird_idx, (egg, cap)) in self.eggs.iter().zip(self.eggs_cap.iter()).enumerate() {
@@ -104,7 +114,7 @@ impl MatRow {
                 if count == idx {
                     // This is the requested spot
                     self.eggs[bird_idx] += 1;
-                    return Ok(());
+                    return Ok(bird_idx);
                 } else {
                     // Not yet the requested spot
                     count += 1;
@@ -115,13 +125,21 @@ impl MatRow {
         // Requested spot not found, so return number of valid spots in this row
         Err(count)
     }
-    pub fn place_egg_at_exact_column(&mut self, col_idx: usize) -> WingResult<()> {
-        let bird_idx = match self.bird_col_idxs.get(col_idx) {
-            Some(bird_idx) => *bird_idx,
-            None => return Err(WingError::InvalidAction),
-        };
+    pub fn bird_idx_at_column(&self, col_idx: usize) -> Option<usize> {
+        self.bird_col_idxs.get(col_idx).copied()
+    }
 
-        self.place_egg_at_exact_bird_idx(bird_idx, 0)
+    /// Columns covered by the bird. Sideways birds (European) cover 2 of them.
+    pub fn columns_of_bird(&self, bird_idx: usize) -> Vec<usize> {
+        self.bird_col_idxs
+            .iter()
+            .enumerate()
+            .filter_map(|(col_idx, col_bird_idx)| (*col_bird_idx == bird_idx).then_some(col_idx))
+            .collect()
+    }
+
+    pub fn num_empty_columns(&self) -> usize {
+        5 - self.next_col_to_play
     }
 
     pub fn place_egg_at_exact_bird_idx(
@@ -137,7 +155,7 @@ impl MatRow {
         }
     }
 
-    pub fn discard_egg(&mut self, idx: usize) -> Result<(), usize> {
+    pub fn discard_egg(&mut self, idx: usize) -> Result<usize, usize> {
         let mut count = 0;
 
         for (col_idx, egg) in self.eggs.iter().enumerate() {
@@ -147,7 +165,7 @@ impl MatRow {
                 if count == idx {
                     // This is the requested spot
                     self.eggs[col_idx] -= 1;
-                    return Ok(());
+                    return Ok(col_idx);
                 } else {
                     // Not yet the requested spot
                     count += 1;
@@ -188,6 +206,14 @@ impl MatRow {
         &self.tucked_cards
     }
 
+    pub fn get_spent_nectar(&self) -> u8 {
+        self.spent_nectar
+    }
+
+    pub fn spend_nectar(&mut self, num_nectar: u8) {
+        self.spent_nectar += num_nectar;
+    }
+
     pub fn play_a_bird(&mut self, bird_card: BirdCard) -> WingResult<()> {
         // Get indexes to insert at
         let birds_idx = self.birds.len();
@@ -203,15 +229,15 @@ impl MatRow {
         self.next_col_to_play += 1;
 
         match bird_card {
+            // They are played side-ways. Unless it is the last column
             BirdCard::CommonBlackbird
             | BirdCard::EuropeanRoller
             | BirdCard::GreyHeron
-            | BirdCard::LongTailedTit => {
-                // They are played side-ways. Unless it is the last column
-                if self.bird_col_idxs.len() < 5 {
-                    self.bird_col_idxs.push(birds_idx);
-                    self.next_col_to_play += 1;
-                }
+            | BirdCard::LongTailedTit
+                if self.bird_col_idxs.len() < 5 =>
+            {
+                self.bird_col_idxs.push(birds_idx);
+                self.next_col_to_play += 1;
             }
             _ => {}
         }
@@ -223,6 +249,16 @@ impl MatRow {
         self.cached_food[bird_idx][food_idx as usize] += 1;
     }
 
+    pub fn remove_cached_food(&mut self, bird_idx: usize, food_idx: FoodIndex) -> WingResult<()> {
+        let cached_food = &mut self.cached_food[bird_idx][food_idx as usize];
+        if *cached_food == 0 {
+            return Err(WingError::InvalidAction);
+        }
+
+        *cached_food -= 1;
+        Ok(())
+    }
+
     pub fn tuck_card(&mut self, bird_idx: usize) {
         self.tucked_cards[bird_idx] += 1;
     }
@@ -244,6 +280,21 @@ impl MatRow {
         Ok(())
     }
 
+    /// Plays the bird on top of the bird at bird_idx (European). Eggs and food of the covered bird are discarded.
+    ///
+    /// Returns the covered bird. Caller is responsible for tucking it.
+    pub fn cover_bird(&mut self, bird_idx: usize, bird_card: BirdCard) -> WingResult<BirdCard> {
+        if bird_idx >= self.birds.len() {
+            return Err(WingError::InvalidAction);
+        }
+
+        // New bird takes over the column(s) and the tucked cards of the covered one
+        self.cached_food[bird_idx] = Default::default();
+        self.eggs[bird_idx] = 0;
+        self.eggs_cap[bird_idx] = bird_card.egg_capacity();
+        Ok(std::mem::replace(&mut self.birds[bird_idx], bird_card))
+    }
+
     pub fn remove_bird(&mut self, bird_idx: usize) -> (BirdCard, u8, BirdResourceRow, u8, u8) {
         let result = (
             self.birds.remove(bird_idx),
@@ -252,7 +303,16 @@ impl MatRow {
             self.eggs.remove(bird_idx),
             self.eggs_cap.remove(bird_idx),
         );
-        self.next_col_to_play -= 1;
+
+        // Free column(s) of the bird, and shift indexes of the birds after it
+        self.bird_col_idxs
+            .retain(|col_bird_idx| *col_bird_idx != bird_idx);
+        for col_bird_idx in self.bird_col_idxs.iter_mut() {
+            if *col_bird_idx > bird_idx {
+                *col_bird_idx -= 1;
+            }
+        }
+        self.next_col_to_play = self.bird_col_idxs.len();
         result
     }
 }
@@ -279,11 +339,12 @@ impl MatRow {
             cached_food,
             eggs,
             eggs_cap,
+            spent_nectar: 0,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerMat {
     forest: MatRow,
     grassland: MatRow,
@@ -317,22 +378,43 @@ impl PlayerMat {
         }
     }
 
+    /// Birds in each of the columns. Sideways birds (European) are in both of the columns they cover.
     pub fn get_columns(&self) -> Vec<[Option<&BirdCard>; 3]> {
-        let bird_cards = self.rows().map(|mt| mt.get_birds());
+        let rows = self.rows();
 
-        let num_columns = bird_cards.iter().map(|row| row.len()).max().unwrap();
+        let num_columns = rows
+            .iter()
+            .map(|row| row.bird_col_idxs.len())
+            .max()
+            .unwrap();
 
         (0..num_columns)
             .map(|col_idx| {
-                [
-                    bird_cards[0].get(col_idx),
-                    bird_cards[1].get(col_idx),
-                    bird_cards[2].get(col_idx),
-                ]
+                rows.map(|row| {
+                    row.bird_idx_at_column(col_idx)
+                        .map(|bird_idx| &row.birds[bird_idx])
+                })
             })
             .collect()
     }
 
+    /// Birds in the same column(s) as the given bird, incl. the bird itself.
+    pub fn birds_in_columns_of(&self, habitat: &Habitat, bird_idx: usize) -> Vec<(Habitat, usize)> {
+        let columns = self.get_row(habitat).columns_of_bird(bird_idx);
+
+        let mut result = vec![];
+        for row in self.rows() {
+            for col_idx in columns.iter() {
+                if let Some(col_bird_idx) = row.bird_idx_at_column(*col_idx) {
+                    if !result.contains(&(row.habitat, col_bird_idx)) {
+                        result.push((row.habitat, col_bird_idx));
+                    }
+                }
+            }
+        }
+        result
+    }
+
     pub fn playable_habitats(&self, card: &BirdCard) -> Vec<Habitat> {
         card.habitats()
             .iter()
@@ -360,20 +442,16 @@ impl PlayerMat {
             .collect()
     }
 
-    pub fn get_actions_from_habitat_action(&self, habitat: &Habitat) -> Vec<Action> {
-        let hab_action = habitat.action();
-        let hab_row = self.get_row(habitat);
+    pub fn get_actions_from_habitat_action(
+        &self,
+        layout: &MatLayout,
+        habitat: &Habitat,
+    ) -> Vec<Action> {
+        // Reward is in the leftmost exposed column, so sideways birds (European) count twice
+        let num_covered_columns = 5 - self.get_row(habitat).num_empty_columns();
 
         let mut result = vec![Action::BirdActionFromHabitat(*habitat)];
-
-        let num_actions =
-            if habitat == &Habitat::Grassland { 2 } else { 1 } + hab_row.get_birds().len() / 2;
-
-        result.extend((0..num_actions).map(|_| hab_action.clone()));
-
-        if hab_row.get_birds().len() % 2 == 1 {
-            result.push(habitat.optional_action())
-        }
+        result.extend(layout.column_actions(habitat, num_covered_columns));
 
         result
     }
@@ -414,7 +492,7 @@ impl PlayerMat {
         Ok(())
     }
 
-    fn find_bird(&self, bird_card: &BirdCard) -> Option<(Habitat, usize)> {
+    pub fn find_bird(&self, bird_card: &BirdCard) -> Option<(Habitat, usize)> {
         for row in self.rows() {
             if let Some(bird_idx) = row.birds.iter().position(|bc| bc == bird_card) {
                 return Some((row.habitat, bird_idx));
@@ -423,12 +501,13 @@ impl PlayerMat {
         None
     }
 
-    pub fn place_egg(&mut self, idx: u8) -> WingResult<()> {
+    /// Places an egg on idx-th bird that has space for it. Returns location of that bird.
+    pub fn place_egg(&mut self, idx: u8) -> WingResult<(Habitat, usize)> {
         let idx = idx as usize;
         let mut cur_action_count = 0;
         for hab_row in [&mut self.forest, &mut self.grassland, &mut self.wetland] {
             match hab_row.place_egg(idx - cur_action_count) {
-                Ok(()) => return Ok(()),
+                Ok(bird_idx) => return Ok((hab_row.habitat, bird_idx)),
                 Err(num_actions_in_row) => {
                     cur_action_count += num_actions_in_row;
                 }
@@ -439,12 +518,13 @@ impl PlayerMat {
         Err(WingError::InvalidAction)
     }
 
-    pub fn discard_egg(&mut self, idx: u8) -> WingResult<()> {
+    /// Discards an egg from idx-th bird that has any eggs. Returns location of that bird.
+    pub fn discard_egg(&mut self, idx: u8) -> WingResult<(Habitat, usize)> {
         let idx = idx as usize;
         let mut cur_action_count = 0;
         for hab_row in [&mut self.forest, &mut self.grassland, &mut self.wetland] {
             match hab_row.discard_egg(idx - cur_action_count) {
-                Ok(()) => return Ok(()),
+                Ok(bird_idx) => return Ok((hab_row.habitat, bird_idx)),
                 Err(num_actions_in_row) => {
                     cur_action_count += num_actions_in_row;
                 }
@@ -483,11 +563,11 @@ impl PlayerMat {
         habitat: &Habitat,
     ) -> WingResult<Vec<Action>> {
         let row = self.get_row_mut(habitat);
-        if row.get_birds().len() >= 5 {
+        let Some(col_to_play) = row.col_to_play() else {
             return Err(WingError::InvalidAction);
-        }
+        };
 
-        let egg_cost = row.col_to_play().unwrap().div_ceil(2);
+        let egg_cost = col_to_play.div_ceil(2);
 
         row.play_a_bird(bird_card)?;
 
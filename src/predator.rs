@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bird_card::BirdCard, event::GameEvent, food::FoodIndex, habitat::Habitat, trigger::Trigger,
+    wingspan_env::WingspanEnv,
+};
+
+/// Outcome of a single use of a [predator] power.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hunt {
+    pub player_idx: usize,
+    pub bird_card: BirdCard,
+    pub habitat: Habitat,
+    pub bird_idx: usize,
+    pub succeeded: bool,
+}
+
+/// Records outcome of a hunt. Successful hunts are announced to other players (i.e. Black Vulture).
+pub(crate) fn record_hunt(env: &mut WingspanEnv, hunt: Hunt) {
+    if hunt.succeeded {
+        env.push_event(GameEvent::PredatorSucceeded {
+            player_idx: hunt.player_idx,
+            bird_card: hunt.bird_card,
+            habitat: hunt.habitat,
+            bird_idx: hunt.bird_idx,
+        });
+        env.raise_trigger(Trigger::PredatorSucceeded {
+            player_idx: hunt.player_idx,
+        });
+    }
+    env.push_hunt(hunt);
+}
+
+fn record_hunt_of_current_player(
+    env: &mut WingspanEnv,
+    habitat: &Habitat,
+    bird_idx: usize,
+    succeeded: bool,
+) {
+    let bird_card = env.current_player().get_mat().get_row(habitat).get_birds()[bird_idx];
+
+    record_hunt(
+        env,
+        Hunt {
+            player_idx: env.current_player_idx(),
+            bird_card,
+            habitat: *habitat,
+            bird_idx,
+            succeeded,
+        },
+    );
+}
+
+/// "look at a [card] from the deck. if <condition>, tuck it behind this bird. if not, discard it."
+///
+/// Returns whether the hunt succeeded. Hunt with an empty deck fails.
+pub(crate) fn hunt_with_card(
+    env: &mut WingspanEnv,
+    habitat: &Habitat,
+    bird_idx: usize,
+    condition: impl FnOnce(&BirdCard) -> bool,
+) -> bool {
+    let succeeded = if env._bird_deck.num_cards_in_deck() > 0 {
+        let bird_card = env._bird_deck.draw_cards_from_deck(1)[0];
+        condition(&bird_card)
+    } else {
+        false
+    };
+
+    if succeeded {
+        env.tuck_card(habitat, bird_idx);
+    }
+    record_hunt_of_current_player(env, habitat, bird_idx, succeeded);
+
+    succeeded
+}
+
+/// Wingspan condition of `hunt_with_card`. Birds without a wingspan (wild one) match any condition.
+pub(crate) fn wingspan_less_than(max_wingspan: u16) -> impl Fn(&BirdCard) -> bool {
+    move |bird_card| {
+        bird_card
+            .wingspan()
+            .map(|wingspan| wingspan < max_wingspan)
+            .unwrap_or(true)
+    }
+}
+
+/// "roll all [die] not in birdfeeder. if any are a <food>, ..."
+///
+/// Returns number of dice showing the food. Hunt succeeds if there is at least one of them,
+/// in which case caller caches the food.
+pub(crate) fn hunt_with_dice(
+    env: &mut WingspanEnv,
+    habitat: &Habitat,
+    bird_idx: usize,
+    food_idx: FoodIndex,
+) -> usize {
+    let dice_idxs = food_idx.dice_sides();
+
+    let num_dice_matching = env
+        ._bird_feeder
+        .roll_all_dice_not_in_birdfeeder(&mut env.rng)
+        .iter()
+        .filter(|dice_idx| dice_idxs.contains(dice_idx))
+        .count();
+    record_hunt_of_current_player(env, habitat, bird_idx, num_dice_matching > 0);
+
+    num_dice_matching
+}
@@ -0,0 +1,168 @@
+//! Deterministic log of everything that happened in a game since the reset.
+//!
+//! Together with the config and the seed, steps of the log fully determine the game.
+//! Each step records its resulting state checksum, so that re-executing the log can verify the game at every step.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::Action,
+    error::{WingError, WingResult},
+    serialization::STATE_VERSION,
+    wingspan_env::{WingspanEnv, WingspanEnvConfig},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayStep {
+    /// Player that made the decision.
+    pub player_idx: usize,
+    /// Action as it was before the decision was made.
+    pub action: Action,
+    pub action_idx: u8,
+    /// Whether the step was taken automatically, because action had only one valid choice.
+    pub auto_step: bool,
+    /// Checksum of the state of the environment right after this step.
+    pub state_checksum: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub config: WingspanEnvConfig,
+    pub seed: u64,
+    pub steps: Vec<ReplayStep>,
+}
+
+impl Replay {
+    pub fn new(config: WingspanEnvConfig, seed: u64) -> Self {
+        Self {
+            version: STATE_VERSION,
+            config,
+            seed,
+            steps: vec![],
+        }
+    }
+
+    pub fn to_json(&self) -> WingResult<String> {
+        serde_json::to_string(self).map_err(|err| WingError::Serialization(err.to_string()))
+    }
+
+    pub fn from_json(json: &str) -> WingResult<Self> {
+        serde_json::from_str(json).map_err(|err| WingError::Serialization(err.to_string()))
+    }
+
+    /// Re-executes the game, verifying that every step (incl. auto-steps) matches the log.
+    ///
+    /// Returns the environment in the state after the last step.
+    pub fn replay(&self) -> WingResult<WingspanEnv> {
+        if self.version != STATE_VERSION {
+            return Err(WingError::ReplayMismatch(format!(
+                "Unsupported replay version {}. Expected version {STATE_VERSION}",
+                self.version
+            )));
+        }
+
+        let mut config = self.config.clone();
+        config.record_replay = true;
+        let mut env = WingspanEnv::try_new(config);
+        env.reset(Some(self.seed));
+
+        let mut num_steps_verified = 0;
+        while num_steps_verified < self.steps.len() {
+            let step = &self.steps[num_steps_verified];
+            if step.auto_step {
+                return Err(WingError::ReplayMismatch(format!(
+                    "Step {num_steps_verified} was expected to be taken automatically, but it was not: {step:?}"
+                )));
+            }
+
+            env.step(step.action_idx).map_err(|err| {
+                WingError::ReplayMismatch(format!(
+                    "Step {num_steps_verified} failed with {err:?}: {step:?}"
+                ))
+            })?;
+
+            // Unwrap is safe, since recording is enabled
+            let recorded_steps = &env.replay().unwrap().steps;
+            for (step_idx, recorded_step) in
+                recorded_steps.iter().enumerate().skip(num_steps_verified)
+            {
+                match self.steps.get(step_idx) {
+                    Some(expected_step) if expected_step == recorded_step => {}
+                    expected_step => {
+                        return Err(WingError::ReplayMismatch(format!(
+                            "Step {step_idx} does not match. Expected {expected_step:?}, got {recorded_step:?}"
+                        )))
+                    }
+                }
+            }
+            num_steps_verified = recorded_steps.len();
+        }
+
+        Ok(env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        step_result::StepResult,
+        wingspan_env::{test_utils::random_legal_action, WingspanEnvConfigBuilder},
+    };
+
+    fn play_random_game(seed: u64) -> WingspanEnv {
+        let mut env = WingspanEnv::try_new(
+            WingspanEnvConfigBuilder::default()
+                .record_replay(true)
+                .build()
+                .unwrap(),
+        );
+        env.reset(Some(seed));
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        loop {
+            let action_idx = random_legal_action(&mut env, &mut rng);
+            // Invalid actions are not recorded
+            let _ = env.step(u8::MAX);
+            if env.step(action_idx).unwrap() == StepResult::Terminated {
+                break;
+            }
+        }
+
+        env
+    }
+
+    #[test]
+    fn replay_reproduces_game() {
+        for seed in 0..10 {
+            let env = play_random_game(seed);
+            let replay = env.replay().unwrap();
+            assert!(replay.steps.iter().any(|step| step.auto_step));
+
+            let replay = Replay::from_json(&replay.to_json().unwrap()).unwrap();
+            let replayed_env = replay.replay().unwrap();
+            assert_eq!(
+                replayed_env.state_checksum().unwrap(),
+                env.state_checksum().unwrap()
+            );
+            assert_eq!(replayed_env.points(), env.points());
+        }
+    }
+
+    #[test]
+    fn replay_detects_divergence() {
+        let env = play_random_game(0);
+        let mut replay = env.replay().unwrap().clone();
+        let step_idx = replay.steps.len() / 2;
+        replay.steps[step_idx].state_checksum += 1;
+
+        let err = replay.replay().unwrap_err();
+        assert!(
+            matches!(&err, WingError::ReplayMismatch(msg) if msg.starts_with(&format!("Step {step_idx} "))),
+            "{err:?}"
+        );
+    }
+}
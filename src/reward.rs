@@ -0,0 +1,162 @@
+//! Rewards emitted by every step, selected by `WingspanEnvConfig::reward_mode`.
+//!
+//! Rewards are computed for every player, not only for the one that made the decision.
+//! A single step can change points of several players (i.e. pink powers, or steps chained after `ChangePlayer`),
+//! and each change is attributed to the player it belongs to.
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::wingspan_env::WingspanEnv;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[pyclass(eq, eq_int)]
+pub enum RewardMode {
+    /// Change in player's points.
+    Points = 0,
+    /// Change in difference between player's points and points of the best opponent.
+    ScoreMargin = 1,
+    /// 0 until the end of the game. Then it is player's share of the win (see `GameResult::win_shares`).
+    WinLoss = 2,
+    /// 0 until the end of the game. Then it is based on player's place (see `GameResult::rank_rewards`).
+    Rank = 3,
+}
+
+// Difference between points of each player and their best opponent. Without opponents, it is player's points
+fn score_margins(points: &[u8]) -> Vec<f64> {
+    (0..points.len())
+        .map(|player_idx| {
+            let best_opponent = points
+                .iter()
+                .enumerate()
+                .filter(|(other_idx, _)| *other_idx != player_idx)
+                .map(|(_, other_points)| *other_points)
+                .max()
+                .unwrap_or_default();
+            points[player_idx] as f64 - best_opponent as f64
+        })
+        .collect()
+}
+
+/// Rewards of every player for a step, given their points before it, whether the game had already ended,
+/// and the environment after it.
+pub(crate) fn step_rewards(
+    mode: RewardMode,
+    points_before: &[u8],
+    ended_before: bool,
+    env: &WingspanEnv,
+) -> Vec<f64> {
+    match mode {
+        RewardMode::Points => env
+            .points()
+            .iter()
+            .zip(points_before)
+            .map(|(after, before)| *after as f64 - *before as f64)
+            .collect(),
+        RewardMode::ScoreMargin => score_margins(&env.points())
+            .iter()
+            .zip(score_margins(points_before))
+            .map(|(after, before)| after - before)
+            .collect(),
+        // Outcome is rewarded once, on the step that ends the game
+        RewardMode::WinLoss | RewardMode::Rank => match env.game_result() {
+            Some(_) if ended_before => vec![0.0; points_before.len()],
+            Some(game_result) if mode == RewardMode::WinLoss => game_result.win_shares(),
+            Some(game_result) => game_result.rank_rewards(),
+            None => vec![0.0; points_before.len()],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        step_result::StepResult,
+        wingspan_env::{test_utils::random_legal_action, WingspanEnvConfigBuilder},
+    };
+
+    // Plays a random game, and returns the final environment, points at the start and the sum of rewards
+    fn play_game(reward_mode: RewardMode, seed: u64) -> (WingspanEnv, Vec<u8>, Vec<f64>) {
+        let mut env = WingspanEnv::try_new(
+            WingspanEnvConfigBuilder::default()
+                .num_players(3usize)
+                .reward_mode(reward_mode)
+                .build()
+                .unwrap(),
+        );
+        env.reset(Some(seed));
+        let mut rng = StdRng::seed_from_u64(seed);
+        // Bonus cards (i.e. ones counting cards in hand) can be worth points before the first action
+        let initial_points = env.points();
+
+        let mut total_rewards = vec![0.0; 3];
+        loop {
+            let action_idx = random_legal_action(&mut env, &mut rng);
+            let (step_result, rewards) = env.step_with_rewards(action_idx).unwrap();
+            for (total, reward) in total_rewards.iter_mut().zip(rewards.iter()) {
+                *total += reward;
+            }
+            if step_result == StepResult::Terminated {
+                break;
+            }
+            if matches!(reward_mode, RewardMode::WinLoss | RewardMode::Rank) {
+                assert!(rewards.iter().all(|reward| *reward == 0.0));
+            }
+        }
+        (env, initial_points, total_rewards)
+    }
+
+    #[test]
+    fn rewards_add_up_over_the_game() {
+        for seed in 0..5 {
+            let (env, initial_points, total_rewards) = play_game(RewardMode::Points, seed);
+            let gained_points: Vec<_> = env
+                .points()
+                .iter()
+                .zip(initial_points)
+                .map(|(after, before)| *after as f64 - before as f64)
+                .collect();
+            assert_eq!(total_rewards, gained_points);
+
+            let (env, initial_points, total_rewards) = play_game(RewardMode::ScoreMargin, seed);
+            let gained_margins: Vec<_> = score_margins(&env.points())
+                .iter()
+                .zip(score_margins(&initial_points))
+                .map(|(after, before)| after - before)
+                .collect();
+            assert_eq!(total_rewards, gained_margins);
+
+            let (env, _, total_rewards) = play_game(RewardMode::WinLoss, seed);
+            assert_eq!(total_rewards, env.game_result().unwrap().win_shares());
+
+            let (env, _, total_rewards) = play_game(RewardMode::Rank, seed);
+            assert_eq!(total_rewards, env.game_result().unwrap().rank_rewards());
+        }
+    }
+
+    #[test]
+    fn no_rewards_after_game_ended() {
+        for reward_mode in [
+            RewardMode::Points,
+            RewardMode::ScoreMargin,
+            RewardMode::WinLoss,
+            RewardMode::Rank,
+        ] {
+            let (mut env, _, _) = play_game(reward_mode, 0);
+            for _ in 0..2 {
+                let (step_result, rewards) = env.step_with_rewards(0).unwrap();
+                assert_eq!(step_result, StepResult::Terminated);
+                assert!(rewards.iter().all(|reward| *reward == 0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn score_margin_is_relative_to_best_opponent() {
+        assert_eq!(score_margins(&[10, 15, 12]), [-5.0, 3.0, -3.0]);
+        assert_eq!(score_margins(&[7]), [7.0]);
+    }
+}
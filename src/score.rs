@@ -0,0 +1,229 @@
+//! Itemized final score of a player.
+
+use itertools::Itertools;
+use pyo3::prelude::*;
+
+use crate::{bonus_card::BonusCard, habitat::Habitat, player::Player, wingspan_env::WingspanEnv};
+
+/// Points from a single bonus card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[pyclass(frozen, get_all)]
+pub struct BonusCardScore {
+    pub bonus_card: BonusCard,
+    /// Number of birds (or other items) matching the card.
+    pub num_matching: usize,
+    pub points: u8,
+}
+
+#[pymethods]
+impl BonusCardScore {
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+/// Score of a player split into the categories of the official score pad.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[pyclass(frozen, get_all)]
+pub struct ScoreBreakdown {
+    pub bird_points: u8,
+    pub bonus_card_points: u8,
+    /// Points from each of the bonus cards, in order in which player holds them.
+    pub bonus_cards: Vec<BonusCardScore>,
+    pub round_goal_points: u8,
+    pub egg_points: u8,
+    pub cached_food_points: u8,
+    pub tucked_card_points: u8,
+    /// Majority of spent nectar in each of the habitats (Oceania). 0 until Oceania is supported.
+    pub nectar_points: u8,
+    /// Sum of all of the categories above.
+    pub total: u8,
+    /// Food left in player's supply. It is not worth any points, but it breaks ties in total score.
+    pub unused_food: u8,
+}
+
+#[pymethods]
+impl ScoreBreakdown {
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl Player {
+    /// Score of the player, as if the game ended right now.
+    ///
+    /// It does not trigger end of round nor end of game powers.
+    /// Nectar majority depends on the other players, and is only included by `WingspanEnv::score_breakdown`.
+    pub fn score_breakdown(&self) -> ScoreBreakdown {
+        let rows = self.get_mat().rows();
+
+        let bird_points = rows
+            .iter()
+            .flat_map(|mat_row| mat_row.get_birds().iter())
+            .map(|bird_card| bird_card.points())
+            .sum();
+        let egg_points = self.get_mat().egg_count();
+        let cached_food_points = rows
+            .iter()
+            .map(|mat_row| mat_row.get_cached_food().iter().flatten().sum::<u8>())
+            .sum();
+        let tucked_card_points = rows
+            .iter()
+            .map(|mat_row| mat_row.get_tucked_cards().iter().sum::<u8>())
+            .sum();
+
+        let bonus_cards: Vec<_> = self
+            .get_bonus_cards()
+            .iter()
+            .map(|bonus_card| {
+                let num_matching = bonus_card.get_count_of_matching(self);
+                BonusCardScore {
+                    bonus_card: *bonus_card,
+                    num_matching,
+                    points: bonus_card.scoring_rule().points(num_matching),
+                }
+            })
+            .collect();
+        let bonus_card_points = bonus_cards.iter().map(|score| score.points).sum();
+
+        let round_goal_points = self.get_end_of_round_points();
+
+        ScoreBreakdown {
+            bird_points,
+            bonus_card_points,
+            bonus_cards,
+            round_goal_points,
+            egg_points,
+            cached_food_points,
+            tucked_card_points,
+            nectar_points: 0,
+            total: bird_points
+                + bonus_card_points
+                + round_goal_points
+                + egg_points
+                + cached_food_points
+                + tucked_card_points,
+            unused_food: self.get_foods().iter().sum(),
+        }
+    }
+}
+
+impl WingspanEnv {
+    /// Score breakdown of each of the players.
+    pub fn score_breakdown(&self) -> Vec<ScoreBreakdown> {
+        let nectar_points = self.nectar_points();
+
+        (0..self.config().num_players)
+            .map(|player_idx| {
+                let mut breakdown = self.get_player(player_idx).score_breakdown();
+                breakdown.nectar_points = nectar_points[player_idx];
+                breakdown.total += breakdown.nectar_points;
+                breakdown
+            })
+            .collect()
+    }
+
+    /// Points of each of the players for spent nectar.
+    ///
+    /// In each habitat, player who spent the most nectar gets 5 points, and the second one gets 2 points.
+    /// Tied players split points of the places they occupy (rounded down). Player has to spend nectar to score.
+    fn nectar_points(&self) -> Vec<u8> {
+        const PLACE_POINTS: [u8; 2] = [5, 2];
+
+        let num_players = self.config().num_players;
+        let mut points = vec![0; num_players];
+
+        for habitat in [Habitat::Forest, Habitat::Grassland, Habitat::Wetland] {
+            let spent: Vec<u8> = (0..num_players)
+                .map(|player_idx| {
+                    self.get_player(player_idx)
+                        .get_mat()
+                        .get_row(&habitat)
+                        .get_spent_nectar()
+                })
+                .collect();
+
+            let mut place = 0;
+            for amount in spent
+                .iter()
+                .copied()
+                .filter(|x| *x > 0)
+                .sorted()
+                .rev()
+                .dedup()
+            {
+                if place >= PLACE_POINTS.len() {
+                    break;
+                }
+
+                let tied: Vec<_> = (0..num_players)
+                    .filter(|idx| spent[*idx] == amount)
+                    .collect();
+                let shared_points: u8 = PLACE_POINTS.iter().skip(place).take(tied.len()).sum();
+                for player_idx in tied.iter() {
+                    points[*player_idx] += shared_points / tied.len() as u8;
+                }
+                place += tied.len();
+            }
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::wingspan_env::{test_utils::play_random_game, WingspanEnvConfigBuilder};
+
+    #[test]
+    fn breakdown_adds_up_to_points() {
+        for seed in 0..10 {
+            let mut env = WingspanEnv::try_new(
+                WingspanEnvConfigBuilder::default()
+                    .num_players(3usize)
+                    .build()
+                    .unwrap(),
+            );
+            env.reset(Some(seed));
+            play_random_game(&mut env, &mut StdRng::seed_from_u64(seed));
+
+            for (player_idx, breakdown) in env.score_breakdown().iter().enumerate() {
+                let player = env.get_player(player_idx);
+                assert_eq!(breakdown.total, env.points()[player_idx]);
+                assert_eq!(
+                    breakdown.bonus_card_points,
+                    breakdown.bonus_cards.iter().map(|x| x.points).sum::<u8>()
+                );
+                assert_eq!(
+                    breakdown
+                        .bonus_cards
+                        .iter()
+                        .map(|x| x.bonus_card)
+                        .collect::<Vec<_>>(),
+                    player.get_bonus_cards().to_vec()
+                );
+                assert_eq!(breakdown.egg_points, player.get_mat().egg_count());
+                assert_eq!(
+                    breakdown.round_goal_points,
+                    player.get_end_of_round_points()
+                );
+                assert_eq!(breakdown.unused_food, player.get_foods().iter().sum::<u8>());
+            }
+        }
+    }
+
+    #[test]
+    fn bonus_card_scoring_rules() {
+        // Each
+        assert_eq!(BonusCard::BirdCounter.scoring_rule().points(0), 0);
+        assert_eq!(BonusCard::BirdCounter.scoring_rule().points(3), 6);
+
+        // Ladder: 2-3 birds are worth 3 points, 4+ birds are worth 7 points
+        let rule = BonusCard::Anatomist.scoring_rule();
+        let points: Vec<_> = (0..6).map(|count| rule.points(count)).collect();
+        assert_eq!(points, [0, 0, 3, 3, 7, 7]);
+    }
+}
@@ -0,0 +1,191 @@
+//! Versioned serialization of the complete state of the game.
+//!
+//! Two formats are supported:
+//!  - binary (bincode), meant for checkpoints and sending states between processes,
+//!  - JSON, meant for bug reports and inspecting the state by hand.
+//!
+//! Both formats include a version of the state layout.
+//! It has to be bumped whenever a change to serialized structs (incl. order of bird/bonus cards) breaks old states.
+
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{WingError, WingResult},
+    wingspan_env::WingspanEnv,
+};
+
+pub const STATE_VERSION: u32 = 14;
+
+#[derive(Serialize, Deserialize)]
+struct VersionedState<T> {
+    version: u32,
+    state: T,
+}
+
+// FNV-1a, since unlike std hashers it is guaranteed to be stable across Rust versions
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Write for Fnv1aHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for byte in buf {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x100000001b3);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// Populates caches, which are skipped during serialization
+pub(crate) fn restore_caches(mut env: WingspanEnv) -> WingspanEnv {
+    if let Some(action) = env.next_action().cloned() {
+        action.is_performable(&mut env);
+    }
+    env
+}
+
+fn check_version(version: u32) -> WingResult<()> {
+    if version != STATE_VERSION {
+        return Err(WingError::Serialization(format!(
+            "Unsupported state version {version}. Expected version {STATE_VERSION}"
+        )));
+    }
+    Ok(())
+}
+
+impl WingspanEnv {
+    /// Compact binary representation of the full state of the environment (incl. RNG).
+    pub fn to_bytes(&self) -> WingResult<Vec<u8>> {
+        bincode::serialize(&VersionedState {
+            version: STATE_VERSION,
+            state: self,
+        })
+        .map_err(|err| WingError::Serialization(err.to_string()))
+    }
+
+    /// Restores environment from output of `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> WingResult<Self> {
+        let mut reader = bytes;
+        // Version goes first, so it can be checked before the rest of the state is parsed
+        let version: u32 = bincode::deserialize_from(&mut reader)
+            .map_err(|err| WingError::Serialization(err.to_string()))?;
+        check_version(version)?;
+
+        bincode::deserialize_from(reader)
+            .map(restore_caches)
+            .map_err(|err| WingError::Serialization(err.to_string()))
+    }
+
+    /// Checksum of the full state of the environment (incl. RNG).
+    pub fn state_checksum(&self) -> WingResult<u64> {
+        let mut hasher = Fnv1aHasher::default();
+        bincode::serialize_into(&mut hasher, self)
+            .map_err(|err| WingError::Serialization(err.to_string()))?;
+        Ok(hasher.0)
+    }
+
+    /// Human-readable representation of the full state of the environment (incl. RNG).
+    pub fn to_json(&self) -> WingResult<String> {
+        serde_json::to_string(&VersionedState {
+            version: STATE_VERSION,
+            state: self,
+        })
+        .map_err(|err| WingError::Serialization(err.to_string()))
+    }
+
+    /// Restores environment from output of `to_json`.
+    pub fn from_json(json: &str) -> WingResult<Self> {
+        let versioned: VersionedState<serde_json::Value> =
+            serde_json::from_str(json).map_err(|err| WingError::Serialization(err.to_string()))?;
+        check_version(versioned.version)?;
+
+        serde_json::from_value(versioned.state)
+            .map(restore_caches)
+            .map_err(|err| WingError::Serialization(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        step_result::StepResult,
+        wingspan_env::{test_utils::random_legal_action, WingspanEnvConfigBuilder},
+    };
+
+    fn assert_same_game(mut env: WingspanEnv, mut restored: WingspanEnv, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        loop {
+            assert_eq!(env.action_mask(), restored.action_mask());
+            assert_eq!(env.to_bytes().unwrap(), restored.to_bytes().unwrap());
+
+            let action_idx = random_legal_action(&mut env, &mut rng);
+            let result = env.step(action_idx).unwrap();
+            assert_eq!(result, restored.step(action_idx).unwrap());
+            if result == StepResult::Terminated {
+                break;
+            }
+        }
+        assert_eq!(env.points(), restored.points());
+    }
+
+    #[test]
+    fn restored_env_continues_identically() {
+        for seed in 0..10 {
+            let mut env = WingspanEnv::try_new(
+                WingspanEnvConfigBuilder::default()
+                    .num_players(3usize)
+                    .build()
+                    .unwrap(),
+            );
+            env.reset(Some(seed));
+
+            // Play into the middle of the game, so that callbacks, queue etc. are populated
+            let mut rng = StdRng::seed_from_u64(seed + 100);
+            for _ in 0..60 {
+                let action_idx = random_legal_action(&mut env, &mut rng);
+                env.step(action_idx).unwrap();
+            }
+
+            let from_bytes = WingspanEnv::from_bytes(&env.to_bytes().unwrap()).unwrap();
+            assert_same_game(env.clone(), from_bytes, seed);
+
+            let from_json = WingspanEnv::from_json(&env.to_json().unwrap()).unwrap();
+            assert_same_game(env, from_json, seed);
+        }
+    }
+
+    #[test]
+    fn other_versions_are_rejected() {
+        let env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+
+        let mut bytes = env.to_bytes().unwrap();
+        bytes[0] += 1;
+        assert!(matches!(
+            WingspanEnv::from_bytes(&bytes),
+            Err(WingError::Serialization(_))
+        ));
+
+        let json = env.to_json().unwrap().replacen(
+            &format!("\"version\":{STATE_VERSION}"),
+            "\"version\":0",
+            1,
+        );
+        assert!(matches!(
+            WingspanEnv::from_json(&json),
+            Err(WingError::Serialization(_))
+        ));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{food::FoodIndex, habitat::Habitat};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FoodSource {
+    Feeder,
+    Supply,
+}
+
+/// Mutations of the game that pink ("once between turns") powers can react to.
+///
+/// Triggers are raised by the mutations themselves (see `WingspanEnv::raise_trigger`),
+/// and are matched against active callbacks after every step (see `BirdCard::conditional_callback`).
+/// Unlike events, they are consumed as soon as callbacks have seen them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trigger {
+    FoodGained {
+        player_idx: usize,
+        food: FoodIndex,
+        source: FoodSource,
+    },
+    CardTucked {
+        player_idx: usize,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    EggLaid {
+        player_idx: usize,
+        habitat: Habitat,
+        bird_idx: usize,
+    },
+    PredatorSucceeded {
+        player_idx: usize,
+    },
+    BirdPlayed {
+        player_idx: usize,
+        habitat: Habitat,
+    },
+    // Player took "gain food" (forest), "lay eggs" (grassland) or "draw cards" (wetland) action
+    HabitatActionTaken {
+        player_idx: usize,
+        habitat: Habitat,
+    },
+}
+
+impl Trigger {
+    /// Player whose game state was changed
+    pub fn player_idx(&self) -> usize {
+        match self {
+            Self::FoodGained { player_idx, .. }
+            | Self::CardTucked { player_idx, .. }
+            | Self::EggLaid { player_idx, .. }
+            | Self::PredatorSucceeded { player_idx }
+            | Self::BirdPlayed { player_idx, .. }
+            | Self::HabitatActionTaken { player_idx, .. } => *player_idx,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Bounded history of snapshots of the environment, used to undo steps.
+//!
+//! Snapshots are taken before every step. Since a step can include chained automatic steps,
+//! feeder rerolls and deck draws, restoring the full state is simpler and safer than reverting each change.
+
+use std::{collections::VecDeque, sync::Arc};
+
+use crate::wingspan_env::WingspanEnv;
+
+#[derive(Debug)]
+pub struct Snapshot {
+    /// State of the environment, without its own history and replay.
+    pub env: WingspanEnv,
+    /// Number of steps in the replay at the time of the snapshot.
+    pub num_replay_steps: usize,
+}
+
+// Snapshots are shared, so that cloning of the environment (i.e. for search) does not copy whole history
+#[derive(Debug, Clone, Default)]
+pub struct UndoHistory {
+    snapshots: VecDeque<Arc<Snapshot>>,
+}
+
+impl UndoHistory {
+    /// Adds snapshot to the history, forgetting the oldest one if there are more than `max_size` of them.
+    pub fn push(&mut self, snapshot: Snapshot, max_size: usize) {
+        if max_size == 0 {
+            return;
+        }
+        while self.snapshots.len() >= max_size {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(Arc::new(snapshot));
+    }
+
+    pub fn pop(&mut self) -> Option<Snapshot> {
+        self.snapshots.pop_back().map(|snapshot| {
+            Arc::try_unwrap(snapshot).unwrap_or_else(|snapshot| Snapshot {
+                env: snapshot.env.clone(),
+                num_replay_steps: snapshot.num_replay_steps,
+            })
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        error::WingError,
+        step_result::StepResult,
+        wingspan_env::{test_utils::random_legal_action, WingspanEnv, WingspanEnvConfigBuilder},
+    };
+
+    #[test]
+    fn undo_restores_state_before_step() {
+        for seed in 0..10 {
+            let mut env = WingspanEnv::try_new(
+                WingspanEnvConfigBuilder::default()
+                    .undo_history_size(3usize)
+                    .record_replay(true)
+                    .build()
+                    .unwrap(),
+            );
+            env.reset(Some(seed));
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            loop {
+                let before = env.to_bytes().unwrap();
+                let events_before = env.events().to_vec();
+                let action_idx = random_legal_action(&mut env, &mut rng);
+
+                let result = env.step(action_idx).unwrap();
+                let after = env.to_bytes().unwrap();
+
+                env.undo().unwrap();
+                assert_eq!(env.to_bytes().unwrap(), before);
+                assert_eq!(env.events(), events_before);
+
+                // Redoing the same step ends in the same state, incl. feeder rerolls and deck draws
+                assert_eq!(env.step(action_idx).unwrap(), result);
+                assert_eq!(env.to_bytes().unwrap(), after);
+
+                if result == StepResult::Terminated {
+                    break;
+                }
+            }
+
+            // Replay does not contain undone steps
+            let replayed = env.replay().unwrap().replay().unwrap();
+            assert_eq!(
+                replayed.state_checksum().unwrap(),
+                env.state_checksum().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn undo_is_bounded_by_history_size() {
+        let mut env = WingspanEnv::try_new(
+            WingspanEnvConfigBuilder::default()
+                .undo_history_size(5usize)
+                .build()
+                .unwrap(),
+        );
+        env.reset(Some(0));
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let mut states = vec![];
+        for _ in 0..8 {
+            states.push(env.to_bytes().unwrap());
+            let action_idx = random_legal_action(&mut env, &mut rng);
+            env.step(action_idx).unwrap();
+        }
+        // Invalid steps are not recorded
+        assert!(env.step(u8::MAX).is_err());
+
+        for state in states.iter().rev().take(5) {
+            env.undo().unwrap();
+            assert_eq!(&env.to_bytes().unwrap(), state);
+        }
+        assert!(matches!(env.undo(), Err(WingError::NothingToUndo)));
+
+        // History is disabled by default
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        let action_idx = random_legal_action(&mut env, &mut rng);
+        env.step(action_idx).unwrap();
+        assert!(matches!(env.undo(), Err(WingError::NothingToUndo)));
+    }
+}
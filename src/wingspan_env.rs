@@ -1,28 +1,54 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 
 use derive_builder::Builder;
 use itertools::Itertools;
-use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
-
-use pyo3::{exceptions::PyValueError, prelude::*};
+use numpy::PyArray1;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
 
 use crate::{
-    action::{Action, PyAction},
-    bird_card::{get_deck as get_birds_deck, BirdCardColor},
+    action::{Action, ActionType, PyAction},
+    bird_card::{get_deck as get_birds_deck, BirdCard},
     bird_card_callback::BirdCardCallback,
     bird_feeder::BirdFeeder,
     bonus_card::{get_deck as get_bonus_deck, BonusCard},
     deck_and_holder::DeckAndHolder,
-    end_of_round_goal::{sample_end_of_round_goals, EndOfRoundGoal, EndOfRoundScoring},
+    end_of_round_goal::{
+        sample_end_of_round_goals, score_round_goal, EndOfRoundGoal, EndOfRoundScoring,
+        RoundGoalRecord,
+    },
     error::{WingError, WingResult},
+    event::GameEvent,
     expansion::Expansion,
-    food::Foods,
-    habitat::{Habitat, HABITATS},
+    food::{FoodIndex, Foods, NUM_FOODS},
+    game_result::GameResult,
+    habitat::Habitat,
+    mat_layout::MatLayout,
+    observation::observation_layout,
     player::Player,
+    predator::{record_hunt, Hunt},
+    replay::{Replay, ReplayStep},
+    reward::{step_rewards, RewardMode},
+    score::ScoreBreakdown,
     step_result::StepResult,
+    trigger::{FoodSource, Trigger},
+    undo::{Snapshot, UndoHistory},
 };
 
-#[derive(Debug, Builder, Clone, PartialEq, Eq, PartialOrd, Ord)]
+// Bird cards dealt to each player at the start of the game
+const STARTING_HAND_SIZE: usize = 5;
+
+#[derive(Debug, Builder, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[builder(build_fn(validate = "Self::validate"))]
+#[pyclass(frozen, get_all)]
 pub struct WingspanEnvConfig {
     #[builder(setter(into), default = 20)]
     pub(crate) hand_limit: u8,
@@ -34,36 +60,160 @@ pub struct WingspanEnvConfig {
     expansions: Vec<Expansion>,
     #[builder(setter(into), default = EndOfRoundScoring::Competitive)]
     scoring_style: EndOfRoundScoring,
+    // Rewards of the habitat actions (see `MatLayout`)
+    #[builder(default = MatLayout::Core)]
+    pub(crate) mat_layout: MatLayout,
+    // How rewards returned by `step_with_rewards` are computed
+    #[builder(default = RewardMode::Points)]
+    pub(crate) reward_mode: RewardMode,
+    // Whether to record every step into a replay (see `WingspanEnv::replay`).
+    // It is off by default, since checksum of the state after every step is not free
+    #[builder(default = false)]
+    pub(crate) record_replay: bool,
+    // Maximum number of steps that can be undone (see `WingspanEnv::undo`). 0 disables the history.
+    #[builder(setter(into), default = 0)]
+    pub(crate) undo_history_size: usize,
 }
 
-#[derive(Debug, Clone)]
+impl WingspanEnvConfigBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.num_players == Some(0) {
+            return Err("num_players must be at least 1".to_string());
+        }
+        if let Some(num_rounds) = self.num_rounds {
+            if !(1..=4).contains(&num_rounds) {
+                return Err(format!(
+                    "num_rounds must be between 1 and 4, got {num_rounds}"
+                ));
+            }
+        }
+        // Playing a bird from hand is indexed by (card, habitat), and actions are indexed by u8
+        if let Some(hand_limit) = self.hand_limit {
+            if !(1..=85).contains(&hand_limit) {
+                return Err(format!(
+                    "hand_limit must be between 1 and 85, got {hand_limit}"
+                ));
+            }
+        }
+        if let Some(expansions) = &self.expansions {
+            if !expansions.contains(&Expansion::Core) {
+                return Err("expansions must include Core".to_string());
+            }
+            if let Some(expansion) = expansions
+                .iter()
+                .find(|x| !matches!(x, Expansion::Core | Expansion::European))
+            {
+                return Err(format!("Expansion {expansion:?} is not supported yet"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl WingspanEnvConfig {
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl WingspanEnvConfig {
+    /// Config built from optional arguments of python constructors. Missing ones use the defaults.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn from_py_args(
+        hand_limit: Option<u8>,
+        num_players: Option<usize>,
+        num_rounds: Option<usize>,
+        expansions: Option<Vec<Expansion>>,
+        scoring_style: Option<EndOfRoundScoring>,
+        reward_mode: Option<RewardMode>,
+        record_replay: Option<bool>,
+        undo_history_size: Option<usize>,
+        mat_layout: Option<MatLayout>,
+    ) -> PyResult<Self> {
+        let mut builder = WingspanEnvConfigBuilder::default();
+        if let Some(hand_limit) = hand_limit {
+            builder.hand_limit(hand_limit);
+        }
+        if let Some(num_players) = num_players {
+            builder.num_players(num_players);
+        }
+        if let Some(num_rounds) = num_rounds {
+            builder.num_rounds(num_rounds);
+        }
+        if let Some(expansions) = expansions {
+            builder.expansions(expansions);
+        }
+        if let Some(scoring_style) = scoring_style {
+            builder.scoring_style(scoring_style);
+        }
+        if let Some(reward_mode) = reward_mode {
+            builder.reward_mode(reward_mode);
+        }
+        if let Some(record_replay) = record_replay {
+            builder.record_replay(record_replay);
+        }
+        if let Some(undo_history_size) = undo_history_size {
+            builder.undo_history_size(undo_history_size);
+        }
+        if let Some(mat_layout) = mat_layout {
+            builder.mat_layout(mat_layout);
+        }
+
+        builder
+            .build()
+            .map_err(|err| PyValueError::new_err(format!("Error building config: {err}")))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WingspanEnv {
     config: WingspanEnvConfig,
-    pub(crate) rng: StdRng,
+    pub(crate) rng: ChaCha12Rng,
     _round_idx: i8,
     _player_idx: usize,
     _cur_turn_player_idx: usize,
     pub(crate) _bird_deck: DeckAndHolder,
-    _bonus_deck: Vec<BonusCard>,
+    pub(crate) _bonus_deck: Vec<BonusCard>,
     _end_of_round_goals: Vec<EndOfRoundGoal>,
+    // Results of every player for each of the already scored round goals
+    _round_goal_history: Vec<RoundGoalRecord>,
     _players: Vec<Player>,
     pub(crate) _bird_feeder: BirdFeeder,
     _action_queue: Vec<Action>,
-    _callbacks: HashMap<usize, HashSet<BirdCardCallback>>, // List of callback items to go through.
+    _callbacks: BTreeMap<usize, BTreeSet<BirdCardCallback>>, // List of callback items to go through.
     // List of currently active callbacks (i.e. callbacks - callbacks that already executed)
-    _active_callbacks: HashMap<usize, HashSet<BirdCardCallback>>,
+    _active_callbacks: BTreeMap<usize, BTreeSet<BirdCardCallback>>,
 
     // Whether end of game calculation happened or not
     _end_of_game_happened: bool,
-
-    // Some cards specifically require checking if a predator action succeeds or not.
-    // It's a unique dynamic in Wingspan so ok to have this done this way IMO
-    pub(crate) _predator_succeeded: bool,
+    // Whether end of round (teal) powers are being resolved. Round advances once the queue is empty
+    _end_of_round_phase: bool,
+    // Whether game end (yellow) powers are being resolved. Game ends once the queue is empty
+    _end_of_game_phase: bool,
 
     // Specifically needed for Self::LoggerheadShrike
     // Needs to keep track of state across the turn
     pub(crate) _turn_action_taken: u8,
-    pub(crate) _food_at_start_of_turn: Foods,
+    // Hunts of predators during the current turn, incl. the repeated ones
+    _hunts: Vec<Hunt>,
+    // Foods gained by the player whose turn it is (i.e. for Self::EuropeanRobin)
+    _foods_gained_this_turn: Foods,
+
+    // History of the game since the last reset. It is not a part of the state itself
+    #[serde(skip)]
+    _replay: Option<Replay>,
+    // Events that happened during the last step
+    #[serde(skip)]
+    _events: Vec<GameEvent>,
+    // Triggers raised by the current action, which pink callbacks did not see yet.
+    // Always empty between steps
+    #[serde(skip)]
+    _triggers: Vec<Trigger>,
+    // Snapshots of the state before each of the recent steps
+    #[serde(skip)]
+    _history: UndoHistory,
 }
 
 impl WingspanEnv {
@@ -71,7 +221,7 @@ impl WingspanEnv {
         let num_players = config.num_players;
         let mut env = WingspanEnv {
             config,
-            rng: StdRng::from_entropy(),
+            rng: ChaCha12Rng::from_entropy(),
             // Round index. [0, 3] are normal turns. -1 indicates game setup
             _round_idx: -1,
             // Player currently taking an action
@@ -82,14 +232,21 @@ impl WingspanEnv {
             _bonus_deck: Default::default(),
             _bird_feeder: Default::default(),
             _end_of_round_goals: Default::default(),
+            _round_goal_history: Default::default(),
             _players: Vec::with_capacity(num_players),
             _action_queue: Vec::with_capacity(50), // 50 seems like a reasonable upper bound even for most intense chains?
             _callbacks: Default::default(),
             _active_callbacks: Default::default(),
-            _predator_succeeded: false,
             _turn_action_taken: Default::default(),
-            _food_at_start_of_turn: Default::default(),
+            _hunts: vec![],
+            _foods_gained_this_turn: Default::default(),
             _end_of_game_happened: false,
+            _end_of_round_phase: false,
+            _end_of_game_phase: false,
+            _replay: None,
+            _events: vec![],
+            _triggers: vec![],
+            _history: Default::default(),
         };
         env.reset(None);
 
@@ -99,18 +256,36 @@ impl WingspanEnv {
     pub fn reset(&mut self, seed: Option<u64>) {
         self._round_idx = -1;
         self._player_idx = 0;
-
-        // If provided reset RNG
-        if let Some(seed) = seed {
-            self.rng = StdRng::seed_from_u64(seed);
-        }
+        self._cur_turn_player_idx = 0;
+        self._callbacks.clear();
+        self._active_callbacks.clear();
+        self._end_of_game_happened = false;
+        self._end_of_round_phase = false;
+        self._end_of_game_phase = false;
+        self._turn_action_taken = Default::default();
+        self._hunts.clear();
+        self._foods_gained_this_turn = Default::default();
+        self._bird_feeder = BirdFeeder::new(self.config.expansions.contains(&Expansion::Oceania));
+        self._events.clear();
+        self._triggers.clear();
+        self._history.clear();
+
+        // If not provided, draw a seed, so that the game can be replayed
+        let seed = seed.unwrap_or_else(|| self.rng.gen());
+        self.rng = ChaCha12Rng::seed_from_u64(seed);
+        self._replay = self
+            .config
+            .record_replay
+            .then(|| Replay::new(self.config.clone(), seed));
 
         // Create new deck
         let mut deck = get_birds_deck(&self.config.expansions);
         deck.shuffle(&mut self.rng);
         self._bird_deck = DeckAndHolder::new(deck);
         self._bonus_deck = get_bonus_deck(&self.config.expansions);
+        self._bonus_deck.shuffle(&mut self.rng);
 
+        self._round_goal_history.clear();
         self._end_of_round_goals = sample_end_of_round_goals(
             self.config.expansions.as_slice(),
             self.config.num_rounds,
@@ -120,7 +295,7 @@ impl WingspanEnv {
         // Give each player cards
         self._players.clear();
         for _player_idx in 0..self.config.num_players {
-            let player_bird_cards = self._bird_deck.draw_cards_from_deck(5);
+            let player_bird_cards = self._bird_deck.draw_cards_from_deck(STARTING_HAND_SIZE);
             let player_bonus_cards = self._bonus_deck.split_off(self._bonus_deck.len() - 2);
             self._players
                 .push(Player::new(player_bird_cards, player_bonus_cards));
@@ -141,9 +316,7 @@ impl WingspanEnv {
 
     fn start_of_turn(&mut self) {
         self._bird_deck.refill_display();
-        self._predator_succeeded = false;
-
-        self._food_at_start_of_turn = *self.current_player().get_foods();
+        self._hunts.clear();
     }
 
     fn end_of_round(&mut self) -> WingResult<()> {
@@ -151,30 +324,26 @@ impl WingspanEnv {
         self._player_idx = self._round_idx as usize % self.config.num_players;
         self._cur_turn_player_idx = self._player_idx;
 
-        // TODO: End of round abilities
-
+        // End of round powers are resolved before this (see `queue_end_of_round_powers`)
         if self._round_idx > 0 {
             let goal = self._end_of_round_goals[(self._round_idx - 1) as usize];
 
             self.score_end_of_round_goal(&goal, (self._round_idx - 1) as usize);
+        }
+        for player in self._players.iter_mut() {
+            player.clear_action_cubes();
+        }
 
-            for player_idx in (self._player_idx..self.config.num_players).chain(0..self._player_idx)
-            {
-                self._player_idx = player_idx;
-                let birds = self
-                    .get_player(player_idx)
-                    .get_birds_on_mat()
-                    .iter()
-                    .zip(HABITATS)
-                    .flat_map(|(v, hab)| v.iter().map(move |bc| (*bc, hab)).enumerate())
-                    .filter(|(_, (bc, _))| *bc.color() == BirdCardColor::Teal)
-                    .collect::<Vec<_>>();
-                for (bird_idx, (bc, habitat)) in birds.clone() {
-                    bc.activate(self, &habitat, bird_idx)?;
-                }
+        // Nectar which was not spent during the round is discarded
+        for player_idx in 0..self.config.num_players {
+            let num_food = self._players[player_idx].discard_unspent_nectar();
+            if num_food > 0 {
+                self.push_event(GameEvent::FoodDiscarded {
+                    player_idx,
+                    food: FoodIndex::Nectar,
+                    num_food,
+                });
             }
-
-            self._player_idx = self._cur_turn_player_idx;
         }
 
         // Start of the new round
@@ -199,34 +368,35 @@ impl WingspanEnv {
             )));
         }
 
-        // Activate them birds
-        for player_idx in 0..self.config.num_players {
-            self._player_idx = player_idx;
-            let birds = self
-                .get_player(player_idx)
-                .get_birds_on_mat()
-                .iter()
-                .zip(HABITATS)
-                .flat_map(|(v, hab)| v.iter().map(move |bc| (*bc, hab)).enumerate())
-                .filter(|(_, (bc, _))| *bc.color() == BirdCardColor::Yellow)
-                .collect::<Vec<_>>();
-            for (bird_idx, (bc, habitat)) in birds.clone() {
-                bc.activate(self, &habitat, bird_idx)?;
-            }
-        }
+        // Game end powers are resolved before this (see `queue_end_of_game_powers`),
+        // so the final score is what is on the mats now
         self._end_of_game_happened = true;
 
         Ok(())
     }
 
     pub fn points(&self) -> Vec<u8> {
-        self._players
-            .iter()
-            .map(|p| p.calculate_points())
+        self.score_breakdown()
+            .into_iter()
+            .map(|breakdown| breakdown.total)
             .collect_vec()
     }
 
-    fn check_callbacks(&mut self, action: &Action, action_idx: u8) -> WingResult<()> {
+    fn check_callbacks(&mut self) -> WingResult<()> {
+        // Successful pink powers can raise further triggers (e.g. a pink predator succeeding)
+        loop {
+            let triggers = std::mem::take(&mut self._triggers);
+            if triggers.is_empty() {
+                return Ok(());
+            }
+
+            for trigger in triggers.iter() {
+                self.check_callbacks_for_trigger(trigger)?;
+            }
+        }
+    }
+
+    fn check_callbacks_for_trigger(&mut self, trigger: &Trigger) -> WingResult<()> {
         for player_idx in 0..self.config.num_players {
             if player_idx == self._cur_turn_player_idx {
                 continue;
@@ -238,8 +408,7 @@ impl WingspanEnv {
                 for callback in callbacks.clone().iter() {
                     let callback_successful = callback.card.conditional_callback(
                         self,
-                        action,
-                        action_idx,
+                        trigger,
                         &callback.habitat,
                         callback.card_idx,
                         callback.card_player_idx,
@@ -251,12 +420,30 @@ impl WingspanEnv {
                 }
             }
 
+            for cb in callbacks_to_remove.iter() {
+                self._events.push(GameEvent::PinkPowerTriggered {
+                    player_idx: cb.card_player_idx,
+                    bird_card: cb.card,
+                    habitat: cb.habitat,
+                    bird_idx: cb.card_idx,
+                });
+                if cb.card.is_predator() {
+                    record_hunt(
+                        self,
+                        Hunt {
+                            player_idx: cb.card_player_idx,
+                            bird_card: cb.card,
+                            habitat: cb.habitat,
+                            bird_idx: cb.card_idx,
+                            succeeded: true,
+                        },
+                    );
+                }
+            }
+
             if let Some(callbacks) = self._active_callbacks.get_mut(&player_idx) {
                 for cb in callbacks_to_remove {
                     callbacks.remove(&cb);
-                    if cb.card.is_predator() {
-                        self._predator_succeeded = true;
-                    }
                 }
             }
         }
@@ -265,7 +452,85 @@ impl WingspanEnv {
     }
 
     pub fn step(&mut self, action_idx: u8) -> WingResult<StepResult> {
-        if self._round_idx == 4 {
+        let snapshot = (self.config.undo_history_size > 0).then(|| self.snapshot());
+
+        self._events.clear();
+        let result = self.recorded_step(action_idx, false);
+        match (&result, snapshot) {
+            (Ok(_), Some(snapshot)) => self._history.push(snapshot, self.config.undo_history_size),
+            (Ok(_), None) => {}
+            // Step did not happen
+            (Err(_), _) => self._events.clear(),
+        }
+        result
+    }
+
+    fn snapshot(&mut self) -> Snapshot {
+        // Neither history nor replay are part of the snapshot, so that each step does not copy them
+        let history = std::mem::take(&mut self._history);
+        let replay = self._replay.take();
+        let snapshot = Snapshot {
+            env: self.clone(),
+            num_replay_steps: replay.as_ref().map_or(0, |replay| replay.steps.len()),
+        };
+        self._history = history;
+        self._replay = replay;
+        snapshot
+    }
+
+    /// Restores the state from before the last successful `step`, incl. all of the steps that were taken automatically.
+    ///
+    /// At most `undo_history_size` (see config) steps can be undone in a row.
+    pub fn undo(&mut self) -> WingResult<()> {
+        let Snapshot {
+            mut env,
+            num_replay_steps,
+        } = self._history.pop().ok_or(WingError::NothingToUndo)?;
+
+        env._history = std::mem::take(&mut self._history);
+        env._replay = self._replay.take();
+        if let Some(replay) = env._replay.as_mut() {
+            replay.steps.truncate(num_replay_steps);
+        }
+        *self = env;
+        Ok(())
+    }
+
+    fn recorded_step(&mut self, action_idx: u8, auto_step: bool) -> WingResult<StepResult> {
+        let replay_step_idx = match (self._replay.as_mut(), self._action_queue.last()) {
+            (Some(replay), Some(action)) if !self._end_of_game_happened => {
+                replay.steps.push(ReplayStep {
+                    player_idx: self._player_idx,
+                    action: action.clone(),
+                    action_idx,
+                    auto_step,
+                    state_checksum: 0,
+                });
+                replay.steps.len() - 1
+            }
+            _ => return self.perform_step(action_idx),
+        };
+
+        let result = self.perform_step(action_idx);
+        match result {
+            Ok(_) => {
+                let state_checksum = self.state_checksum()?;
+                if let Some(replay) = self._replay.as_mut() {
+                    replay.steps[replay_step_idx].state_checksum = state_checksum;
+                }
+            }
+            Err(_) => {
+                // Step did not happen
+                if let Some(replay) = self._replay.as_mut() {
+                    replay.steps.truncate(replay_step_idx);
+                }
+            }
+        }
+        result
+    }
+
+    fn perform_step(&mut self, action_idx: u8) -> WingResult<StepResult> {
+        if self._end_of_game_happened {
             // We have terminated / End of round
             return Ok(StepResult::Terminated);
         }
@@ -284,37 +549,71 @@ impl WingspanEnv {
         // Update action here to keep track of what is going on this turn
         if matches!(action, Action::ChooseAction) {
             self._turn_action_taken = action_idx;
+            self._foods_gained_this_turn = Default::default();
+            self.current_player_mut().place_action_cube(action_idx);
         }
-        self.check_callbacks(&action, action_idx)?;
+        self.check_callbacks()?;
 
-        // Ensure that next action can be performed
-        while !self._action_queue.is_empty() {
-            let next_action = self._action_queue.last().unwrap().clone();
+        loop {
+            // Ensure that next action can be performed
+            while !self._action_queue.is_empty() {
+                let next_action = self._action_queue.last().unwrap().clone();
 
-            // If next action is not performable, remove it
-            if !next_action.is_performable(self) {
-                self._action_queue.pop();
-                continue;
+                // If next action is not performable, remove it
+                if !next_action.is_performable(self) {
+                    self._action_queue.pop();
+                    continue;
+                }
+
+                // If next action has only one valid action, just do it
+                let valid_actions = next_action.valid_actions(self);
+                if valid_actions.len() == 1 {
+                    if self.recorded_step(valid_actions[0], true)? == StepResult::Terminated {
+                        // Game ended as part of the chained step
+                        return Ok(StepResult::Terminated);
+                    }
+                } else {
+                    // Next action is valid and has more than one option
+                    break;
+                }
             }
 
-            // If next action has only one valid action, just do it
-            let valid_actions = next_action.valid_actions(self);
-            if valid_actions.len() == 1 {
-                self.step(valid_actions[0])?;
-            } else {
-                // Next action is valid and has more than one option
-                break;
+            if !self._action_queue.is_empty() {
+                return Ok(StepResult::Live);
+            }
+
+            // End of round powers are resolved, so the round can advance
+            if self._end_of_round_phase {
+                self._end_of_round_phase = false;
+                self.end_of_round()?;
+
+                if self._round_idx == self.config.num_rounds as i8 {
+                    // End of game is after Round 4 (0 - when it is zero indexed).
+                    // Decisions of game end powers need to be made before final scoring.
+                    self.queue_end_of_game_powers();
+                    continue;
+                }
+                self.push_action(Action::ChooseAction);
+
+                // Reduce number of turns left, since a new player will be making a move
+                self.current_player_mut().turns_left -= 1;
+                return Ok(StepResult::Live);
             }
-        }
 
-        // Handle end of turn for the player
-        if self._action_queue.is_empty() {
+            // Game end powers are resolved, so the game is over
+            if self._end_of_game_phase {
+                self._end_of_game_phase = false;
+                self.end_of_game()?;
+                return Ok(StepResult::Terminated);
+            }
+
+            // Handle end of turn for the player
             // Re-activate current players callbacks
             self._active_callbacks.entry(self._player_idx).insert_entry(
                 self._callbacks
                     .get(&self._player_idx)
                     .cloned()
-                    .unwrap_or(HashSet::new()),
+                    .unwrap_or_default(),
             );
 
             // Go to next player
@@ -348,106 +647,107 @@ impl WingspanEnv {
                         self.push_action(Action::DiscardFoodOrBirdCard);
                     }
                 }
-            } else {
-                self._player_idx %= self.config.num_players;
-                // Normal rounds
-                if self.current_player().turns_left == 0 {
-                    // End of round
-                    self.end_of_round()?;
-
-                    if self._round_idx == self.config.num_rounds as i8 {
-                        // End of game is after Round 4 (0 - when it is zero indexed)
-                        self.end_of_game()?;
-                        return Ok(StepResult::Terminated);
-                    }
-                } else {
-                    // End of normal turn
-                    self.start_of_turn();
-                }
-                self.push_action(Action::ChooseAction);
+                return Ok(StepResult::Live);
+            }
 
-                // Reduce number of turns left, since a new player will be making a move
-                self.current_player_mut().turns_left -= 1;
+            self._player_idx %= self.config.num_players;
+            // Normal rounds
+            if self.current_player().turns_left == 0 {
+                // End of round. Decisions of end of round powers need to be made before the round advances,
+                // so go back to resolving the queue.
+                self.queue_end_of_round_powers();
+                continue;
             }
-        }
 
-        Ok(StepResult::Live)
+            // End of normal turn
+            self.start_of_turn();
+            self.push_action(Action::ChooseAction);
+
+            // Reduce number of turns left, since a new player will be making a move
+            self.current_player_mut().turns_left -= 1;
+            return Ok(StepResult::Live);
+        }
     }
 
-    fn score_end_of_round_goal(&mut self, goal: &EndOfRoundGoal, round_to_score_idx: usize) {
-        // TODO: Implement also non-competitive scoring
-        let scores = (0..self._players.len())
-            .map(|player_idx| (goal.get_num_matching(self, player_idx), player_idx))
-            .sorted();
-
-        const COMPETITIVE_BASE_SCORES: [u8; 4] = [4, 1, 0, 0];
-        const COMPETITIVE_PER_ROUND_SCORE_ADDS: [u8; 4] = [1, 1, 1, 0];
-
-        let mut cur_score = 0;
-        let mut cur_players = vec![];
-
-        match self.config.scoring_style {
-            EndOfRoundScoring::Competitive => {
-                for (score_idx, (player_score, player_idx)) in scores.enumerate() {
-                    // First iter
-                    if score_idx == 0 {
-                        cur_score = player_score;
-                        cur_players.push(player_idx);
-                        continue;
-                    }
+    // Queues end of round (teal) powers of every player, starting with the first player of the ending round
+    fn queue_end_of_round_powers(&mut self) {
+        self._end_of_round_phase = true;
+        let first_player_idx = self._round_idx as usize % self.config.num_players;
+        let num_players = self.config.num_players;
+
+        // Get back to the player that goes next, once powers are resolved
+        self.push_action(Action::ChangePlayer(self._player_idx));
+        // Actions are a LIFO queue, so push them in reverse order.
+        for player_idx in (first_player_idx..num_players)
+            .chain(0..first_player_idx)
+            .rev()
+        {
+            self.push_action(Action::EndOfRoundPowers);
+            self.push_action(Action::ChangePlayer(player_idx));
+        }
+    }
 
-                    // 0-score is always worth nothing
-                    if cur_score == 0 {
-                        break;
-                    }
+    // Queues game end (yellow) powers of every player, in the player order
+    fn queue_end_of_game_powers(&mut self) {
+        self._end_of_game_phase = true;
+        // Actions are a LIFO queue, so push them in reverse order.
+        for player_idx in (0..self.config.num_players).rev() {
+            self.push_action(Action::EndOfGamePowers);
+            self.push_action(Action::ChangePlayer(player_idx));
+        }
+    }
 
-                    // More than 1 player with the same score
-                    if player_score == cur_score {
-                        cur_players.push(player_idx);
-                        continue;
-                    }
+    /// Replay of the game since the last reset.
+    ///
+    /// It is None if recording is disabled in the config, or if the environment was restored from a serialized state.
+    pub fn replay(&self) -> Option<&Replay> {
+        self._replay.as_ref()
+    }
 
-                    // At this point scores are different
-
-                    // First process current queue
-                    let cur_score_idx = score_idx - cur_players.len();
-                    let total_pts: u8 =
-                        // Points from base score
-                        COMPETITIVE_BASE_SCORES[cur_score_idx..score_idx.min(4)].iter().sum::<u8>() +
-                        // They increase per round
-                        COMPETITIVE_PER_ROUND_SCORE_ADDS[cur_score_idx..score_idx.min(4)].iter().sum::<u8>() * (round_to_score_idx as u8)
-                    ;
-                    let pts_per_player = total_pts / cur_players.len() as u8;
-                    for cur_player_idx in cur_players.iter() {
-                        self.get_player_mut(*cur_player_idx)
-                            .add_end_of_round_points(pts_per_player);
-                    }
+    /// Performs a step, and returns rewards of all of the players (see `RewardMode`).
+    ///
+    /// Rewards are attributed to the players they belong to,
+    /// regardless of who took the action (e.g. pink powers of other players).
+    pub fn step_with_rewards(&mut self, action_idx: u8) -> WingResult<(StepResult, Vec<f64>)> {
+        let points_before = self.points();
+        let ended_before = self.game_result().is_some();
+        let step_result = self.step(action_idx)?;
+        let rewards = step_rewards(self.config.reward_mode, &points_before, ended_before, self);
+
+        Ok((step_result, rewards))
+    }
 
-                    // Only up-to third place gets points.
-                    if score_idx > 2 {
-                        break;
-                    }
+    fn score_end_of_round_goal(&mut self, goal: &EndOfRoundGoal, round_to_score_idx: usize) {
+        let num_matching: Vec<_> = (0..self._players.len())
+            .map(|player_idx| goal.get_num_matching(self, player_idx))
+            .collect();
+        let scores = score_round_goal(self.config.scoring_style, round_to_score_idx, &num_matching);
 
-                    // Keep track of new score and new player set
-                    cur_players.clear();
-                    cur_score = player_score;
-                    cur_players.push(player_idx);
-                }
-            }
-            EndOfRoundScoring::Friendly => {
-                for (player_score, player_idx) in scores {
-                    self.get_player_mut(player_idx)
-                        .add_end_of_round_points(player_score.min(5) as u8);
-                }
+        for (player_idx, score) in scores.iter().enumerate() {
+            if score.points == 0 {
+                continue;
             }
+            self.get_player_mut(player_idx)
+                .add_end_of_round_points(score.points);
+            self.push_event(GameEvent::RoundGoalScored {
+                round_idx: round_to_score_idx,
+                player_idx,
+                points: score.points,
+            });
         }
+        self._round_goal_history.push(RoundGoalRecord {
+            round_idx: round_to_score_idx,
+            goal: *goal,
+            scoring_style: self.config.scoring_style,
+            scores,
+        });
     }
 
     pub fn populate_action_queue_from_habitat_action(&mut self, habitat: &Habitat) {
         let mut actions = self
-            .current_player_mut()
-            .get_mat_mut()
-            .get_actions_from_habitat_action(habitat);
+            .current_player()
+            .get_mat()
+            .get_actions_from_habitat_action(&self.config.mat_layout, habitat);
 
         self.append_actions(&mut actions);
     }
@@ -461,6 +761,140 @@ impl WingspanEnv {
             .add_bonus_cards(&mut player_bonus_cards);
     }
 
+    // Helpers below modify state of the current player and record the corresponding events.
+
+    pub fn lay_egg(
+        &mut self,
+        habitat: &Habitat,
+        bird_idx: usize,
+        egg_cap_override: u8,
+    ) -> WingResult<()> {
+        self.current_player_mut()
+            .get_mat_mut()
+            .get_row_mut(habitat)
+            .place_egg_at_exact_bird_idx(bird_idx, egg_cap_override)?;
+        self.push_event(GameEvent::EggLaid {
+            player_idx: self.current_player_idx(),
+            habitat: *habitat,
+            bird_idx,
+        });
+        self.raise_trigger(Trigger::EggLaid {
+            player_idx: self.current_player_idx(),
+            habitat: *habitat,
+            bird_idx,
+        });
+        Ok(())
+    }
+
+    pub fn gain_food_from_feeder(&mut self, food: FoodIndex, num_food: u8) {
+        self.current_player_mut().add_food(food, num_food);
+        self.record_food_gained(food, num_food);
+        for _ in 0..num_food {
+            self.push_event(GameEvent::FoodGainedFromFeeder {
+                player_idx: self.current_player_idx(),
+                food,
+            });
+            self.raise_trigger(Trigger::FoodGained {
+                player_idx: self.current_player_idx(),
+                food,
+                source: FoodSource::Feeder,
+            });
+        }
+    }
+
+    pub fn gain_food_from_supply(&mut self, food: FoodIndex, num_food: u8) {
+        self.current_player_mut().add_food(food, num_food);
+        self.record_food_gained(food, num_food);
+        for _ in 0..num_food {
+            self.push_event(GameEvent::FoodGainedFromSupply {
+                player_idx: self.current_player_idx(),
+                food,
+            });
+            self.raise_trigger(Trigger::FoodGained {
+                player_idx: self.current_player_idx(),
+                food,
+                source: FoodSource::Supply,
+            });
+        }
+    }
+
+    fn record_food_gained(&mut self, food: FoodIndex, num_food: u8) {
+        if self._player_idx == self._cur_turn_player_idx {
+            self._foods_gained_this_turn[food as usize] += num_food;
+        }
+    }
+
+    pub fn cache_food(&mut self, habitat: &Habitat, bird_idx: usize, food: FoodIndex) {
+        self.current_player_mut()
+            .get_mat_mut()
+            .get_row_mut(habitat)
+            .cache_food(bird_idx, food);
+        self.push_event(GameEvent::FoodCached {
+            player_idx: self.current_player_idx(),
+            habitat: *habitat,
+            bird_idx,
+            food,
+        });
+    }
+
+    pub fn tuck_card(&mut self, habitat: &Habitat, bird_idx: usize) {
+        self.current_player_mut()
+            .get_mat_mut()
+            .get_row_mut(habitat)
+            .tuck_card(bird_idx);
+        self.push_event(GameEvent::CardTucked {
+            player_idx: self.current_player_idx(),
+            habitat: *habitat,
+            bird_idx,
+        });
+        self.raise_trigger(Trigger::CardTucked {
+            player_idx: self.current_player_idx(),
+            habitat: *habitat,
+            bird_idx,
+        });
+    }
+
+    /// Gives the card to the current player. If their hand is already at the hand limit, the card is discarded.
+    pub fn add_bird_card(&mut self, bird_card: BirdCard, from_display: bool) {
+        if self.current_player().get_bird_cards().len() >= self.config.hand_limit.into() {
+            return;
+        }
+        self.current_player_mut().add_bird_card(bird_card);
+        if from_display {
+            self.current_player_mut().reveal_bird_card(bird_card);
+        }
+        self.push_event(GameEvent::BirdCardDrawn {
+            player_idx: self.current_player_idx(),
+            bird_card,
+            from_display,
+        });
+    }
+
+    /// Foods a "[wild]" can be gained as. Nectar is only in the supply when playing with Oceania.
+    pub fn wild_foods(&self) -> Box<[FoodIndex]> {
+        FoodIndex::iter().take(self.num_foods()).collect()
+    }
+
+    /// Number of foods in the game. Nectar is the last food, and it is only used when playing with Oceania.
+    ///
+    /// Actions that choose a food are sized with it, so that indexes of the following choices do not depend on unused foods.
+    pub fn num_foods(&self) -> usize {
+        if self.config.expansions.contains(&Expansion::Oceania) {
+            NUM_FOODS
+        } else {
+            NUM_FOODS - 1
+        }
+    }
+
+    /// Draws up to `num_cards`, stopping at the hand limit (see `add_bird_card`).
+    pub fn draw_bird_cards_from_deck(&mut self, num_cards: usize) {
+        let hand_size = self.current_player().get_bird_cards().len();
+        let num_cards = num_cards.min((self.config.hand_limit as usize).saturating_sub(hand_size));
+        for bird_card in self._bird_deck.draw_cards_from_deck(num_cards) {
+            self.add_bird_card(bird_card, false);
+        }
+    }
+
     pub fn get_player(&self, player_idx: usize) -> &Player {
         &self._players[player_idx % self._players.len()]
     }
@@ -509,6 +943,95 @@ impl WingspanEnv {
         self._action_queue.last().map(|x| x.action_space_size(self))
     }
 
+    /// Fixed width of the action mask. It is the upper bound of action space size across all of the actions.
+    pub fn action_mask_size(&self) -> usize {
+        // Bird cards are only added below the hand limit, but the starting hand can be bigger than it
+        let max_hand_size = (self.config.hand_limit as usize).max(STARTING_HAND_SIZE);
+        let num_other_players = self.config.num_players - 1;
+
+        [
+            // Playing a bird from hand into any of the habitats
+            3 * max_hand_size,
+            // Discarding any food or bird card during setup
+            self.num_foods() + max_hand_size,
+            // Any spot on the mat
+            15,
+            // Discarding any bonus card
+            get_bonus_deck(&self.config.expansions).len(),
+            // Stealing any food from any other player, or choosing a player and a reward
+            self.num_foods() * num_other_players,
+            2 * num_other_players,
+            // Set of cards passed around the players
+            self.config.num_players + 1,
+        ]
+        .into_iter()
+        .max()
+        .unwrap()
+    }
+
+    /// Mask over the action space, with `true` at every index that is a valid choice for the next action.
+    ///
+    /// If game is in terminated state, all of the entries are `false`.
+    pub fn action_mask(&mut self) -> Vec<bool> {
+        let mut mask = vec![false; self.action_mask_size()];
+
+        for action_idx in self.valid_actions() {
+            let action_idx = action_idx as usize;
+            debug_assert!(
+                action_idx < mask.len(),
+                "Action index {action_idx} of {:?} does not fit into the mask of size {}",
+                self._action_queue.last(),
+                mask.len()
+            );
+            mask[action_idx] = true;
+        }
+
+        mask
+    }
+
+    /// Valid choices for the next action. Empty if game is in terminated state.
+    pub fn valid_actions(&mut self) -> Vec<u8> {
+        let action = match self._action_queue.last() {
+            Some(action) => action.clone(),
+            None => return vec![],
+        };
+        if !action.is_performable(self) {
+            return vec![];
+        }
+
+        action.valid_actions(self)
+    }
+
+    /// Copy of the environment meant for simulations (i.e. search).
+    ///
+    /// It does not carry over replay nor undo history, and does not record them going forward.
+    pub fn detached_clone(&self) -> Self {
+        let mut env = self.clone();
+        env.config.record_replay = false;
+        env.config.undo_history_size = 0;
+        env._replay = None;
+        env._history.clear();
+        env
+    }
+
+    /// Whether the game has ended (incl. end of game powers and scoring).
+    pub fn is_terminated(&self) -> bool {
+        self._end_of_game_happened
+    }
+
+    pub fn round_idx(&self) -> i8 {
+        self._round_idx
+    }
+
+    pub fn end_of_round_goals(&self) -> &Vec<EndOfRoundGoal> {
+        &self._end_of_round_goals
+    }
+
+    /// Goal of each of the rounds scored so far, with count, placement and points of every player.
+    pub fn round_goal_history(&self) -> &[RoundGoalRecord] {
+        &self._round_goal_history
+    }
+
     pub fn config(&self) -> &WingspanEnvConfig {
         &self.config
     }
@@ -541,35 +1064,176 @@ impl WingspanEnv {
             .insert(callback);
     }
 
-    pub fn predator_succeeded(&mut self) {
-        self._predator_succeeded = true;
+    /// Removes callbacks of the player's bird, i.e. when it is covered by another bird (European).
+    pub(crate) fn remove_callbacks_of_bird(
+        &mut self,
+        player_idx: usize,
+        habitat: &Habitat,
+        bird_idx: usize,
+    ) {
+        let is_of_bird = |callback: &BirdCardCallback| {
+            callback.habitat == *habitat && callback.card_idx == bird_idx
+        };
+        for callbacks in [&mut self._callbacks, &mut self._active_callbacks] {
+            if let Some(callbacks) = callbacks.get_mut(&player_idx) {
+                callbacks.retain(|callback| !is_of_bird(callback));
+            }
+        }
+    }
+
+    pub(crate) fn push_event(&mut self, event: GameEvent) {
+        self._events.push(event);
+    }
+
+    /// Events that happened during the last step (incl. steps that were taken automatically).
+    pub fn events(&self) -> &[GameEvent] {
+        &self._events
+    }
+
+    pub(crate) fn push_hunt(&mut self, hunt: Hunt) {
+        self._hunts.push(hunt);
+    }
+
+    /// Foods gained by the player whose turn it is, since the start of the turn.
+    pub fn foods_gained_this_turn(&self) -> &Foods {
+        &self._foods_gained_this_turn
+    }
+
+    /// Hunts of predators during the current turn, in order in which they happened.
+    pub fn hunts(&self) -> &[Hunt] {
+        &self._hunts
+    }
+
+    /// Lets active pink callbacks react to a change of the game state, once the current action is performed.
+    pub(crate) fn raise_trigger(&mut self, trigger: Trigger) {
+        self._triggers.push(trigger);
     }
 }
 
 #[pyclass]
 #[derive(Debug, Clone)]
 pub struct PyWingspanEnv {
-    inner: WingspanEnv,
+    pub(crate) inner: WingspanEnv,
 }
 
 #[pymethods]
 impl PyWingspanEnv {
     #[new]
-    #[pyo3(signature = (hand_limit=None, num_players=None))]
-    pub fn new(hand_limit: Option<u8>, num_players: Option<u8>) -> PyResult<Self> {
-        let mut builder = &mut WingspanEnvConfigBuilder::create_empty();
-        if let Some(hand_limit) = hand_limit {
-            builder = builder.hand_limit(hand_limit);
+    #[pyo3(signature = (hand_limit=None, num_players=None, record_replay=None, undo_history_size=None, num_rounds=None, expansions=None, scoring_style=None, seed=None, reward_mode=None, mat_layout=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        hand_limit: Option<u8>,
+        num_players: Option<usize>,
+        record_replay: Option<bool>,
+        undo_history_size: Option<usize>,
+        num_rounds: Option<usize>,
+        expansions: Option<Vec<Expansion>>,
+        scoring_style: Option<EndOfRoundScoring>,
+        seed: Option<u64>,
+        reward_mode: Option<RewardMode>,
+        mat_layout: Option<MatLayout>,
+    ) -> PyResult<Self> {
+        let config = WingspanEnvConfig::from_py_args(
+            hand_limit,
+            num_players,
+            num_rounds,
+            expansions,
+            scoring_style,
+            reward_mode,
+            record_replay,
+            undo_history_size,
+            mat_layout,
+        )?;
+
+        let mut inner = WingspanEnv::try_new(config);
+        if seed.is_some() {
+            inner.reset(seed);
         }
-        if let Some(num_players) = num_players {
-            builder = builder.num_players(num_players);
+        Ok(Self { inner })
+    }
+
+    pub fn __getstate__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyBytes>> {
+        Self::to_bytes(slf)
+    }
+
+    pub fn __setstate__(slf: &Bound<'_, Self>, state: &[u8]) -> PyResult<()> {
+        slf.borrow_mut().inner = WingspanEnv::from_bytes(state)?;
+        Ok(())
+    }
+
+    /// Full state of the environment in a compact, binary format.
+    pub fn to_bytes<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = slf.borrow().inner.to_bytes()?;
+        Ok(PyBytes::new(slf.py(), &bytes))
+    }
+
+    #[staticmethod]
+    pub fn from_bytes(state: &[u8]) -> PyResult<Self> {
+        Ok(Self {
+            inner: WingspanEnv::from_bytes(state)?,
+        })
+    }
+
+    /// Full state of the environment in a human-readable JSON format.
+    pub fn to_json(slf: &Bound<'_, Self>) -> PyResult<String> {
+        Ok(slf.borrow().inner.to_json()?)
+    }
+
+    #[staticmethod]
+    pub fn from_json(state: &str) -> PyResult<Self> {
+        Ok(Self {
+            inner: WingspanEnv::from_json(state)?,
+        })
+    }
+
+    /// Replay of the game since the last reset in JSON format.
+    pub fn replay_json(slf: &Bound<'_, Self>) -> PyResult<Option<String>> {
+        Ok(slf
+            .borrow()
+            .inner
+            .replay()
+            .map(|replay| replay.to_json())
+            .transpose()?)
+    }
+
+    #[staticmethod]
+    pub fn from_replay_json(replay: &str) -> PyResult<Self> {
+        Ok(Self {
+            inner: Replay::from_json(replay)?.replay()?,
+        })
+    }
+
+    /// Events that happened during the last step.
+    pub fn events(slf: &Bound<'_, Self>) -> Vec<GameEvent> {
+        slf.borrow().inner.events().to_vec()
+    }
+
+    /// Restores the state from before the last step.
+    pub fn undo(slf: &Bound<'_, Self>) -> PyResult<()> {
+        Ok(slf.borrow_mut().inner.undo()?)
+    }
+
+    /// Copy of the environment with information hidden from the observer resampled.
+    #[pyo3(signature = (observer_idx=None, seed=None))]
+    pub fn determinize(
+        slf: &Bound<'_, Self>,
+        observer_idx: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        let inner = &slf.borrow().inner;
+        let observer_idx = observer_idx.unwrap_or(inner.current_player_idx());
+        if observer_idx >= inner.config.num_players {
+            return Err(PyValueError::new_err(format!(
+                "Invalid observer index {observer_idx}"
+            )));
         }
-        let config = builder
-            .build()
-            .map_err(|err| PyValueError::new_err(format!("Error building config: {err}")))?;
+        let mut rng = match seed {
+            Some(seed) => ChaCha12Rng::seed_from_u64(seed),
+            None => ChaCha12Rng::from_entropy(),
+        };
 
         Ok(Self {
-            inner: WingspanEnv::try_new(config),
+            inner: inner.determinize(observer_idx, &mut rng),
         })
     }
 
@@ -584,19 +1248,91 @@ impl PyWingspanEnv {
     }
 
     #[pyo3(signature = (seed=None))]
-    pub fn reset(slf: &Bound<'_, Self>, seed: Option<u64>) {
-        slf.borrow_mut().inner.reset(seed)
+    pub fn reset<'py>(
+        slf: &Bound<'py, Self>,
+        seed: Option<u64>,
+    ) -> PyResult<(Bound<'py, PyArray1<f32>>, Bound<'py, PyDict>)> {
+        slf.borrow_mut().inner.reset(seed);
+
+        Ok((
+            Self::observation(slf, None)?,
+            Self::info(slf, StepResult::Live)?,
+        ))
     }
 
-    pub fn step(slf: &Bound<'_, Self>, action_idx: u8) -> PyResult<StepResult> {
-        match slf.borrow_mut().inner.step(action_idx) {
+    /// Performs an action for the current player.
+    ///
+    /// Returns a gymnasium style tuple of (observation, reward, terminated, truncated, info).
+    /// Reward is the one of the player that performed the action, as selected by `reward_mode` of the config.
+    #[allow(clippy::type_complexity)]
+    pub fn step<'py>(
+        slf: &Bound<'py, Self>,
+        action_idx: u8,
+    ) -> PyResult<(
+        Bound<'py, PyArray1<f32>>,
+        f64,
+        bool,
+        bool,
+        Bound<'py, PyDict>,
+    )> {
+        let acting_player_idx = slf.borrow().inner.current_player_idx();
+        let (step_result, rewards) = Self::step_agents(slf, action_idx)?;
+
+        Ok((
+            Self::observation(slf, None)?,
+            rewards[acting_player_idx],
+            step_result == StepResult::Terminated,
+            false,
+            Self::info(slf, step_result)?,
+        ))
+    }
+
+    /// Performs an action for the current player (agent), and returns the rewards for all of the agents.
+    ///
+    /// Reward of each agent is computed according to `reward_mode` of the config.
+    pub fn step_agents(slf: &Bound<'_, Self>, action_idx: u8) -> PyResult<(StepResult, Vec<f64>)> {
+        let inner = &mut slf.borrow_mut().inner;
+        match inner.step_with_rewards(action_idx) {
             Ok(x) => Ok(x),
-            Err(WingError::InvalidAction) => Ok(StepResult::Invalid),
+            Err(WingError::InvalidAction) => {
+                Ok((StepResult::Invalid, vec![0.0; inner.config().num_players]))
+            }
             Err(x) => Err(x.into()),
-            // Err(x) => return Err(x.into()),
         }
     }
 
+    /// Index of the player whose turn it currently is.
+    #[getter]
+    pub fn turn_player_idx(slf: &Bound<'_, Self>) -> usize {
+        slf.borrow().inner.current_turn_player_idx()
+    }
+
+    /// Goal of each of the rounds scored so far, with count, placement and points of every player.
+    pub fn round_goal_history(slf: &Bound<'_, Self>) -> Vec<RoundGoalRecord> {
+        slf.borrow().inner.round_goal_history().to_vec()
+    }
+
+    /// Placement of every player (0 is first, None if nothing matched) on each of the round goals scored so far.
+    pub fn round_goal_placements(slf: &Bound<'_, Self>) -> Vec<Vec<Option<usize>>> {
+        slf.borrow()
+            .inner
+            .round_goal_history()
+            .iter()
+            .map(|record| record.scores.iter().map(|score| score.placement).collect())
+            .collect()
+    }
+
+    /// Settings the environment was created with.
+    #[getter]
+    pub fn config(slf: &Bound<'_, Self>) -> WingspanEnvConfig {
+        slf.borrow().inner.config().clone()
+    }
+
+    #[getter]
+    pub fn num_players(slf: &Bound<'_, Self>) -> usize {
+        slf.borrow().inner.config().num_players
+    }
+
     pub fn points(&self) -> Vec<usize> {
         self.inner
             .points()
@@ -605,10 +1341,53 @@ impl PyWingspanEnv {
             .collect_vec()
     }
 
+    /// Final standings of the players. None until the game is terminated.
+    pub fn game_result(slf: &Bound<'_, Self>) -> Option<GameResult> {
+        slf.borrow().inner.game_result()
+    }
+
+    /// Score of each of the players split into categories, incl. unused food as the tiebreaker.
+    pub fn score_breakdown(slf: &Bound<'_, Self>) -> Vec<ScoreBreakdown> {
+        slf.borrow().inner.score_breakdown()
+    }
+
     pub fn action_space_size(slf: &Bound<'_, Self>) -> Option<usize> {
         slf.borrow().inner.action_space_size()
     }
 
+    pub fn action_mask_size(slf: &Bound<'_, Self>) -> usize {
+        slf.borrow().inner.action_mask_size()
+    }
+
+    pub fn action_mask<'py>(slf: &Bound<'py, Self>) -> Bound<'py, PyArray1<bool>> {
+        let mask = slf.borrow_mut().inner.action_mask();
+        PyArray1::from_vec(slf.py(), mask)
+    }
+
+    #[pyo3(signature = (player_idx=None))]
+    pub fn observation<'py>(
+        slf: &Bound<'py, Self>,
+        player_idx: Option<usize>,
+    ) -> PyResult<Bound<'py, PyArray1<f32>>> {
+        let inner = &slf.borrow().inner;
+        let obs = match player_idx {
+            Some(player_idx) if player_idx >= inner.config().num_players => {
+                return Err(PyValueError::new_err(format!(
+                    "Player index {player_idx} is out of range for {} players",
+                    inner.config().num_players
+                )))
+            }
+            Some(player_idx) => inner.observation_for_player(player_idx),
+            None => inner.observation(),
+        };
+
+        Ok(PyArray1::from_vec(slf.py(), obs))
+    }
+
+    pub fn observation_layout(slf: &Bound<'_, Self>) -> Vec<(String, usize)> {
+        observation_layout(slf.borrow().inner.config())
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn _debug_get_state(
         slf: &Bound<'_, Self>,
@@ -617,7 +1396,7 @@ impl PyWingspanEnv {
         usize,
         Option<String>,
         Vec<Player>,
-        HashMap<usize, HashSet<BirdCardCallback>>,
+        BTreeMap<usize, BTreeSet<BirdCardCallback>>,
     ) {
         let inner = &slf.borrow().inner;
 
@@ -634,3 +1413,869 @@ impl PyWingspanEnv {
         slf.borrow().inner.next_action().map(PyAction::from)
     }
 }
+
+impl PyWingspanEnv {
+    fn info<'py>(slf: &Bound<'py, Self>, step_result: StepResult) -> PyResult<Bound<'py, PyDict>> {
+        let py = slf.py();
+        let info = PyDict::new(py);
+
+        info.set_item("action_mask", Self::action_mask(slf))?;
+        info.set_item("player_idx", slf.borrow().inner.current_player_idx())?;
+        info.set_item(
+            "action_type",
+            slf.borrow().inner.next_action().map(ActionType::from),
+        )?;
+        info.set_item("step_result", step_result)?;
+        info.set_item("game_result", slf.borrow().inner.game_result())?;
+
+        Ok(info)
+    }
+}
+
+// Helpers for tests, which play through games
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use rand::{rngs::StdRng, seq::SliceRandom};
+
+    use super::WingspanEnv;
+    use crate::step_result::StepResult;
+
+    pub(crate) fn legal_actions_in_mask(mask: &[bool]) -> Vec<u8> {
+        mask.iter()
+            .enumerate()
+            .filter_map(|(idx, legal)| legal.then_some(idx as u8))
+            .collect()
+    }
+
+    pub(crate) fn legal_actions(env: &mut WingspanEnv) -> Vec<u8> {
+        legal_actions_in_mask(&env.action_mask())
+    }
+
+    pub(crate) fn random_legal_action(env: &mut WingspanEnv, rng: &mut StdRng) -> u8 {
+        *legal_actions(env).choose(rng).unwrap()
+    }
+
+    pub(crate) fn play_until_round_starts(env: &mut WingspanEnv) {
+        while env.round_idx() < 0 {
+            let action_idx = legal_actions(env)[0];
+            env.step(action_idx).unwrap();
+        }
+    }
+
+    pub(crate) fn play_random_game(env: &mut WingspanEnv, rng: &mut StdRng) {
+        loop {
+            let action_idx = random_legal_action(env, rng);
+            if env.step(action_idx).unwrap() == StepResult::Terminated {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    use super::test_utils::{
+        legal_actions, play_random_game, play_until_round_starts, random_legal_action,
+    };
+    use super::*;
+    use crate::bird_card::{get_deck, BirdCard, BirdCardColor};
+
+    #[test]
+    fn reset_after_game_matches_fresh_env() {
+        let config = WingspanEnvConfigBuilder::default().build().unwrap();
+        let mut env = WingspanEnv::try_new(config.clone());
+        env.reset(Some(3));
+        play_random_game(&mut env, &mut StdRng::seed_from_u64(3));
+
+        env.reset(Some(7));
+        let mut fresh_env = WingspanEnv::try_new(config);
+        fresh_env.reset(Some(7));
+
+        assert_eq!(env.observation(), fresh_env.observation());
+        assert_eq!(env.action_mask(), fresh_env.action_mask());
+        play_random_game(&mut env, &mut StdRng::seed_from_u64(7));
+    }
+
+    #[test]
+    fn european_games_play_to_the_end() {
+        for seed in 0..64 {
+            let config = WingspanEnvConfigBuilder::default()
+                .expansions(vec![Expansion::Core, Expansion::European])
+                .num_players(2 + seed as usize % 4)
+                .build()
+                .unwrap();
+            let mut env = WingspanEnv::try_new(config);
+            env.reset(Some(seed));
+            play_random_game(&mut env, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(env.round_idx(), 4);
+        }
+    }
+
+    #[test]
+    fn config_builder_validates_settings() {
+        let build = |builder: &mut WingspanEnvConfigBuilder| builder.build();
+
+        assert!(build(WingspanEnvConfigBuilder::default().num_players(0usize)).is_err());
+        assert!(build(WingspanEnvConfigBuilder::default().num_rounds(5usize)).is_err());
+        assert!(build(WingspanEnvConfigBuilder::default().num_rounds(0usize)).is_err());
+        assert!(build(WingspanEnvConfigBuilder::default().hand_limit(86)).is_err());
+        assert!(build(WingspanEnvConfigBuilder::default().expansions(vec![])).is_err());
+        assert!(
+            build(WingspanEnvConfigBuilder::default().expansions(vec![Expansion::European]))
+                .is_err()
+        );
+        assert!(build(
+            WingspanEnvConfigBuilder::default()
+                .expansions(vec![Expansion::Core, Expansion::European])
+        )
+        .is_ok());
+        // Nectar is in place, but the rest of Oceania is not
+        assert!(build(
+            WingspanEnvConfigBuilder::default()
+                .expansions(vec![Expansion::Core, Expansion::Oceania])
+        )
+        .is_err());
+
+        // Shorter games end after the last configured round
+        for num_rounds in 1..=4usize {
+            let config = build(
+                WingspanEnvConfigBuilder::default()
+                    .num_rounds(num_rounds)
+                    .num_players(3usize)
+                    .scoring_style(EndOfRoundScoring::Friendly),
+            )
+            .unwrap();
+            let mut env = WingspanEnv::try_new(config);
+            env.reset(Some(num_rounds as u64));
+            assert_eq!(env.end_of_round_goals().len(), num_rounds);
+            play_random_game(&mut env, &mut StdRng::seed_from_u64(0));
+            assert_eq!(env.round_idx(), num_rounds as i8);
+        }
+    }
+
+    #[test]
+    fn round_goal_history_is_recorded() {
+        for scoring_style in [EndOfRoundScoring::Competitive, EndOfRoundScoring::Friendly] {
+            let config = WingspanEnvConfigBuilder::default()
+                .num_players(3usize)
+                .scoring_style(scoring_style)
+                .build()
+                .unwrap();
+            let mut env = WingspanEnv::try_new(config);
+            env.reset(Some(1));
+            play_random_game(&mut env, &mut StdRng::seed_from_u64(1));
+
+            let history = env.round_goal_history();
+            assert_eq!(history.len(), 4);
+            for (round_idx, record) in history.iter().enumerate() {
+                assert_eq!(record.round_idx, round_idx);
+                assert_eq!(record.goal, env.end_of_round_goals()[round_idx]);
+                assert_eq!(record.scoring_style, scoring_style);
+                for (player_idx, score) in record.scores.iter().enumerate() {
+                    assert_eq!(score.player_idx, player_idx);
+                    assert_eq!(score.placement.is_some(), score.num_matching > 0);
+                    assert!(score.points <= 7);
+                }
+            }
+            for player_idx in 0..3 {
+                let total_points: u8 = history
+                    .iter()
+                    .map(|record| record.scores[player_idx].points)
+                    .sum();
+                assert_eq!(
+                    env.get_player(player_idx).get_end_of_round_points(),
+                    total_points
+                );
+            }
+
+            env.reset(Some(2));
+            assert!(env.round_goal_history().is_empty());
+        }
+    }
+
+    #[test]
+    fn action_mask_only_marks_accepted_actions() {
+        // Small hand limits are below the starting hand, and hands are filled up to the limit by powers
+        let configs = [
+            (20, 2usize, 0..100),
+            (1, 2, 0..30),
+            (3, 5, 0..30),
+            (6, 1, 0..30),
+        ];
+        for (hand_limit, num_players, seeds) in configs {
+            for seed in seeds {
+                check_action_mask_on_random_game(
+                    WingspanEnvConfigBuilder::default()
+                        .hand_limit(hand_limit)
+                        .num_players(num_players)
+                        .build()
+                        .unwrap(),
+                    seed,
+                );
+            }
+        }
+    }
+
+    fn check_action_mask_on_random_game(config: WingspanEnvConfig, seed: u64) {
+        let mut env = WingspanEnv::try_new(config.clone());
+        env.reset(Some(seed));
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        loop {
+            let legal = legal_actions(&mut env);
+            let action = env.next_action().unwrap().clone();
+            assert_eq!(
+                legal.len(),
+                action.valid_actions(&mut env).len(),
+                "Valid actions of {action:?} do not fit into the mask ({config:?}, seed {seed})"
+            );
+            assert!(
+                !legal.is_empty(),
+                "No legal actions for {:?} ({config:?}, seed {seed})",
+                env.next_action()
+            );
+
+            for action_idx in legal.iter() {
+                let mut env_copy = env.clone();
+                let result = env_copy.step(*action_idx);
+                assert!(
+                    result.is_ok(),
+                    "Action {action_idx} of {:?} was marked legal, but failed with {result:?} ({config:?}, seed {seed})",
+                    env.next_action()
+                );
+            }
+
+            let action_idx = *legal.choose(&mut rng).unwrap();
+            if env.step(action_idx).unwrap() == StepResult::Terminated {
+                break;
+            }
+
+            let max_hand_size = (config.hand_limit as usize).max(STARTING_HAND_SIZE);
+            for player_idx in 0..config.num_players {
+                let hand_size = env.get_player(player_idx).get_bird_cards().len();
+                assert!(
+                    hand_size <= max_hand_size,
+                    "Hand of {hand_size} cards is over the limit ({config:?}, seed {seed})"
+                );
+            }
+        }
+
+        assert!(env.action_mask().iter().all(|legal| !legal));
+    }
+
+    #[test]
+    fn discard_food_choice_marks_choice_indices() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        for food_idx in [FoodIndex::Fish, FoodIndex::Seed] {
+            let num_food = env.current_player().get_foods()[food_idx as usize];
+            env.current_player_mut()
+                .discard_food(food_idx, num_food)
+                .unwrap();
+        }
+        env.current_player_mut().add_food(FoodIndex::Seed, 1);
+
+        // Only the 2nd choice can be paid. Its index is 1, not the index of [seed] (2)
+        let action = Action::DiscardFoodChoice(
+            Box::new([(FoodIndex::Fish, 1), (FoodIndex::Seed, 1)]),
+            Habitat::Forest,
+        );
+        assert_eq!(action.valid_actions(&mut env), vec![1]);
+
+        env.push_action(action);
+        env.step(1).unwrap();
+        assert_eq!(
+            env.current_player().get_foods()[FoodIndex::Seed as usize],
+            0
+        );
+    }
+
+    #[test]
+    fn discard_food_or_bird_card_indexes_follow_foods_in_game() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        env.step(0).unwrap();
+        assert_eq!(env.next_action(), Some(&Action::DiscardFoodOrBirdCard));
+
+        // Without Oceania there is no nectar, so cards in hand start right after [rodent]
+        assert_eq!(env.num_foods(), 5);
+        assert_eq!(env.valid_actions(), (0..10).collect::<Vec<u8>>());
+
+        let bird_card = env.current_player().get_bird_cards()[0];
+        let foods = *env.current_player().get_foods();
+        env.step(5).unwrap();
+        assert!(!env.current_player().get_bird_cards().contains(&bird_card));
+        assert_eq!(env.current_player().get_foods(), &foods);
+    }
+
+    #[test]
+    fn out_of_turn_decisions_are_attributed_to_deciding_player() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        play_until_round_starts(&mut env);
+        assert_eq!(env.current_turn_player_idx(), 0);
+
+        // Hummingbird power: each player gains 1 die, starting with the player of your choice.
+        env.push_action(Action::ChooseThenAction(
+            2,
+            BirdCard::AnnasHummingbird,
+            Habitat::Forest,
+            0,
+        ));
+        let foods_before: Vec<_> = (0..2).map(|idx| *env.get_player(idx).get_foods()).collect();
+
+        // Start with the other player
+        env.step(1).unwrap();
+        let mut deciding_players = vec![];
+        while !matches!(env.next_action(), Some(Action::ChooseAction)) {
+            deciding_players.push(env.current_player_idx());
+            let action_idx = legal_actions(&mut env)[0];
+            let (_, rewards) = env.step_with_rewards(action_idx).unwrap();
+            assert_eq!(rewards.len(), 2);
+        }
+
+        assert_eq!(deciding_players, vec![1, 0]);
+        assert_eq!(env.current_turn_player_idx(), 0);
+        assert_eq!(env.current_player_idx(), 0);
+        for (player_idx, foods_before) in foods_before.iter().enumerate() {
+            let gained: u8 = env
+                .get_player(player_idx)
+                .get_foods()
+                .iter()
+                .zip(foods_before.iter())
+                .map(|(after, before)| after - before)
+                .sum();
+            assert_eq!(gained, 1);
+        }
+    }
+
+    #[test]
+    fn end_of_round_powers_are_resolved_before_round_advances() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        play_until_round_starts(&mut env);
+
+        // Player 0 can discard seeds to tuck cards, player 1 can tuck cards from hand to draw new ones
+        for (player_idx, bird_card) in [(0, BirdCard::HouseSparrow), (1, BirdCard::Ruff)] {
+            env.get_player_mut(player_idx)
+                .get_mat_mut()
+                .put_bird_card(bird_card, &bird_card.habitats()[0])
+                .unwrap();
+        }
+        env.gain_food_from_supply(FoodIndex::Seed, 2);
+        for player_idx in 0..2 {
+            env.get_player_mut(player_idx).set_turns_left(0);
+        }
+
+        // Finish the last turn of the round
+        env.step(1).unwrap();
+        while !env._end_of_round_phase {
+            let action_idx = legal_actions(&mut env)[0];
+            env.step(action_idx).unwrap();
+        }
+
+        let mut deciding_players = vec![];
+        while env._end_of_round_phase {
+            assert_eq!(env.round_idx(), 0);
+            assert!(env.round_goal_history().is_empty());
+            deciding_players.push(env.current_player_idx());
+
+            // Accept all of the options
+            let action_idx = *legal_actions(&mut env).last().unwrap();
+            env.step(action_idx).unwrap();
+        }
+        deciding_players.dedup();
+        assert_eq!(deciding_players, vec![0, 1]);
+
+        assert_eq!(env.round_idx(), 1);
+        assert_eq!(env.round_goal_history().len(), 1);
+        assert_eq!(env.next_action(), Some(&Action::ChooseAction));
+        assert_eq!(env.current_player_idx(), 1);
+
+        let sparrow_habitat = BirdCard::HouseSparrow.habitats()[0];
+        let player = env.get_player(0);
+        assert_eq!(player.get_foods()[FoodIndex::Seed as usize], 0);
+        assert!(
+            player
+                .get_mat()
+                .get_row(&sparrow_habitat)
+                .get_tucked_cards()[0]
+                >= 2
+        );
+        let ruff_habitat = BirdCard::Ruff.habitats()[0];
+        let player = env.get_player(1);
+        assert_eq!(
+            player.get_mat().get_row(&ruff_habitat).get_tucked_cards()[0],
+            3
+        );
+    }
+
+    // Only the mechanism is covered here: Kakapo and Crested Pigeon are Oceania birds,
+    // and there are no yellow birds in the expansions, which can be used in a game yet
+    #[test]
+    fn game_end_powers_are_resolved_before_game_ends() {
+        let mut env = WingspanEnv::try_new(
+            WingspanEnvConfigBuilder::default()
+                .num_rounds(1usize)
+                .build()
+                .unwrap(),
+        );
+        env.reset(Some(0));
+        play_until_round_starts(&mut env);
+
+        // Player 0 draws and discards bonus cards, player 1 can cache seeds from their supply
+        for (player_idx, bird_card) in [(0, BirdCard::Kakapo), (1, BirdCard::CrestedPigeon)] {
+            env.get_player_mut(player_idx)
+                .get_mat_mut()
+                .put_bird_card(bird_card, &bird_card.habitats()[0])
+                .unwrap();
+        }
+        env.set_current_player(1);
+        env.gain_food_from_supply(FoodIndex::Seed, 3);
+        env.set_current_player(0);
+        for player_idx in 0..2 {
+            env.get_player_mut(player_idx).set_turns_left(0);
+        }
+
+        // Finish the last turn of the game
+        env.step(1).unwrap();
+        while !env._end_of_game_phase {
+            let action_idx = legal_actions(&mut env)[0];
+            env.step(action_idx).unwrap();
+        }
+        // Last round is scored before the game end powers
+        assert_eq!(env.round_goal_history().len(), 1);
+
+        let mut deciding_players = vec![];
+        let seeds = env.get_player(1).get_foods()[FoodIndex::Seed as usize];
+        loop {
+            assert!(env.game_result().is_none());
+            deciding_players.push(env.current_player_idx());
+
+            // Accept all of the options
+            let action_idx = *legal_actions(&mut env).last().unwrap();
+            if env.step(action_idx).unwrap() == StepResult::Terminated {
+                break;
+            }
+        }
+        deciding_players.dedup();
+        assert_eq!(deciding_players, vec![0, 1]);
+        assert!(env.game_result().is_some());
+
+        // One kept during the setup, and one kept out of the 4 drawn
+        assert_eq!(env.get_player(0).get_bonus_cards().len(), 2);
+        let pigeon_habitat = BirdCard::CrestedPigeon.habitats()[0];
+        let player = env.get_player(1);
+        assert_eq!(player.get_foods()[FoodIndex::Seed as usize], 0);
+        assert_eq!(
+            player.get_mat().get_row(&pigeon_habitat).get_cached_food()[0]
+                [FoodIndex::Seed as usize],
+            seeds
+        );
+    }
+
+    #[test]
+    fn game_ends_without_decisions_when_no_bird_has_game_end_power() {
+        let expansions = vec![Expansion::Core, Expansion::European];
+        assert!(get_deck(&expansions)
+            .iter()
+            .all(|bird_card| *bird_card.color() != BirdCardColor::Yellow));
+
+        let mut env = WingspanEnv::try_new(
+            WingspanEnvConfigBuilder::default()
+                .expansions(expansions)
+                .build()
+                .unwrap(),
+        );
+        env.reset(Some(0));
+        let mut rng = StdRng::seed_from_u64(0);
+        loop {
+            let action_idx = random_legal_action(&mut env, &mut rng);
+            if env.step(action_idx).unwrap() == StepResult::Terminated {
+                break;
+            }
+            // Game end powers of every player are skipped within the step that scores the last round
+            assert!(!env._end_of_game_phase);
+        }
+        assert!(env.game_result().is_some());
+    }
+
+    #[test]
+    fn pink_powers_react_to_triggers_of_other_players() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        play_until_round_starts(&mut env);
+
+        // Player 1 lays an egg on a bowl nest, when another player takes the "lay eggs" action
+        for bird_card in [BirdCard::AmericanRobin, BirdCard::BrownHeadedCowbird] {
+            env.get_player_mut(1)
+                .get_mat_mut()
+                .put_bird_card(bird_card, &bird_card.habitats()[0])
+                .unwrap();
+        }
+        let callback = BirdCardCallback {
+            card: BirdCard::BrownHeadedCowbird,
+            habitat: BirdCard::BrownHeadedCowbird.habitats()[0],
+            card_idx: 0,
+            card_player_idx: 1,
+        };
+        env.push_callback(callback.clone());
+        env._active_callbacks.entry(1).or_default().insert(callback);
+
+        let robin_eggs = |env: &WingspanEnv| {
+            env.get_player(1)
+                .get_mat()
+                .get_row(&BirdCard::AmericanRobin.habitats()[0])
+                .get_eggs()[0]
+        };
+        let pink_power_triggered = |env: &WingspanEnv| {
+            env.events()
+                .iter()
+                .any(|event| matches!(event, GameEvent::PinkPowerTriggered { .. }))
+        };
+        let finish_turn = |env: &mut WingspanEnv| {
+            while env.next_action() != Some(&Action::ChooseAction) {
+                let action_idx = legal_actions(env)[0];
+                env.step(action_idx).unwrap();
+            }
+        };
+
+        // "Gain food" action does not trigger it
+        env.step(1).unwrap();
+        assert!(!pink_power_triggered(&env));
+        finish_turn(&mut env);
+        assert_eq!(robin_eggs(&env), 0);
+
+        // Neither do actions of the owner
+        assert_eq!(env.current_turn_player_idx(), 1);
+        env.step(2).unwrap();
+        assert!(!pink_power_triggered(&env));
+        finish_turn(&mut env);
+        let robin_eggs_before = robin_eggs(&env);
+
+        assert_eq!(env.current_turn_player_idx(), 0);
+        env.step(2).unwrap();
+        assert!(pink_power_triggered(&env));
+        assert_eq!(robin_eggs(&env), robin_eggs_before + 1);
+    }
+
+    #[test]
+    fn repeated_hunts_are_recorded_and_trigger_pink_powers() {
+        let mut num_successful_hunts = 0;
+        for seed in 0..10 {
+            let mut env =
+                WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+            env.reset(Some(seed));
+            play_until_round_starts(&mut env);
+
+            for bird_card in [BirdCard::Anhinga, BirdCard::HoodedMerganser] {
+                env.current_player_mut()
+                    .get_mat_mut()
+                    .put_bird_card(bird_card, &Habitat::Wetland)
+                    .unwrap();
+            }
+            env.get_player_mut(1)
+                .get_mat_mut()
+                .put_bird_card(BirdCard::BlackVulture, &Habitat::Forest)
+                .unwrap();
+            let callback = BirdCardCallback {
+                card: BirdCard::BlackVulture,
+                habitat: Habitat::Forest,
+                card_idx: 0,
+                card_player_idx: 1,
+            };
+            env.push_callback(callback.clone());
+            env._active_callbacks.entry(1).or_default().insert(callback);
+
+            // Anhinga rolls the dice that are out of the birdfeeder
+            for _ in 0..4 {
+                let food_idx = FoodIndex::iter()
+                    .find(|food_idx| env._bird_feeder.contains(*food_idx) > 0)
+                    .unwrap();
+                env._bird_feeder.take_specific_food(food_idx).unwrap();
+            }
+
+            // Hooded Merganser repeats the power of Anhinga
+            env.push_action(Action::ChooseThenAction(
+                1,
+                BirdCard::HoodedMerganser,
+                Habitat::Wetland,
+                1,
+            ));
+            env.step(0).unwrap();
+
+            let hunts = env.hunts().to_vec();
+            assert_eq!(hunts.len(), 1);
+            let hunt = hunts[0];
+            assert_eq!(hunt.bird_card, BirdCard::Anhinga);
+            assert_eq!((hunt.player_idx, hunt.bird_idx), (0, 0));
+
+            let cached_fish = env
+                .get_player(0)
+                .get_mat()
+                .get_row(&Habitat::Wetland)
+                .get_cached_food()[0][FoodIndex::Fish as usize];
+            assert_eq!(hunt.succeeded, cached_fish == 1);
+            let vulture_triggered = env.events().iter().any(|event| {
+                matches!(
+                    event,
+                    GameEvent::PinkPowerTriggered {
+                        player_idx: 1,
+                        bird_card: BirdCard::BlackVulture,
+                        ..
+                    }
+                )
+            });
+            assert_eq!(hunt.succeeded, vulture_triggered);
+
+            num_successful_hunts += hunt.succeeded as usize;
+        }
+        assert!(num_successful_hunts > 0);
+    }
+
+    #[test]
+    fn nectar_is_wild_and_spent_nectar_scores_majority() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        play_until_round_starts(&mut env);
+        assert_eq!(env.current_player_idx(), 0);
+
+        let foods = *env.current_player().get_foods();
+        for food in FoodIndex::iter() {
+            env.current_player_mut()
+                .discard_food(food, foods[food as usize])
+                .unwrap();
+        }
+        env.current_player_mut().add_food(FoodIndex::Seed, 1);
+        env.current_player_mut().add_food(FoodIndex::Nectar, 2);
+
+        // American Goldfinch costs 2 [seed]. Missing one is paid with nectar, and the other one is a choice
+        env.current_player_mut()
+            .add_bird_card(BirdCard::AmericanGoldfinch);
+        env.push_action(Action::PlayBirdHabitat(Habitat::Grassland));
+        legal_actions(&mut env);
+        let card_idx = env
+            .current_player()
+            .get_playable_card_hab_combos()
+            .iter()
+            .position(|(bird_card, _, _)| *bird_card == BirdCard::AmericanGoldfinch)
+            .unwrap();
+        env.step(card_idx as u8).unwrap();
+
+        assert_eq!(
+            env.next_action(),
+            Some(&Action::PayFood(
+                Box::new([FoodIndex::Seed, FoodIndex::Nectar]),
+                Habitat::Grassland
+            ))
+        );
+        env.step(1).unwrap();
+
+        let player = env.get_player(0);
+        assert_eq!(player.get_foods()[FoodIndex::Nectar as usize], 0);
+        assert_eq!(
+            player
+                .get_mat()
+                .get_row(&Habitat::Grassland)
+                .get_spent_nectar(),
+            2
+        );
+
+        // Only player who spent nectar scores the majority
+        assert_eq!(env.score_breakdown()[0].nectar_points, 5);
+        assert_eq!(env.score_breakdown()[1].nectar_points, 0);
+
+        // Tied players split points of 1st and 2nd place
+        env.get_player_mut(1)
+            .get_mat_mut()
+            .get_row_mut(&Habitat::Grassland)
+            .spend_nectar(2);
+        for breakdown in env.score_breakdown() {
+            assert_eq!(breakdown.nectar_points, 3);
+        }
+        assert_eq!(
+            env.points(),
+            env.score_breakdown()
+                .iter()
+                .map(|breakdown| breakdown.total)
+                .collect_vec()
+        );
+
+        // Unspent nectar is discarded at the end of the round, spent one stays on the mat
+        env.get_player_mut(0).add_food(FoodIndex::Nectar, 1);
+        env.end_of_round().unwrap();
+        let player = env.get_player(0);
+        assert_eq!(player.get_foods()[FoodIndex::Nectar as usize], 0);
+        assert_eq!(
+            player
+                .get_mat()
+                .get_row(&Habitat::Grassland)
+                .get_spent_nectar(),
+            2
+        );
+    }
+
+    #[test]
+    fn nectar_traded_in_grassland_is_spent_there() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        play_until_round_starts(&mut env);
+        // Oceania is not allowed in the config yet, but nectar is only a food choice with it
+        env.config.expansions.push(Expansion::Oceania);
+
+        let foods = *env.current_player().get_foods();
+        for food in FoodIndex::iter() {
+            env.current_player_mut()
+                .discard_food(food, foods[food as usize])
+                .unwrap();
+        }
+        env.current_player_mut().add_food(FoodIndex::Seed, 1);
+        env.current_player_mut().add_food(FoodIndex::Nectar, 1);
+        env.current_player_mut()
+            .get_mat_mut()
+            .put_bird_card(BirdCard::AmericanGoldfinch, &Habitat::Grassland)
+            .unwrap();
+
+        // Accept the trade, and pay for the extra egg with nectar
+        env.push_action(MatLayout::Core.trade_action(&Habitat::Grassland));
+        env.step(1).unwrap();
+        assert_eq!(
+            env.next_action(),
+            Some(&Action::DiscardFood(Habitat::Grassland))
+        );
+        env.step(FoodIndex::Nectar as u8).unwrap();
+
+        // The only spot for the egg is chosen automatically
+        let player = env.current_player();
+        assert_eq!(player.get_foods()[FoodIndex::Seed as usize], 1);
+        assert_eq!(player.get_foods()[FoodIndex::Nectar as usize], 0);
+        let grassland = player.get_mat().get_row(&Habitat::Grassland);
+        assert_eq!(grassland.get_spent_nectar(), 1);
+        assert_eq!(grassland.get_eggs()[0], 1);
+        assert_eq!(
+            player
+                .get_mat()
+                .get_row(&Habitat::Forest)
+                .get_spent_nectar(),
+            0
+        );
+    }
+
+    #[test]
+    fn nectar_powers_choose_another_player() {
+        let mut env = WingspanEnv::try_new(
+            WingspanEnvConfigBuilder::default()
+                .num_players(3usize)
+                .build()
+                .unwrap(),
+        );
+        env.reset(Some(0));
+        play_until_round_starts(&mut env);
+        assert_eq!(env.current_player_idx(), 0);
+        for bird_card in [BirdCard::LewinsHoneyeater, BirdCard::RedWingedParrot] {
+            env.current_player_mut()
+                .get_mat_mut()
+                .put_bird_card(bird_card, &Habitat::Forest)
+                .unwrap();
+        }
+        let nectar = |env: &WingspanEnv| -> Vec<u8> {
+            (0..3)
+                .map(|idx| env.get_player(idx).get_foods()[FoodIndex::Nectar as usize])
+                .collect()
+        };
+        let nectar_before = nectar(&env);
+
+        // Lewin's Honeyeater: the 2nd other player (2) gains nectar too
+        let mut result = BirdCard::LewinsHoneyeater
+            .activate(&mut env, &Habitat::Forest, 0)
+            .unwrap();
+        env.append_actions(&mut result.immediate_actions);
+        env.step(1).unwrap();
+        let nectar_after = nectar(&env);
+        assert_eq!(nectar_after[0], nectar_before[0] + 1);
+        assert_eq!(nectar_after[1], nectar_before[1]);
+        assert_eq!(nectar_after[2], nectar_before[2] + 1);
+
+        // Red-Winged Parrot: give nectar to player 1 and gain 2 dice
+        let mut result = BirdCard::RedWingedParrot
+            .activate(&mut env, &Habitat::Forest, 1)
+            .unwrap();
+        env.append_actions(&mut result.immediate_actions);
+        env.step(1).unwrap();
+        env.step(1).unwrap();
+        let nectar_after = nectar(&env);
+        assert_eq!(nectar_after[0], nectar_before[0]);
+        assert_eq!(nectar_after[1], nectar_before[1] + 1);
+        assert_eq!(env.next_action(), Some(&Action::GetFood));
+        assert_eq!(
+            env._action_queue
+                .iter()
+                .filter(|action| **action == Action::GetFood)
+                .count(),
+            2
+        );
+
+        // Or give it to player 2 and lay 2 eggs on the parrot
+        env.current_player_mut().add_food(FoodIndex::Nectar, 1);
+        env.push_action(Action::ChooseThenAction(
+            4,
+            BirdCard::RedWingedParrot,
+            Habitat::Forest,
+            1,
+        ));
+        env.step(2).unwrap();
+        assert_eq!(nectar(&env)[2], nectar_before[2] + 2);
+        assert_eq!(
+            env.current_player()
+                .get_mat()
+                .get_row(&Habitat::Forest)
+                .get_eggs()[1],
+            2
+        );
+
+        // Without nectar, there is nothing to give
+        let foods = *env.current_player().get_foods();
+        env.current_player_mut()
+            .discard_food(FoodIndex::Nectar, foods[FoodIndex::Nectar as usize])
+            .unwrap();
+        let result = BirdCard::RedWingedParrot
+            .activate(&mut env, &Habitat::Forest, 1)
+            .unwrap();
+        assert!(result.immediate_actions.is_empty());
+    }
+
+    #[test]
+    fn habitat_actions_follow_mat_layout() {
+        let mut env = WingspanEnv::try_new(WingspanEnvConfigBuilder::default().build().unwrap());
+        env.reset(Some(0));
+        play_until_round_starts(&mut env);
+
+        let forest_birds = BirdCard::iter()
+            .filter(|bird_card| bird_card.habitats().contains(&Habitat::Forest))
+            .take(3);
+        for bird_card in forest_birds {
+            env.current_player_mut()
+                .get_mat_mut()
+                .put_bird_card(bird_card, &Habitat::Forest)
+                .unwrap();
+        }
+
+        // Gain food with 3 birds in the forest
+        env.step(1).unwrap();
+
+        let mat_layout = env.config().mat_layout;
+        let reward = mat_layout.column_reward(&Habitat::Forest, 3);
+        let num_gain_food = env
+            ._action_queue
+            .iter()
+            .filter(|action| **action == Action::GetFood)
+            .count();
+        assert_eq!(num_gain_food, reward.num_actions as usize);
+        assert_eq!(
+            env.next_action() == Some(&mat_layout.trade_action(&Habitat::Forest)),
+            reward.trade
+        );
+    }
+}
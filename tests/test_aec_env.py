@@ -0,0 +1,34 @@
+import numpy as np
+import pytest
+
+pytest.importorskip("pettingzoo")
+
+from pettingzoo.test import api_test  # noqa: E402
+
+from wingspan_gym.aec import WingspanAECEnv  # noqa: E402
+
+
+def test_api():
+    api_test(WingspanAECEnv(), num_cycles=1000)
+
+
+def test_full_game_rewards_match_points():
+    env = WingspanAECEnv(num_players=3)
+    env.reset(seed=2)
+    rng = np.random.default_rng(2)
+
+    points_at_start = env.points()
+    total_rewards = {agent: 0.0 for agent in env.possible_agents}
+    for agent in env.agent_iter():
+        observation, reward, termination, truncation, _ = env.last()
+        total_rewards[agent] += reward
+
+        if termination or truncation:
+            action = None
+        else:
+            assert env.agent_name_mapping[agent] == env._inner.player_idx
+            action = int(rng.choice(np.flatnonzero(observation["action_mask"])))
+        env.step(action)
+
+    for agent, start, end in zip(env.possible_agents, points_at_start, env.points()):
+        assert total_rewards[agent] == end - start
@@ -0,0 +1,51 @@
+import numpy as np
+import pytest
+
+from wingspan_gym._internal import BatchWingspanEnv, PyWingspanEnv
+
+
+def random_actions(action_masks, rng):
+    return np.array([rng.choice(np.flatnonzero(mask)) for mask in action_masks])
+
+
+@pytest.mark.parametrize("num_threads", [None, 2])
+def test_batch_matches_single_envs(num_threads):
+    num_envs = 4
+    batch_env = BatchWingspanEnv(num_envs, num_threads=num_threads)
+    envs = [PyWingspanEnv() for _ in range(num_envs)]
+
+    observations, action_masks = batch_env.reset(seed=7)
+    for env_idx, env in enumerate(envs):
+        env.reset(7 + env_idx)
+        np.testing.assert_array_equal(observations[env_idx], env.observation())
+        np.testing.assert_array_equal(action_masks[env_idx], env.action_mask())
+
+    rng = np.random.default_rng(0)
+    for _ in range(500):
+        actions = random_actions(action_masks, rng)
+        observations, action_masks, rewards, dones, infos = batch_env.step(actions)
+        assert observations.shape[0] == action_masks.shape[0] == num_envs
+        np.testing.assert_array_equal(infos["_final_observation"], dones)
+        np.testing.assert_array_equal(infos["_final_info"], dones)
+
+        for env_idx, env in enumerate(envs):
+            obs, reward, terminated, _, info = env.step(int(actions[env_idx]))
+            assert rewards[env_idx] == reward
+            assert dones[env_idx] == terminated
+            if terminated:
+                np.testing.assert_array_equal(infos["final_observation"][env_idx], obs)
+                assert infos["final_info"][env_idx]["game_result"] == info["game_result"]
+            else:
+                assert infos["final_observation"][env_idx] is None
+                assert infos["final_info"][env_idx] is None
+            if terminated:
+                # Seed of the next game is drawn from the environment itself
+                env.reset()
+            np.testing.assert_array_equal(observations[env_idx], env.observation())
+
+
+def test_wrong_number_of_actions():
+    batch_env = BatchWingspanEnv(2)
+    batch_env.reset()
+    with pytest.raises(ValueError):
+        batch_env.step([0, 0, 0])
@@ -0,0 +1,19 @@
+import numpy as np
+
+from wingspan_gym._internal import PyWingspanEnv
+
+
+def test_determinize_keeps_observation():
+    env = PyWingspanEnv(num_players=3)
+    env.reset(4)
+    rng = np.random.default_rng(4)
+    while env.round_idx < 1:
+        env.step(int(rng.choice(np.flatnonzero(env.action_mask()))))
+
+    observer_idx = env.player_idx
+    for seed in range(5):
+        determinized = env.determinize(seed=seed)
+        np.testing.assert_array_equal(
+            determinized.observation(observer_idx), env.observation(observer_idx)
+        )
+        np.testing.assert_array_equal(determinized.action_mask(), env.action_mask())
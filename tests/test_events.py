@@ -0,0 +1,22 @@
+import numpy as np
+
+from wingspan_gym import WingspanEnv
+from wingspan_gym._internal import GameEvent
+
+
+def test_events_follow_steps():
+    env = WingspanEnv()
+    _, info = env.reset(seed=5)
+    rng = np.random.default_rng(5)
+
+    eggs_laid = 0
+    while True:
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        *_, terminated, _, info = env.step(action)
+        events = env.events()
+        assert all(isinstance(event, GameEvent) for event in events)
+        eggs_laid += sum(isinstance(event, GameEvent.EggLaid) for event in events)
+        if terminated:
+            break
+
+    assert eggs_laid > 0
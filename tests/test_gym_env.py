@@ -0,0 +1,202 @@
+import numpy as np
+import pytest
+from gymnasium.utils.env_checker import check_env
+
+from wingspan_gym import (
+    EndOfRoundScoring,
+    Expansion,
+    RewardMode,
+    StepResult,
+    WingspanEnv,
+)
+
+
+def test_check_env():
+    check_env(WingspanEnv(), skip_render_check=True)
+
+
+def test_reset_signature():
+    env = WingspanEnv()
+    observation, info = env.reset(seed=0)
+
+    assert env.observation_space.contains(observation)
+    assert info["action_mask"].shape == (env.action_space.n,)
+    assert info["player_idx"] == 0
+    assert info["action_type"] is not None
+
+
+def test_full_game_with_action_mask():
+    env = WingspanEnv()
+    observation, info = env.reset(seed=1)
+    rng = np.random.default_rng(1)
+
+    terminated = False
+    while not terminated:
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        observation, reward, terminated, truncated, info = env.step(action)
+
+        assert env.observation_space.contains(observation)
+        assert isinstance(reward, float)
+        assert not truncated
+        assert info["step_result"] != StepResult.Invalid
+
+    assert not info["action_mask"].any()
+    assert info["action_type"] is None
+
+
+def test_same_seed_same_game():
+    env_a, env_b = WingspanEnv(), WingspanEnv()
+    obs_a, _ = env_a.reset(seed=5)
+    obs_b, _ = env_b.reset(seed=5)
+
+    np.testing.assert_array_equal(obs_a, obs_b)
+
+
+def test_config():
+    env = WingspanEnv(
+        num_players=3,
+        num_rounds=2,
+        scoring_style=EndOfRoundScoring.Friendly,
+        hand_limit=10,
+    )
+    config = env.config
+    assert config.num_players == 3
+    assert config.num_rounds == 2
+    assert config.scoring_style == EndOfRoundScoring.Friendly
+    assert config.hand_limit == 10
+    assert config.expansions == [Expansion.Core]
+    assert env.action_space.n == 30
+
+    _, info = env.reset(seed=0)
+    rng = np.random.default_rng(0)
+    terminated = False
+    while not terminated:
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        _, _, terminated, _, info = env.step(action)
+    assert env.cur_round() == 2
+
+
+def test_invalid_config():
+    with pytest.raises(ValueError, match="num_rounds"):
+        WingspanEnv(num_rounds=5)
+    with pytest.raises(ValueError, match="num_players"):
+        WingspanEnv(num_players=0)
+
+
+def test_seed_in_constructor():
+    env_a = WingspanEnv(seed=5)
+    env_b = WingspanEnv(seed=5)
+    np.testing.assert_array_equal(env_a.observation(), env_b.observation())
+
+    # Resets without seed are reproducible as well
+    np.testing.assert_array_equal(env_a.reset()[0], env_b.reset()[0])
+
+
+@pytest.mark.parametrize(
+    "scoring_style", [EndOfRoundScoring.Competitive, EndOfRoundScoring.Friendly]
+)
+def test_round_goal_placements(scoring_style):
+    env = WingspanEnv(num_players=3, scoring_style=scoring_style)
+    _, info = env.reset(seed=2)
+    rng = np.random.default_rng(2)
+    assert env.round_goal_placements() == []
+
+    terminated = False
+    while not terminated:
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        _, _, terminated, _, info = env.step(action)
+
+    placements = env.round_goal_placements()
+    assert len(placements) == 4
+    for round_placements in placements:
+        assert len(round_placements) == 3
+        assert all(p is None or 0 <= p < 3 for p in round_placements)
+
+
+def test_round_goal_history():
+    env = WingspanEnv(num_players=3)
+    _, info = env.reset(seed=3)
+    rng = np.random.default_rng(3)
+
+    terminated = False
+    while not terminated:
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        _, _, terminated, _, info = env.step(action)
+
+    history = env.round_goal_history()
+    assert [record.round_idx for record in history] == [0, 1, 2, 3]
+    for record in history:
+        assert record.scoring_style == EndOfRoundScoring.Competitive
+        assert len(record.scores) == 3
+        for player_idx, score in enumerate(record.scores):
+            assert score.player_idx == player_idx
+            assert (score.placement is None) == (score.num_matching == 0)
+            if score.placement is None:
+                assert score.points == 0
+    assert [
+        [score.placement for score in record.scores] for record in history
+    ] == env.round_goal_placements()
+
+
+def test_score_breakdown():
+    env = WingspanEnv()
+    _, info = env.reset(seed=4)
+    rng = np.random.default_rng(4)
+
+    terminated = False
+    while not terminated:
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        _, _, terminated, _, info = env.step(action)
+
+    for breakdown, points in zip(env.score_breakdown(), env.points()):
+        assert breakdown.total == points
+        assert breakdown.total == (
+            breakdown.bird_points
+            + breakdown.bonus_card_points
+            + breakdown.round_goal_points
+            + breakdown.egg_points
+            + breakdown.cached_food_points
+            + breakdown.tucked_card_points
+        )
+        assert breakdown.bonus_card_points == sum(
+            card.points for card in breakdown.bonus_cards
+        )
+
+
+def test_game_result():
+    env = WingspanEnv(num_players=3)
+    _, info = env.reset(seed=5)
+    rng = np.random.default_rng(5)
+
+    terminated = False
+    while not terminated:
+        assert info["game_result"] is None
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        _, _, terminated, _, info = env.step(action)
+
+    game_result = info["game_result"]
+    assert game_result.scores == env.points()
+    assert game_result.winners == [
+        idx for idx, rank in enumerate(game_result.ranks) if rank == 0
+    ]
+    assert game_result.shared_victory == (len(game_result.winners) > 1)
+    assert sum(game_result.win_shares()) == pytest.approx(1.0)
+    assert sum(game_result.zero_sum_rewards()) == pytest.approx(0.0)
+    assert sum(game_result.rank_rewards()) == pytest.approx(0.0)
+
+
+def test_win_loss_reward_is_given_at_the_end():
+    env = WingspanEnv(num_players=3, reward_mode=RewardMode.WinLoss)
+    assert env.config.reward_mode == RewardMode.WinLoss
+    _, info = env.reset(seed=2)
+    rng = np.random.default_rng(2)
+
+    terminated = False
+    while not terminated:
+        acting_player_idx = info["player_idx"]
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        _, reward, terminated, _, info = env.step(action)
+        if not terminated:
+            assert reward == 0.0
+
+    assert reward == info["game_result"].win_shares()[acting_player_idx]
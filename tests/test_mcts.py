@@ -0,0 +1,57 @@
+import numpy as np
+import pytest
+
+from wingspan_gym._internal import PyWingspanEnv, search
+
+
+def test_search_returns_valid_action():
+    env = PyWingspanEnv()
+    env.reset(3)
+    before = env.observation(env.player_idx)
+
+    action_idx, stats = search(env, 50, seed=0)
+    assert env.action_mask()[action_idx]
+    assert sum(visits for visits, _ in stats.values()) == 50
+    assert all(env.action_mask()[idx] for idx in stats)
+    np.testing.assert_array_equal(env.observation(env.player_idx), before)
+
+    # Same seed gives the same search
+    assert search(env, 50, seed=0) == (action_idx, stats)
+
+
+def test_search_with_time_budget():
+    env = PyWingspanEnv()
+    env.reset(3)
+    action_idx, _ = search(env, 0.05)
+    assert env.action_mask()[action_idx]
+
+
+def test_search_with_rollout_policy():
+    env = PyWingspanEnv()
+    env.reset(3)
+
+    num_calls = 0
+
+    def first_choice(rollout_env, valid_actions):
+        nonlocal num_calls
+        num_calls += 1
+        assert rollout_env.action_mask()[valid_actions[0]]
+        return valid_actions[0]
+
+    action_idx, _ = search(env, 5, max_rollout_steps=20, seed=0, rollout_policy=first_choice)
+    assert env.action_mask()[action_idx]
+    assert num_calls > 0
+
+
+def test_search_propagates_rollout_policy_errors():
+    env = PyWingspanEnv()
+    env.reset(3)
+
+    def failing(rollout_env, valid_actions):
+        raise RuntimeError("policy failed")
+
+    with pytest.raises(RuntimeError, match="policy failed"):
+        search(env, 5, seed=0, rollout_policy=failing)
+
+    with pytest.raises(ValueError):
+        search(env, 5, seed=0, rollout_policy=lambda rollout_env, valid_actions: 255)
@@ -0,0 +1,52 @@
+import pickle
+
+import numpy as np
+
+from wingspan_gym import WingspanEnv
+from wingspan_gym._internal import PyWingspanEnv
+
+
+def play_random_steps(env: WingspanEnv, info: dict, rng: np.random.Generator, num_steps: int):
+    for _ in range(num_steps):
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        *_, terminated, _, info = env.step(action)
+        if terminated:
+            break
+    return info
+
+
+def test_pickle_round_trip():
+    env = WingspanEnv()
+    _, info = env.reset(seed=3)
+    info = play_random_steps(env, info, np.random.default_rng(3), 50)
+
+    restored = pickle.loads(pickle.dumps(env))
+    np.testing.assert_array_equal(env.observation(), restored.observation())
+
+    # Both environments continue the same way, including rolls of the bird feeder
+    rng_a, rng_b = np.random.default_rng(4), np.random.default_rng(4)
+    play_random_steps(env, info, rng_a, 50)
+    play_random_steps(restored, info, rng_b, 50)
+    np.testing.assert_array_equal(env.observation(), restored.observation())
+    assert env.points() == restored.points()
+
+
+def test_json_round_trip():
+    env = PyWingspanEnv()
+    env.reset(7)
+
+    restored = PyWingspanEnv.from_json(env.to_json())
+    assert restored.to_bytes() == env.to_bytes()
+
+
+def test_replay_round_trip():
+    env = PyWingspanEnv(record_replay=True)
+    _, info = env.reset(11)
+    rng = np.random.default_rng(11)
+    for _ in range(100):
+        action = int(rng.choice(np.flatnonzero(info["action_mask"])))
+        *_, info = env.step(action)
+
+    replayed = PyWingspanEnv.from_replay_json(env.replay_json())
+    assert replayed.to_bytes() == env.to_bytes()
+    assert PyWingspanEnv().replay_json() is None
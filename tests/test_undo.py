@@ -0,0 +1,22 @@
+import numpy as np
+import pytest
+
+from wingspan_gym._internal import PyWingspanEnv
+
+
+def test_undo_restores_state():
+    env = PyWingspanEnv(undo_history_size=10)
+    env.reset(11)
+    rng = np.random.default_rng(11)
+
+    states = []
+    for _ in range(10):
+        states.append(env.to_bytes())
+        env.step(int(rng.choice(np.flatnonzero(env.action_mask()))))
+
+    for state in reversed(states):
+        env.undo()
+        assert env.to_bytes() == state
+
+    with pytest.raises(ValueError):
+        env.undo()